@@ -3,37 +3,28 @@
 //! # Dice Duel
 //!
 //! A two-player dice game where each player rolls two dice.
-//! The player with the highest total wins (ties go to player 1).
+//! The player with the highest total wins; equal totals are a draw and both
+//! players get their points back.
+//!
+//! **Randomness:**
+//! Each player rolls by committing to `keccak256(session_id ‖ player ‖ secret)`
+//! and, once both have rolled, reveals the secret. Binding the session and the
+//! player means a commitment copied from the opponent cannot be opened. The dice are seeded from both secrets, so
+//! neither player can know or steer the result before the other has
+//! committed. A player who does not reveal within `REVEAL_TIMEOUT_LEDGERS`
+//! forfeits.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
-    contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror,
+    contractimpl, contracttype, vec, xdr::ToXdr
 };
+use game_hub_interface::{admin, GameAdmin};
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+pub use game_hub_interface::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -48,6 +39,10 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    AlreadyRevealed = 6,
+    InvalidReveal = 7,
+    BothPlayersNotRevealed = 8,
+    DeadlineNotReached = 9,
 }
 
 // ============================================================================
@@ -63,19 +58,28 @@ pub struct Game {
     pub player2_points: i128,
     pub player1_rolled: bool,
     pub player2_rolled: bool,
+    /// `keccak256(session_id ‖ player ‖ secret)` submitted with `roll`
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Secrets opened with `reveal_roll`
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
+    /// Last ledger to reveal in, set once both players have rolled
+    pub reveal_deadline: Option<u32>,
     pub player1_die1: Option<u32>,
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
     pub winner: Option<Address>,
+    pub is_draw: bool,
+    /// Players who did not reveal in time (set by `claim_timeout`)
+    pub forfeited: Vec<Address>,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
@@ -88,6 +92,9 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers both players have to reveal once both have rolled (~1 hour)
+pub const REVEAL_TIMEOUT_LEDGERS: u32 = 720;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -98,6 +105,20 @@ fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
     env.prng().gen_range::<u64>(1..=6) as u32
 }
 
+/// The commitment `roll` expects: `keccak256` of the session id as 4
+/// big-endian bytes, the player address as `ScVal` XDR, then the secret
+fn roll_commitment(env: &Env, session_id: u32, player: &Address, secret: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &session_id.to_be_bytes());
+    preimage.append(&player.clone().to_xdr(env));
+    preimage.append(&Bytes::from(secret.clone()));
+    env.crypto().keccak256(&preimage).into()
+}
+
+/// Whether the game has a result (win, draw or forfeit)
+fn has_ended(game: &Game) -> bool {
+    game.winner.is_some() || game.is_draw || !game.forfeited.is_empty()
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -114,10 +135,7 @@ impl DiceDuelContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -149,15 +167,8 @@ impl DiceDuelContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = admin::hub(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -178,11 +189,18 @@ impl DiceDuelContract {
             player2_points,
             player1_rolled: false,
             player2_rolled: false,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_secret: None,
+            player2_secret: None,
+            reveal_deadline: None,
             player1_die1: None,
             player1_die2: None,
             player2_die1: None,
             player2_die2: None,
             winner: None,
+            is_draw: false,
+            forfeited: Vec::new(&env),
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -200,12 +218,19 @@ impl DiceDuelContract {
     }
 
     /// Commit a roll for the current game.
-    /// Both players must roll before the winner can be revealed.
+    /// Both players must roll, then reveal, before the winner can be revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player rolling the dice
-    pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(session_id ‖ player ‖ secret)` of a 32-byte
+    ///   secret kept off-chain
+    pub fn roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
@@ -216,8 +241,8 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no result yet)
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -227,31 +252,90 @@ impl DiceDuelContract {
                 return Err(Error::AlreadyRolled);
             }
             game.player1_rolled = true;
+            game.player1_commitment = Some(commitment);
         } else if player == game.player2 {
             if game.player2_rolled {
                 return Err(Error::AlreadyRolled);
             }
             game.player2_rolled = true;
+            game.player2_commitment = Some(commitment);
         } else {
             return Err(Error::NotPlayer);
         }
 
+        // Start the reveal window once both players have committed
+        if game.player1_rolled && game.player2_rolled {
+            game.reveal_deadline = Some(env.ledger().sequence() + REVEAL_TIMEOUT_LEDGERS);
+        }
+
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
+    /// Reveal the secret behind a roll commitment.
+    /// Only allowed once both players have rolled, so no secret is public
+    /// while the other player can still choose their commitment.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The secret committed in `roll`, bound to this session and
+    ///   player
+    pub fn reveal_roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.player1_rolled || !game.player2_rolled {
+            return Err(Error::BothPlayersNotRolled);
+        }
+
+        let (commitment, revealed) = if player == game.player1 {
+            (&game.player1_commitment, &mut game.player1_secret)
+        } else if player == game.player2 {
+            (&game.player2_commitment, &mut game.player2_secret)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        if commitment.as_ref() != Some(&roll_commitment(&env, session_id, &player, &secret)) {
+            return Err(Error::InvalidReveal);
+        }
+        *revealed = Some(secret);
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
+    /// Can only be called after both players have revealed their rolls.
     /// This generates dice rolls for both players, determines the winner, and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -260,20 +344,27 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended (has a winner, was drawn or forfeited)
+        if has_ended(&game) {
+            return Ok(game.winner);
         }
 
-        // Check both players have rolled
+        // Check both players have rolled and revealed
         if !game.player1_rolled || !game.player2_rolled {
             return Err(Error::BothPlayersNotRolled);
         }
+        let (Some(player1_secret), Some(player2_secret)) =
+            (game.player1_secret.clone(), game.player2_secret.clone())
+        else {
+            return Err(Error::BothPlayersNotRevealed);
+        };
 
         // Generate deterministic dice rolls (1-6)
         // Seed components (all deterministic and identical between sim/submit):
         // 1. Session ID - unique per game
         // 2. Player addresses - both players contribute
+        // 3. Both revealed secrets - unknown to either player until the
+        //    other has committed
         //
         // Note: We do NOT include ledger sequence or timestamp because those differ
         // between simulation and submission, which would cause different winners.
@@ -281,6 +372,8 @@ impl DiceDuelContract {
         seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
         seed_bytes.append(&game.player1.to_string().to_bytes());
         seed_bytes.append(&game.player2.to_string().to_bytes());
+        seed_bytes.append(&Bytes::from(player1_secret));
+        seed_bytes.append(&Bytes::from(player2_secret));
         let base_seed = env.crypto().keccak256(&seed_bytes);
 
         // Roll dice for both players using unique seeds
@@ -309,130 +402,119 @@ impl DiceDuelContract {
         game.player2_die1 = Some(player2_die1);
         game.player2_die2 = Some(player2_die2);
 
-        // Determine winner (if tie, player1 wins)
+        // Determine winner (equal totals are a draw)
         let player1_total = player1_die1 + player1_die2;
         let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
+        let winner = match player1_total.cmp(&player2_total) {
+            core::cmp::Ordering::Greater => Some(game.player1.clone()),
+            core::cmp::Ordering::Less => Some(game.player2.clone()),
+            core::cmp::Ordering::Equal => None,
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with the result (this marks the game as ended)
+        game.winner = winner.clone();
+        game.is_draw = winner.is_none();
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = admin::hub(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        // Event emitted by the Game Hub contract (GameSettled)
+        let outcome = match &winner {
+            Some(winner) => GameOutcome::Win(vec![&env, winner.clone()]),
+            None => GameOutcome::Draw,
+        };
+        game_hub.end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
 
         Ok(winner)
     }
 
-    /// Get game information.
+    /// Settle a game whose reveal deadline has passed.
+    /// A player who did not reveal forfeits to the one who did; if neither
+    /// revealed the session is cancelled and both stakes are returned. If both
+    /// revealed, this settles the game like `reveal_winner`.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Game` - The game state (includes dice after game ends)
-    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` if there is none
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         let key = DataKey::Game(session_id);
-        env.storage()
+        let mut game: Game = env
+            .storage()
             .temporary()
             .get(&key)
-            .ok_or(Error::GameNotFound)
-    }
+            .ok_or(Error::GameNotFound)?;
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let deadline = game.reveal_deadline.ok_or(Error::BothPlayersNotRolled)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::DeadlineNotReached);
+        }
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
+        let mut quitters = Vec::new(&env);
+        if game.player1_secret.is_none() {
+            quitters.push_back(game.player1.clone());
+        }
+        if game.player2_secret.is_none() {
+            quitters.push_back(game.player2.clone());
+        }
+        if quitters.is_empty() {
+            return Self::reveal_winner(env, session_id);
+        }
 
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        let winner = match quitters.len() {
+            1 if game.player1_secret.is_some() => Some(game.player1.clone()),
+            1 => Some(game.player2.clone()),
+            _ => None,
+        };
+        game.winner = winner.clone();
+        game.forfeited = quitters.clone();
+        env.storage().temporary().set(&key, &game);
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
+        // A single quitter forfeits their stake; if both quit nobody wins
+        let outcome = match winner {
+            Some(_) => GameOutcome::Forfeit(quitters),
+            None => GameOutcome::Cancelled,
+        };
+        admin::hub(&env).end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        Ok(winner)
     }
 
-    /// Set a new GameHub contract address
+    /// Get game information.
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Game` - The game state (includes dice after game ends)
+    pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
+        let key = DataKey::Game(session_id);
         env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)
     }
+}
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+// ============================================================================
+// Admin Functions
+// ============================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-}
+// get_admin, set_admin, get_hub, set_hub and upgrade, shared by every game
+#[contractimpl(contracttrait)]
+impl GameAdmin for DiceDuelContract {}
 
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod test;
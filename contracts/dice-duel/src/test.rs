@@ -2,10 +2,15 @@
 
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
+//
+// Integration tests against mock-game-hub, which escrows points and keeps
+// standings, are at the end of this file.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use crate::{DiceDuelContract, DiceDuelContractClient, Error, GameOutcome, REVEAL_TIMEOUT_LEDGERS};
+use mock_game_hub as hub;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -28,7 +33,7 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    pub fn end_game_with_outcome(_env: Env, _game_id: Address, _session_id: u32, _outcome: GameOutcome) {
         // Mock implementation - does nothing
     }
 
@@ -83,6 +88,32 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// A roll secret and its commitment for `player` in `session_id`:
+/// `keccak256(session_id ‖ player ‖ secret)`
+fn secret(env: &Env, session_id: u32, player: &Address, seed: u8) -> (BytesN<32>, BytesN<32>) {
+    let secret = BytesN::from_array(env, &[seed; 32]);
+    let mut preimage = Bytes::from_array(env, &session_id.to_be_bytes());
+    preimage.append(&player.clone().to_xdr(env));
+    preimage.append(&Bytes::from(secret.clone()));
+    (secret, env.crypto().keccak256(&preimage).into())
+}
+
+/// Both players roll and reveal
+fn roll_and_reveal(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    let (secret1, commitment1) = secret(env, session_id, player1, 1);
+    let (secret2, commitment2) = secret(env, session_id, player2, 2);
+    client.roll(&session_id, player1, &commitment1);
+    client.roll(&session_id, player2, &commitment2);
+    client.reveal_roll(&session_id, player1, &secret1);
+    client.reveal_roll(&session_id, player2, &secret2);
+}
+
 /// Assert that a Result contains a specific dice_duel error
 fn assert_dice_duel_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
@@ -145,18 +176,26 @@ fn test_complete_game() {
     assert!(game.player2_die1.is_none());
     assert!(game.player2_die2.is_none());
 
-    // Players roll
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    // Players roll, then reveal their secrets
+    let (secret1, commitment1) = secret(&_env, session_id, &player1, 1);
+    let (secret2, commitment2) = secret(&_env, session_id, &player2, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_commitment, Some(commitment1));
+    assert_eq!(game.reveal_deadline, Some(100 + REVEAL_TIMEOUT_LEDGERS));
+
+    client.reveal_roll(&session_id, &player1, &secret1);
+    client.reveal_roll(&session_id, &player2, &secret2);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
-    // Verify dice values and winner stored
+    // Verify dice values and result stored
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_eq!(final_game.winner, winner);
+    assert_eq!(final_game.is_draw, winner.is_none());
 
     let p1d1 = final_game.player1_die1.unwrap();
     let p1d2 = final_game.player1_die2.unwrap();
@@ -172,6 +211,13 @@ fn test_complete_game() {
     let total2 = p2d1 + p2d2;
     assert!((2..=12).contains(&total1));
     assert!((2..=12).contains(&total2));
+
+    let expected_winner = match total1.cmp(&total2) {
+        core::cmp::Ordering::Greater => Some(player1),
+        core::cmp::Ordering::Less => Some(player2),
+        core::cmp::Ordering::Equal => None,
+    };
+    assert_eq!(winner, expected_winner);
 }
 
 #[test]
@@ -183,8 +229,9 @@ fn test_cannot_roll_twice() {
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    client.roll(&session_id, &player1);
-    let result = client.try_roll(&session_id, &player1);
+    let (_, commitment) = secret(&_env, session_id, &player1, 1);
+    client.roll(&session_id, &player1, &commitment);
+    let result = client.try_roll(&session_id, &player1, &commitment);
     assert_dice_duel_error(&result, Error::AlreadyRolled);
 }
 
@@ -196,10 +243,101 @@ fn test_cannot_reveal_before_both_roll() {
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
+    let (secret1, commitment1) = secret(&_env, session_id, &player1, 1);
+    client.roll(&session_id, &player1, &commitment1);
 
     let result = client.try_reveal_winner(&session_id);
     assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+
+    // Secrets stay closed until the opponent has committed
+    let result = client.try_reveal_roll(&session_id, &player1, &secret1);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+}
+
+#[test]
+fn test_cannot_reveal_winner_before_both_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 6u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    let (secret1, commitment1) = secret(&env, session_id, &player1, 1);
+    let (_, commitment2) = secret(&env, session_id, &player2, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+    client.reveal_roll(&session_id, &player1, &secret1);
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRevealed);
+
+    let result = client.try_reveal_roll(&session_id, &player1, &secret1);
+    assert_dice_duel_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 7u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    let (_, commitment1) = secret(&env, session_id, &player1, 1);
+    let (secret2, commitment2) = secret(&env, session_id, &player2, 2);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment2);
+
+    // Player 1 cannot open their commitment with a different secret
+    let result = client.try_reveal_roll(&session_id, &player1, &secret2);
+    assert_dice_duel_error(&result, Error::InvalidReveal);
+    assert!(client.get_game(&session_id).player1_secret.is_none());
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_opened() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    // Player 2 mirrors player 1's commitment, then replays their revealed secret
+    let (secret1, commitment1) = secret(&env, session_id, &player1, 1);
+    client.roll(&session_id, &player1, &commitment1);
+    client.roll(&session_id, &player2, &commitment1);
+    client.reveal_roll(&session_id, &player1, &secret1);
+
+    let result = client.try_reveal_roll(&session_id, &player2, &secret1);
+    assert_dice_duel_error(&result, Error::InvalidReveal);
+    assert!(client.get_game(&session_id).player2_secret.is_none());
+}
+
+#[test]
+fn test_dice_depend_on_both_secrets() {
+    let (env, client, game_hub, player1, player2) = setup_test();
+    let admin = client.get_admin();
+
+    // Same session and players in fresh deployments; only player 2's secret changes
+    let session_id = 8u32;
+    let mut first = None;
+    let mut differs = false;
+    for seed in 2..6u8 {
+        let contract_id = env.register(DiceDuelContract, (&admin, &game_hub.address));
+        let client = DiceDuelContractClient::new(&env, &contract_id);
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+
+        let (secret1, commitment1) = secret(&env, session_id, &player1, 1);
+        let (secret2, commitment2) = secret(&env, session_id, &player2, seed);
+        client.roll(&session_id, &player1, &commitment1);
+        client.roll(&session_id, &player2, &commitment2);
+        client.reveal_roll(&session_id, &player1, &secret1);
+        client.reveal_roll(&session_id, &player2, &secret2);
+        client.reveal_winner(&session_id);
+
+        let game = client.get_game(&session_id);
+        let dice = (game.player1_die1, game.player1_die2, game.player2_die1, game.player2_die2);
+        differs |= first.is_some_and(|first| first != dice);
+        first.get_or_insert(dice);
+    }
+    assert!(differs);
 }
 
 #[test]
@@ -212,7 +350,8 @@ fn test_non_player_cannot_roll() {
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
     let non_player = Address::generate(&_env);
-    let result = client.try_roll(&session_id, &non_player);
+    let (_, commitment) = secret(&_env, session_id, &non_player, 1);
+    let result = client.try_roll(&session_id, &non_player, &commitment);
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
@@ -224,11 +363,11 @@ fn test_cannot_roll_after_game_ended() {
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    roll_and_reveal(&_env, &client, session_id, &player1, &player2);
     client.reveal_winner(&session_id);
 
-    let result = client.try_roll(&session_id, &player1);
+    let (_, commitment) = secret(&_env, session_id, &player1, 1);
+    let result = client.try_roll(&session_id, &player1, &commitment);
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
@@ -243,3 +382,206 @@ fn test_upgrade_function_exists() {
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Integration Tests (mock-game-hub)
+// ============================================================================
+
+fn setup_with_hub() -> (
+    Env,
+    DiceDuelContractClient<'static>,
+    hub::MockGameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(hub::MockGameHub, (&admin,));
+    let game_hub = hub::MockGameHubClient::new(&env, &hub_addr);
+
+    let contract_id = env.register(DiceDuelContract, (&admin, &hub_addr));
+    let client = DiceDuelContractClient::new(&env, &contract_id);
+
+    // The hub only opens sessions for registered games
+    game_hub.add_game(&contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, game_hub, player1, player2)
+}
+
+#[test]
+fn test_hub_locks_stakes_and_pays_winner() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &300, &100);
+
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 300);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 100);
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Active);
+
+    roll_and_reveal(&_env, &client, session_id, &player1, &player2);
+    // Equal totals are covered by test_hub_refunds_draw
+    let Some(winner) = client.reveal_winner(&session_id) else { return };
+    let loser = if winner == player1 { player2.clone() } else { player1.clone() };
+    let loser_stake = if winner == player1 { 100 } else { 300 };
+
+    assert_eq!(game_hub.get_balance(&winner), hub::STARTING_POINTS + loser_stake);
+    assert_eq!(game_hub.get_balance(&loser), hub::STARTING_POINTS - loser_stake);
+
+    let session = game_hub.get_session(&client.address, &session_id);
+    assert_eq!(session.status, hub::SessionStatus::Ended);
+    assert_eq!(session.winners.get_unchecked(0), winner);
+
+    let standing = game_hub.get_standing(&winner);
+    assert_eq!((standing.games, standing.wins), (1, 1));
+    assert_eq!(game_hub.get_standing(&loser).losses, 1);
+    assert_eq!(game_hub.get_leaderboard(&10).get_unchecked(0).player, winner);
+}
+
+#[test]
+fn test_hub_rejects_stake_above_balance() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let result = client.try_start_game(
+        &1u32,
+        &player1,
+        &player2,
+        &(hub::STARTING_POINTS + 1),
+        &100,
+    );
+    assert!(result.is_err());
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS);
+}
+
+#[test]
+fn test_hub_rejects_unregistered_game() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    game_hub.remove_game(&client.address);
+    let result = client.try_start_game(&1u32, &player1, &player2, &100, &100);
+    assert!(result.is_err());
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS);
+}
+
+#[test]
+fn test_hub_refunds_draw() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    // Dice are fixed per session, so play sessions until one ties
+    let mut session_id = 0u32;
+    loop {
+        session_id += 1;
+        assert!(session_id <= 100, "no tie in 100 sessions");
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+        roll_and_reveal(&_env, &client, session_id, &player1, &player2);
+        if client.reveal_winner(&session_id).is_none() {
+            break;
+        }
+    }
+
+    let game = client.get_game(&session_id);
+    assert!(game.is_draw);
+    assert!(game.winner.is_none());
+    assert_eq!(
+        game.player1_die1.unwrap() + game.player1_die2.unwrap(),
+        game.player2_die1.unwrap() + game.player2_die2.unwrap()
+    );
+    assert_eq!(game_hub.get_standing(&player1).draws, 1);
+    assert!(game_hub.get_session(&client.address, &session_id).winners.is_empty());
+    let (_, commitment) = secret(&_env, session_id, &player1, 1);
+    assert_eq!(
+        client.try_roll(&session_id, &player1, &commitment),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+}
+
+/// Both players roll and only `revealers` reveal; returns once the reveal
+/// deadline has passed
+fn roll_and_time_out(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    revealers: &[&Address],
+) {
+    let (secret1, commitment1) = secret(env, session_id, player1, 1);
+    let (secret2, commitment2) = secret(env, session_id, player2, 2);
+    client.roll(&session_id, player1, &commitment1);
+    client.roll(&session_id, player2, &commitment2);
+    for revealer in revealers {
+        let secret = if *revealer == player1 { &secret1 } else { &secret2 };
+        client.reveal_roll(&session_id, revealer, secret);
+    }
+
+    assert_eq!(
+        client.try_claim_timeout(&session_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+    let deadline = client.get_game(&session_id).reveal_deadline.unwrap();
+    env.ledger().set_sequence_number(deadline + 1);
+}
+
+#[test]
+fn test_hub_pays_revealer_when_opponent_forfeits() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &300, &100);
+    roll_and_time_out(&env, &client, session_id, &player1, &player2, &[&player2]);
+
+    assert_eq!(client.claim_timeout(&session_id), Some(player2.clone()));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(game.forfeited, vec![&env, player1.clone()]);
+    assert!(game.player1_die1.is_none());
+
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 300);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS + 300);
+    assert_eq!(game_hub.get_standing(&player1).losses, 1);
+    assert_eq!(
+        client.try_claim_timeout(&session_id),
+        Err(Ok(Error::GameAlreadyEnded))
+    );
+    assert_eq!(client.reveal_winner(&session_id), Some(player2));
+}
+
+#[test]
+fn test_hub_cancels_when_nobody_reveals() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &300, &100);
+    roll_and_time_out(&env, &client, session_id, &player1, &player2, &[]);
+
+    assert_eq!(client.claim_timeout(&session_id), None);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.forfeited.len(), 2);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Cancelled);
+    assert_eq!(game_hub.get_standing(&player1).games, 0);
+}
+
+#[test]
+fn test_claim_timeout_settles_when_both_revealed() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    roll_and_time_out(&env, &client, session_id, &player1, &player2, &[&player1, &player2]);
+
+    let winner = client.claim_timeout(&session_id);
+    let game = client.get_game(&session_id);
+    assert!(game.forfeited.is_empty());
+    assert!(game.player1_die1.is_some());
+    assert_eq!(game.winner, winner);
+    assert_ne!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Active);
+}
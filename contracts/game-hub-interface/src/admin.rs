@@ -0,0 +1,72 @@
+//! Admin and GameHub configuration shared by every game contract.
+
+use soroban_sdk::{contracttrait, contracttype, Address, Env};
+
+use crate::GameHubClient;
+
+#[contracttype]
+enum AdminKey {
+    Admin,
+    GameHubAddress,
+}
+
+/// Admin entrypoints shared by every game. A game stores its admin and hub
+/// with [`init`] in its constructor and exports the rest with
+/// `#[contractimpl(contracttrait)] impl GameAdmin for MyGame {}`.
+///
+/// Types are written as full paths because the default bodies are expanded
+/// inside the implementing contract, which may not import them.
+#[contracttrait]
+pub trait GameAdmin {
+    /// Get the current admin address
+    fn get_admin(env: &soroban_sdk::Env) -> soroban_sdk::Address {
+        env.storage()
+            .instance()
+            .get(&AdminKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address (admin only)
+    fn set_admin(env: &soroban_sdk::Env, new_admin: soroban_sdk::Address) {
+        Self::get_admin(env).require_auth();
+        env.storage().instance().set(&AdminKey::Admin, &new_admin);
+    }
+
+    /// Get the current GameHub contract address
+    fn get_hub(env: &soroban_sdk::Env) -> soroban_sdk::Address {
+        hub_address(env).expect("GameHub address not set")
+    }
+
+    /// Set a new GameHub contract address (admin only)
+    fn set_hub(env: &soroban_sdk::Env, new_hub: soroban_sdk::Address) {
+        Self::get_admin(env).require_auth();
+        env.storage()
+            .instance()
+            .set(&AdminKey::GameHubAddress, &new_hub);
+    }
+
+    /// Update the contract WASM hash (admin only)
+    fn upgrade(env: &soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
+        Self::get_admin(env).require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+/// Stores the admin and GameHub address; call from the constructor.
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    env.storage().instance().set(&AdminKey::Admin, admin);
+    env.storage()
+        .instance()
+        .set(&AdminKey::GameHubAddress, game_hub);
+}
+
+/// The configured GameHub address, if any.
+pub fn hub_address(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&AdminKey::GameHubAddress)
+}
+
+/// Client for the configured GameHub.
+pub fn hub(env: &Env) -> GameHubClient<'_> {
+    let address = hub_address(env).expect("GameHub address not set");
+    GameHubClient::new(env, &address)
+}
@@ -0,0 +1,80 @@
+#![no_std]
+
+//! Game Hub protocol shared by every game in the studio.
+//!
+//! Games call the hub through [`GameHubClient`] and report results as a
+//! [`GameOutcome`]; the hub records each game as a [`Session`]. Changing the
+//! protocol here changes it for every game at compile time. The [`admin`]
+//! module provides the admin entrypoints (`get_admin`, `set_admin`,
+//! `get_hub`, `set_hub`, `upgrade`) that every game exports.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+pub mod admin;
+
+pub use admin::GameAdmin;
+
+/// Protocol version a hub reports from `version`; 2 added
+/// `end_game_with_outcome`, 3 scoped session ids to the game.
+pub const HUB_VERSION: u32 = 3;
+
+/// The Game Hub calls a game makes. Sessions are opened by the game
+/// contract itself (`game_id` must authorize) and can only be ended by it.
+/// A session is identified by `(game_id, session_id)`, so each game numbers
+/// its own sessions.
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn start_multiplayer_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    );
+
+    fn end_game_with_outcome(env: Env, game_id: Address, session_id: u32, outcome: GameOutcome);
+}
+
+/// How a session ended. `Win` lists the players who split the pot;
+/// `Forfeit` lists the players who abandoned the game, and everyone else
+/// splits it. `Draw` and `Cancelled` both return every stake, but a
+/// cancelled session is left out of the standings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Win(Vec<Address>),
+    Draw,
+    Cancelled,
+    Forfeit(Vec<Address>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus { Active, Ended, Cancelled }
+
+/// A game session as the hub records it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    /// Points locked from each player, in `players` order.
+    pub stakes: Vec<i128>,
+    pub status: SessionStatus,
+    /// Players who shared the pot; empty for a draw or a cancellation,
+    /// which refund every stake.
+    pub winners: Vec<Address>,
+}
+
+#[cfg(test)]
+mod test;
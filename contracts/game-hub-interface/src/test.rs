@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+// Tests for the shared admin module, through a minimal game contract that
+// stores its admin with `admin::init` and exports `GameAdmin`.
+
+use crate::{admin, GameAdmin, GameOutcome};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, vec, Address, Env};
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        admin::init(&env, &admin, &game_hub);
+    }
+
+    pub fn finish(env: Env, session_id: u32, winner: Address) {
+        let outcome = GameOutcome::Win(vec![&env, winner]);
+        admin::hub(&env).end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
+    }
+}
+
+#[contractimpl(contracttrait)]
+impl GameAdmin for TestGame {}
+
+/// Records the last outcome it was sent.
+#[contract]
+pub struct RecordingHub;
+
+#[contractimpl]
+impl RecordingHub {
+    pub fn end_game_with_outcome(env: Env, _game_id: Address, session_id: u32, outcome: GameOutcome) {
+        env.storage().instance().set(&session_id, &outcome);
+    }
+
+    pub fn outcome(env: Env, session_id: u32) -> GameOutcome {
+        env.storage().instance().get(&session_id).unwrap()
+    }
+}
+
+fn setup(env: &Env) -> (TestGameClient<'_>, Address, Address) {
+    let admin = Address::generate(env);
+    let hub = env.register(RecordingHub, ());
+    let game = env.register(TestGame, (&admin, &hub));
+    (TestGameClient::new(env, &game), admin, hub)
+}
+
+#[test]
+fn test_init_stores_admin_and_hub() {
+    let env = Env::default();
+    let (client, admin, hub) = setup(&env);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), hub);
+}
+
+#[test]
+fn test_set_admin_requires_current_admin() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.set_admin(&new_admin);
+
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_set_admin_without_auth_fails() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert!(client.try_set_admin(&Address::generate(&env)).is_err());
+}
+
+#[test]
+fn test_hub_client_follows_set_hub() {
+    let env = Env::default();
+    let (client, admin, old_hub) = setup(&env);
+    let new_hub = env.register(RecordingHub, ());
+    let winner = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.set_hub(&new_hub);
+    assert_eq!(env.auths()[0].0, admin);
+
+    client.finish(&7, &winner);
+
+    let expected = GameOutcome::Win(vec![&env, winner]);
+    assert_eq!(RecordingHubClient::new(&env, &new_hub).outcome(&7), expected);
+    assert!(RecordingHubClient::new(&env, &old_hub).try_outcome(&7).is_err());
+}
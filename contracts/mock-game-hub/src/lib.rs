@@ -1,15 +1,85 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env, Vec,
+};
+
+pub use game_hub_interface::{GameOutcome, Session, SessionStatus, HUB_VERSION};
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
+/// (start_game, end_game, end_game_draw, and the multiplayer variants) and
+/// backs it with a local points economy: every player has a point balance,
+/// stakes are locked when a session starts and paid to the winners when it
+/// ends, and per-player standings feed a leaderboard. It exists for game
+/// contracts to compile, integrate and test real outcomes during development.
+///
+/// Only games the admin has registered with `add_game` may open sessions,
+/// and a session can only be ended by the game contract that started it.
+/// Session ids are scoped to that game: two games may use the same id.
+/// The legacy `end_game`, `end_game_draw` and `end_multiplayer_game` take
+/// no game: they reach the session opened under an id no other game had in
+/// play. Games that share ids end sessions with `end_game_with_outcome`.
 #[contract]
 pub struct MockGameHub;
 
+/// Balance of a player the hub has not seen before.
+pub const STARTING_POINTS: i128 = 1000_0000000;
+/// Most entries `get_leaderboard` returns.
+pub const MAX_LEADERBOARD: u32 = 50;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const TTL_LEDGERS: u32 = 518_400;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    SessionExists      = 1,
+    SessionNotFound    = 2,
+    SessionEnded       = 3,
+    InsufficientPoints = 4,
+    InvalidStakes      = 5,
+    NotPlayer          = 6,
+    InvalidAmount      = 7,
+    GameNotRegistered  = 8,
+    InvalidOutcome     = 9,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Standing {
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    /// Net points won (negative when losing overall).
+    pub points_won: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub standing: Standing,
+}
+
+#[contracttype]
+enum DataKey {
+    Admin,
+    /// Present for every game contract approved by the admin.
+    Game(Address),
+    Balance(Address),
+    /// Sessions by game contract and the id that game chose.
+    Session(Address, u32),
+    /// Game whose session the legacy end entrypoints reach for an id.
+    LegacySession(u32),
+    Standing(Address),
+    /// Every player who has played a session, in order of first game.
+    Players,
+}
+
 #[contractevent]
 pub struct GameStarted {
     pub session_id: u32,
@@ -23,20 +93,94 @@ pub struct GameStarted {
 #[contractevent]
 pub struct GameEnded {
     pub session_id: u32,
+    pub game_id: Address,
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct GameDrawn {
+    pub session_id: u32,
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameSettled {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub outcome: GameOutcome,
+}
+
+#[contractevent]
+pub struct MultiplayerGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub points: Vec<i128>,
+}
+
+#[contractevent]
+pub struct MultiplayerGameEnded {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub winners: Vec<Address>,
+}
+
 #[contractimpl]
 impl MockGameHub {
-    /// Start a game session
+    /// Initialize the hub with the admin who manages the game registry
+    ///
+    /// # Arguments
+    /// * `admin` - Address allowed to register and remove games
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Approve a game contract to open sessions (admin only)
+    ///
+    /// # Arguments
+    /// * `game_address` - Address of the game contract
+    pub fn add_game(env: Env, game_address: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        let key = DataKey::Game(game_address);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, DAY_IN_LEDGERS, TTL_LEDGERS);
+    }
+
+    /// Withdraw a game's approval (admin only). Its open sessions can
+    /// still be ended.
+    ///
+    /// # Arguments
+    /// * `game_address` - Address of the game contract
+    pub fn remove_game(env: Env, game_address: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().persistent().remove(&DataKey::Game(game_address));
+    }
+
+    pub fn is_game(env: Env, game_address: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Game(game_address))
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Start a game session (registered games only)
     ///
     /// # Arguments
     /// * `game_id` - Address of the game contract calling this method
     /// * `session_id` - Unique identifier for this game session
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount for player 1 (ignored in mock)
-    /// * `player2_points` - Points amount for player 2 (ignored in mock)
+    /// * `player1_points` - Points locked from player 1
+    /// * `player2_points` - Points locked from player 2
     pub fn start_game(
         env: Env,
         game_id: Address,
@@ -45,8 +189,14 @@ impl MockGameHub {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) {
-        // No auth required for mock
+    ) -> Result<(), Error> {
+        Self::open_session(
+            &env,
+            &game_id,
+            session_id,
+            Vec::from_array(&env, [player1.clone(), player2.clone()]),
+            Vec::from_array(&env, [player1_points, player2_points]),
+        )?;
         GameStarted {
             session_id,
             game_id,
@@ -56,26 +206,368 @@ impl MockGameHub {
             player2_points,
         }
         .publish(&env);
-        // bump instance ttl if required
-        env.storage().instance().extend_ttl(17_280, 518_400);
+        Ok(())
     }
 
-    /// End a game session and declare winner
+    /// End a game session and declare winner (starting game only)
     ///
     /// # Arguments
     /// * `session_id` - The game session being ended
     /// * `player1_won` - True if player1 won, false if player2 won
-    pub fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool,
-    ) {
-        // No auth required for mock
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let session = Self::load_legacy_session(&env, session_id)?;
+        let game_id = session.game_id.clone();
+        let winner = session.players.get_unchecked(if player1_won { 0 } else { 1 });
+        let winners = Vec::from_array(&env, [winner]);
+        Self::close_session(&env, session_id, session, winners, SessionStatus::Ended)?;
         GameEnded {
             session_id,
+            game_id,
             player1_won,
         }
         .publish(&env);
+        Ok(())
+    }
+
+    /// End a game session without a winner; every stake is returned
+    /// (starting game only)
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    pub fn end_game_draw(env: Env, session_id: u32) -> Result<(), Error> {
+        let session = Self::load_legacy_session(&env, session_id)?;
+        let game_id = session.game_id.clone();
+        Self::close_session(&env, session_id, session, Vec::new(&env), SessionStatus::Ended)?;
+        GameDrawn { session_id, game_id }.publish(&env);
+        Ok(())
+    }
+
+    /// End a session of any size with an explicit outcome (starting game
+    /// only). Supersedes `end_game`, `end_game_draw` and
+    /// `end_multiplayer_game`, which remain for older games.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract that started the session
+    /// * `session_id` - The game session being ended
+    /// * `outcome` - How the session ended; see `GameOutcome`
+    pub fn end_game_with_outcome(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        outcome: GameOutcome,
+    ) -> Result<(), Error> {
+        let session = Self::load_session(&env, &game_id, session_id)?;
+        let (winners, status) = match &outcome {
+            GameOutcome::Win(winners) => (winners.clone(), SessionStatus::Ended),
+            GameOutcome::Draw => (Vec::new(&env), SessionStatus::Ended),
+            GameOutcome::Cancelled => (Vec::new(&env), SessionStatus::Cancelled),
+            GameOutcome::Forfeit(quitters) => {
+                if quitters.is_empty() {
+                    return Err(Error::InvalidOutcome);
+                }
+                if quitters.iter().any(|q| !session.players.contains(&q)) {
+                    return Err(Error::NotPlayer);
+                }
+                let mut winners = Vec::new(&env);
+                for player in session.players.iter() {
+                    if !quitters.contains(&player) {
+                        winners.push_back(player);
+                    }
+                }
+                (winners, SessionStatus::Ended)
+            }
+        };
+        // Only a draw or a cancellation may end without winners
+        let refund = matches!(outcome, GameOutcome::Draw | GameOutcome::Cancelled);
+        if winners.is_empty() != refund {
+            return Err(Error::InvalidOutcome);
+        }
+
+        Self::close_session(&env, session_id, session, winners, status)?;
+        GameSettled {
+            session_id,
+            game_id,
+            outcome,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Start a game session with more than two players (registered games
+    /// only)
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `players` - Addresses of every player, in seat order
+    /// * `points` - Points locked from each player
+    pub fn start_multiplayer_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        points: Vec<i128>,
+    ) -> Result<(), Error> {
+        Self::open_session(&env, &game_id, session_id, players.clone(), points.clone())?;
+        MultiplayerGameStarted {
+            session_id,
+            game_id,
+            players,
+            points,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// End a multiplayer game session (starting game only)
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `winners` - Every player who won; empty if nobody did, which
+    ///   returns every stake
+    pub fn end_multiplayer_game(
+        env: Env,
+        session_id: u32,
+        winners: Vec<Address>,
+    ) -> Result<(), Error> {
+        let session = Self::load_legacy_session(&env, session_id)?;
+        let game_id = session.game_id.clone();
+        Self::close_session(&env, session_id, session, winners.clone(), SessionStatus::Ended)?;
+        MultiplayerGameEnded {
+            session_id,
+            game_id,
+            winners,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Credit points to a player (local faucet)
+    ///
+    /// # Arguments
+    /// * `player` - Address receiving the points
+    /// * `amount` - Points to add; must be positive
+    pub fn add_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        // No auth required for mock
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let balance = Self::load_balance(&env, &player);
+        Self::store_balance(&env, &player, balance + amount);
+        Ok(())
+    }
+
+    pub fn version(_env: Env) -> u32 {
+        HUB_VERSION
+    }
+
+    /// Points a player can stake; `STARTING_POINTS` until their first game
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        Self::load_balance(&env, &player)
+    }
+
+    pub fn get_session(env: Env, game_id: Address, session_id: u32) -> Result<Session, Error> {
+        Self::load_session(&env, &game_id, session_id)
+    }
+
+    pub fn get_standing(env: Env, player: Address) -> Standing {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Standing(player))
+            .unwrap_or_default()
+    }
+
+    /// Players ranked by wins, then by net points won, at most `limit`
+    /// (capped at `MAX_LEADERBOARD`)
+    pub fn get_leaderboard(env: Env, limit: u32) -> Vec<LeaderboardEntry> {
+        let players: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Players)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut ranked: Vec<LeaderboardEntry> = Vec::new(&env);
+        for player in players.iter() {
+            let entry = LeaderboardEntry {
+                standing: Self::get_standing(env.clone(), player.clone()),
+                player,
+            };
+            let at = ranked
+                .iter()
+                .position(|other| Self::ranks_above(&entry.standing, &other.standing))
+                .map_or(ranked.len(), |i| i as u32);
+            ranked.insert(at, entry);
+        }
+
+        let limit = limit.min(MAX_LEADERBOARD);
+        while ranked.len() > limit {
+            ranked.pop_back();
+        }
+        ranked
+    }
+
+    fn ranks_above(a: &Standing, b: &Standing) -> bool {
+        (a.wins, a.points_won) > (b.wins, b.points_won)
+    }
+
+    fn open_session(
+        env: &Env,
+        game_id: &Address,
+        session_id: u32,
+        players: Vec<Address>,
+        stakes: Vec<i128>,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+        if !env.storage().persistent().has(&DataKey::Game(game_id.clone())) {
+            return Err(Error::GameNotRegistered);
+        }
+
+        let key = DataKey::Session(game_id.clone(), session_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::SessionExists);
+        }
+        if players.len() != stakes.len() || stakes.iter().any(|s| s < 0) {
+            return Err(Error::InvalidStakes);
+        }
+        for (player, stake) in players.iter().zip(stakes.iter()) {
+            if Self::load_balance(env, &player) < stake {
+                return Err(Error::InsufficientPoints);
+            }
+        }
+        for (player, stake) in players.iter().zip(stakes.iter()) {
+            let balance = Self::load_balance(env, &player);
+            Self::store_balance(env, &player, balance - stake);
+        }
+
+        let session = Session {
+            game_id: game_id.clone(),
+            players,
+            stakes,
+            status: SessionStatus::Active,
+            winners: Vec::new(env),
+        };
+        env.storage().persistent().set(&key, &session);
+        env.storage().persistent().extend_ttl(&key, DAY_IN_LEDGERS, TTL_LEDGERS);
+
+        // The id goes to this game unless another game's session with it is
+        // still active
+        let legacy = DataKey::LegacySession(session_id);
+        let holder: Option<Address> = env.storage().persistent().get(&legacy);
+        let held = holder.is_some_and(|other| {
+            Self::load_session(env, &other, session_id)
+                .is_ok_and(|s| s.status == SessionStatus::Active)
+        });
+        if !held {
+            env.storage().persistent().set(&legacy, game_id);
+        }
+        env.storage().persistent().extend_ttl(&legacy, DAY_IN_LEDGERS, TTL_LEDGERS);
+        // bump instance ttl if required
+        env.storage().instance().extend_ttl(DAY_IN_LEDGERS, TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Pays the pot to `winners` in equal parts, the first one also taking
+    /// the remainder, and updates standings. No winners refunds every stake;
+    /// a `Cancelled` session leaves the standings alone.
+    fn close_session(
+        env: &Env,
+        session_id: u32,
+        mut session: Session,
+        winners: Vec<Address>,
+        status: SessionStatus,
+    ) -> Result<(), Error> {
+        session.game_id.require_auth();
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionEnded);
+        }
+        if winners.iter().any(|w| !session.players.contains(&w)) {
+            return Err(Error::NotPlayer);
+        }
+
+        let pot: i128 = session.stakes.iter().sum();
+        let count = winners.len() as i128;
+        let (share, remainder) = if count == 0 { (0, 0) } else { (pot / count, pot % count) };
+
+        for (player, stake) in session.players.iter().zip(session.stakes.iter()) {
+            if status == SessionStatus::Cancelled {
+                let balance = Self::load_balance(env, &player);
+                Self::store_balance(env, &player, balance + stake);
+                continue;
+            }
+            let mut standing = Self::get_standing(env.clone(), player.clone());
+            standing.games += 1;
+            let payout = if count == 0 {
+                standing.draws += 1;
+                stake
+            } else if let Some(i) = winners.first_index_of(&player) {
+                standing.wins += 1;
+                if i == 0 { share + remainder } else { share }
+            } else {
+                standing.losses += 1;
+                0
+            };
+            standing.points_won += payout - stake;
+
+            let balance = Self::load_balance(env, &player);
+            Self::store_balance(env, &player, balance + payout);
+            Self::store_standing(env, &player, &standing);
+        }
+
+        session.status = status;
+        session.winners = winners;
+        let key = DataKey::Session(session.game_id.clone(), session_id);
+        env.storage().persistent().set(&key, &session);
+        env.storage().persistent().extend_ttl(&key, DAY_IN_LEDGERS, TTL_LEDGERS);
+        Ok(())
+    }
+
+    fn load_session(env: &Env, game_id: &Address, session_id: u32) -> Result<Session, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(game_id.clone(), session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    fn load_legacy_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+        let game_id: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LegacySession(session_id))
+            .ok_or(Error::SessionNotFound)?;
+        Self::load_session(env, &game_id, session_id)
+    }
+
+    fn load_balance(env: &Env, player: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(player.clone()))
+            .unwrap_or(STARTING_POINTS)
+    }
+
+    fn store_balance(env: &Env, player: &Address, balance: i128) {
+        let key = DataKey::Balance(player.clone());
+        env.storage().persistent().set(&key, &balance);
+        env.storage().persistent().extend_ttl(&key, DAY_IN_LEDGERS, TTL_LEDGERS);
+    }
+
+    /// Stores a standing, adding the player to the leaderboard on their
+    /// first game.
+    fn store_standing(env: &Env, player: &Address, standing: &Standing) {
+        if standing.games == 1 {
+            let mut players: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Players)
+                .unwrap_or_else(|| Vec::new(env));
+            players.push_back(player.clone());
+            env.storage().persistent().set(&DataKey::Players, &players);
+            env.storage()
+                .persistent()
+                .extend_ttl(&DataKey::Players, DAY_IN_LEDGERS, TTL_LEDGERS);
+        }
+        let key = DataKey::Standing(player.clone());
+        env.storage().persistent().set(&key, standing);
+        env.storage().persistent().extend_ttl(&key, DAY_IN_LEDGERS, TTL_LEDGERS);
     }
 }
 
@@ -83,15 +575,358 @@ impl MockGameHub {
 mod test {
     use super::*;
     use soroban_sdk::testutils::Address as _;
+
+    /// Registers the hub and one approved game, with every auth mocked.
+    fn setup(env: &Env) -> (MockGameHubClient<'_>, Address) {
+        env.mock_all_auths();
+        let admin = Address::generate(env);
+        let contract_id = env.register(MockGameHub, (&admin,));
+        let client = MockGameHubClient::new(env, &contract_id);
+        let game_id = Address::generate(env);
+        client.add_game(&game_id);
+        (client, game_id)
+    }
+
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
-        let client = MockGameHubClient::new(&env, &contract_id);
-        let game_id = Address::generate(&env);
+        let (client, game_id) = setup(&env);
         let player1 = Address::generate(&env);
         let player2 = Address::generate(&env);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
     }
+
+    #[test]
+    fn test_start_and_draw_game() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        client.start_game(&game_id, &3, &player1, &player2, &1000, &1000);
+        client.end_game_draw(&3);
+    }
+
+    #[test]
+    fn test_start_and_end_multiplayer_game() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let players = Vec::from_array(
+            &env,
+            [Address::generate(&env), Address::generate(&env), Address::generate(&env)],
+        );
+        let points = Vec::from_array(&env, [1000, 1000, 1000]);
+        client.start_multiplayer_game(&game_id, &2, &players, &points);
+        client.end_multiplayer_game(&2, &Vec::from_array(&env, [players.get_unchecked(1)]));
+    }
+
+    #[test]
+    fn test_stakes_are_locked_and_paid_to_winner() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        client.start_game(&game_id, &1, &player1, &player2, &300, &100);
+        assert_eq!(client.get_balance(&player1), STARTING_POINTS - 300);
+        assert_eq!(client.get_balance(&player2), STARTING_POINTS - 100);
+        let session = client.get_session(&game_id, &1);
+        assert_eq!(session.status, SessionStatus::Active);
+        assert_eq!(session.game_id, game_id);
+        assert_eq!(session.stakes, Vec::from_array(&env, [300, 100]));
+
+        client.end_game(&1, &false);
+        assert_eq!(client.get_balance(&player1), STARTING_POINTS - 300);
+        assert_eq!(client.get_balance(&player2), STARTING_POINTS + 300);
+        let session = client.get_session(&game_id, &1);
+        assert_eq!(session.status, SessionStatus::Ended);
+        assert_eq!(session.winners, Vec::from_array(&env, [player2.clone()]));
+
+        let standing = client.get_standing(&player2);
+        assert_eq!((standing.games, standing.wins, standing.losses), (1, 1, 0));
+        assert_eq!(standing.points_won, 300);
+        assert_eq!(client.get_standing(&player1).points_won, -300);
+    }
+
+    #[test]
+    fn test_draw_refunds_stakes() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        client.start_game(&game_id, &1, &player1, &player2, &500, &500);
+        client.end_game_draw(&1);
+        assert_eq!(client.get_balance(&player1), STARTING_POINTS);
+        assert_eq!(client.get_balance(&player2), STARTING_POINTS);
+        assert_eq!(client.get_standing(&player1).draws, 1);
+        assert_eq!(client.get_session(&game_id, &1).winners.len(), 0);
+    }
+
+    #[test]
+    fn test_multiplayer_pot_is_split_among_winners() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let players = Vec::from_array(
+            &env,
+            [Address::generate(&env), Address::generate(&env), Address::generate(&env)],
+        );
+        client.start_multiplayer_game(&game_id, &1, &players, &Vec::from_array(&env, [101, 100, 100]));
+
+        let winners = Vec::from_array(&env, [players.get_unchecked(2), players.get_unchecked(0)]);
+        client.end_multiplayer_game(&1, &winners);
+        // 301 split in two: the first winner listed takes the odd point.
+        assert_eq!(client.get_balance(&players.get_unchecked(2)), STARTING_POINTS + 51);
+        assert_eq!(client.get_balance(&players.get_unchecked(0)), STARTING_POINTS + 49);
+        assert_eq!(client.get_balance(&players.get_unchecked(1)), STARTING_POINTS - 100);
+    }
+
+    #[test]
+    fn test_session_errors() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        assert_eq!(
+            client.try_start_game(&game_id, &1, &player1, &player2, &(STARTING_POINTS + 1), &0),
+            Err(Ok(Error::InsufficientPoints))
+        );
+        assert_eq!(
+            client.try_start_game(&game_id, &1, &player1, &player2, &-1, &0),
+            Err(Ok(Error::InvalidStakes))
+        );
+        assert_eq!(client.try_end_game(&1, &true), Err(Ok(Error::SessionNotFound)));
+
+        client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+        assert_eq!(
+            client.try_start_game(&game_id, &1, &player1, &player2, &10, &10),
+            Err(Ok(Error::SessionExists))
+        );
+        assert_eq!(
+            client.try_end_multiplayer_game(&1, &Vec::from_array(&env, [stranger])),
+            Err(Ok(Error::NotPlayer))
+        );
+        client.end_game(&1, &true);
+        assert_eq!(client.try_end_game_draw(&1), Err(Ok(Error::SessionEnded)));
+    }
+
+    #[test]
+    fn test_add_points() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        let player = Address::generate(&env);
+        client.add_points(&player, &5);
+        assert_eq!(client.get_balance(&player), STARTING_POINTS + 5);
+        assert_eq!(client.try_add_points(&player, &0), Err(Ok(Error::InvalidAmount)));
+    }
+
+    #[test]
+    fn test_leaderboard_ranks_by_wins_then_points() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+
+        client.start_game(&game_id, &1, &a, &b, &100, &100);
+        client.end_game(&1, &true);
+        client.start_game(&game_id, &2, &c, &b, &500, &500);
+        client.end_game(&2, &true);
+        client.start_game(&game_id, &3, &a, &c, &10, &10);
+        client.end_game(&3, &true);
+
+        // a: 2 wins, +110; c: 1 win, +490; b: 0 wins, -600.
+        let board = client.get_leaderboard(&10);
+        let order = Vec::from_array(&env, [a.clone(), c.clone(), b.clone()]);
+        assert_eq!(board.len(), 3);
+        for (entry, expected) in board.iter().zip(order.iter()) {
+            assert_eq!(entry.player, expected);
+        }
+        assert_eq!(board.get_unchecked(0).standing.wins, 2);
+        assert_eq!(board.get_unchecked(2).standing.points_won, -600);
+        assert_eq!(client.get_leaderboard(&1).len(), 1);
+    }
+
+    #[test]
+    fn test_only_registered_games_start_sessions() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+        let unknown = Address::generate(&env);
+
+        assert!(client.is_game(&game_id));
+        assert!(!client.is_game(&unknown));
+        assert_eq!(
+            client.try_start_game(&unknown, &1, &player1, &player2, &10, &10),
+            Err(Ok(Error::GameNotRegistered))
+        );
+
+        client.remove_game(&game_id);
+        assert_eq!(
+            client.try_start_game(&game_id, &1, &player1, &player2, &10, &10),
+            Err(Ok(Error::GameNotRegistered))
+        );
+    }
+
+    #[test]
+    fn test_sessions_require_game_auth() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+        assert_eq!(env.auths()[0].0, game_id);
+
+        // Without the starting game's signature the session stays open
+        env.set_auths(&[]);
+        assert!(client.try_end_game(&1, &true).is_err());
+        assert!(client.try_end_game_draw(&1).is_err());
+        assert_eq!(client.get_session(&game_id, &1).status, SessionStatus::Active);
+
+        env.mock_all_auths();
+        client.end_game(&1, &true);
+        assert_eq!(env.auths()[0].0, game_id);
+    }
+
+    #[test]
+    fn test_session_ids_are_scoped_to_the_game() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let other_game = Address::generate(&env);
+        client.add_game(&other_game);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        // Another game taking id 1 first does not block this game's session 1
+        client.start_game(&other_game, &1, &player1, &player2, &10, &10);
+        client.start_game(&game_id, &1, &player1, &player2, &20, &20);
+        assert_eq!(client.get_session(&other_game, &1).stakes, Vec::from_array(&env, [10, 10]));
+        assert_eq!(client.get_session(&game_id, &1).stakes, Vec::from_array(&env, [20, 20]));
+
+        // Ending one leaves the other open
+        let win = GameOutcome::Win(Vec::from_array(&env, [player1.clone()]));
+        env.set_auths(&[]);
+        assert!(client.try_end_game_with_outcome(&other_game, &1, &win).is_err());
+        env.mock_all_auths();
+        client.end_game_with_outcome(&game_id, &1, &win);
+        assert_eq!(env.auths()[0].0, game_id);
+        assert_eq!(client.get_session(&other_game, &1).status, SessionStatus::Active);
+        assert_eq!(
+            client.try_get_session(&Address::generate(&env), &1),
+            Err(Ok(Error::SessionNotFound))
+        );
+    }
+
+    #[test]
+    fn test_legacy_end_reaches_the_game_holding_the_id() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let other_game = Address::generate(&env);
+        client.add_game(&other_game);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        // The first game to open id 1 keeps it while its session is active
+        client.start_game(&other_game, &1, &player1, &player2, &10, &10);
+        client.start_game(&game_id, &1, &player1, &player2, &20, &20);
+        client.end_game(&1, &true);
+        assert_eq!(env.auths()[0].0, other_game);
+        assert_eq!(client.get_session(&other_game, &1).status, SessionStatus::Ended);
+        assert_eq!(client.get_session(&game_id, &1).status, SessionStatus::Active);
+        assert_eq!(client.try_end_game_draw(&1), Err(Ok(Error::SessionEnded)));
+
+        // Once that session is over, the next game to open the id takes it
+        client.start_game(&other_game, &2, &player1, &player2, &10, &10);
+        client.end_game_draw(&2);
+        client.start_game(&game_id, &2, &player1, &player2, &20, &20);
+        client.end_multiplayer_game(&2, &Vec::from_array(&env, [player2.clone()]));
+        assert_eq!(env.auths()[0].0, game_id);
+        assert_eq!(client.get_session(&game_id, &2).winners, Vec::from_array(&env, [player2]));
+    }
+
+    #[test]
+    fn test_registry_requires_admin_auth() {
+        let env = Env::default();
+        let (client, _) = setup(&env);
+        let admin = client.get_admin();
+        let other = Address::generate(&env);
+
+        client.add_game(&other);
+        assert_eq!(env.auths()[0].0, admin);
+
+        env.set_auths(&[]);
+        assert!(client.try_add_game(&Address::generate(&env)).is_err());
+        assert!(client.try_remove_game(&other).is_err());
+        assert!(client.is_game(&other));
+    }
+
+    #[test]
+    fn test_end_game_with_outcome() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        let players = Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]);
+        let stakes = Vec::from_array(&env, [100, 100, 100]);
+
+        client.start_game(&game_id, &1, &a, &b, &100, &100);
+        client.end_game_with_outcome(&game_id, &1, &GameOutcome::Win(Vec::from_array(&env, [b.clone()])));
+        assert_eq!(client.get_balance(&b), STARTING_POINTS + 100);
+
+        client.start_game(&game_id, &2, &a, &b, &100, &100);
+        client.end_game_with_outcome(&game_id, &2, &GameOutcome::Draw);
+        assert_eq!(client.get_balance(&a), STARTING_POINTS - 100);
+        assert_eq!(client.get_standing(&a).draws, 1);
+
+        // c forfeits a three-player session: a and b split its stake
+        client.start_multiplayer_game(&game_id, &3, &players, &stakes);
+        client.end_game_with_outcome(&game_id, &3, &GameOutcome::Forfeit(Vec::from_array(&env, [c.clone()])));
+        assert_eq!(client.get_balance(&a), STARTING_POINTS - 50);
+        assert_eq!(client.get_balance(&c), STARTING_POINTS - 100);
+        assert_eq!(client.get_session(&game_id, &3).winners, Vec::from_array(&env, [a.clone(), b.clone()]));
+
+        // A cancelled session refunds and stays out of the standings
+        let games = client.get_standing(&a).games;
+        client.start_multiplayer_game(&game_id, &4, &players, &stakes);
+        client.end_game_with_outcome(&game_id, &4, &GameOutcome::Cancelled);
+        assert_eq!(client.get_balance(&a), STARTING_POINTS - 50);
+        assert_eq!(client.get_standing(&a).games, games);
+        assert_eq!(client.get_session(&game_id, &4).status, SessionStatus::Cancelled);
+    }
+
+    #[test]
+    fn test_invalid_outcomes() {
+        let env = Env::default();
+        let (client, game_id) = setup(&env);
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        client.start_game(&game_id, &1, &a, &b, &100, &100);
+        assert_eq!(
+            client.try_end_game_with_outcome(&game_id, &1, &GameOutcome::Win(Vec::new(&env))),
+            Err(Ok(Error::InvalidOutcome))
+        );
+        assert_eq!(
+            client.try_end_game_with_outcome(&game_id, &1, &GameOutcome::Forfeit(Vec::new(&env))),
+            Err(Ok(Error::InvalidOutcome))
+        );
+        assert_eq!(
+            client.try_end_game_with_outcome(&game_id, 
+                &1,
+                &GameOutcome::Forfeit(Vec::from_array(&env, [a.clone(), b.clone()]))
+            ),
+            Err(Ok(Error::InvalidOutcome))
+        );
+        assert_eq!(
+            client.try_end_game_with_outcome(&game_id, &1, &GameOutcome::Forfeit(Vec::from_array(&env, [stranger]))),
+            Err(Ok(Error::NotPlayer))
+        );
+        assert_eq!(client.version(), HUB_VERSION);
+    }
 }
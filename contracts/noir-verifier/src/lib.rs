@@ -1,42 +1,49 @@
 #![no_std]
 
-mod vk;
-
 use soroban_sdk::{
     contract, contractimpl, contracterror, contracttype,
-    Bytes, BytesN, Env, Vec, Symbol,
+    Address, Bytes, BytesN, Env, Vec, Symbol,
 };
-use ultrahonk_soroban_verifier::verifier::UltraHonkVerifier;
-
-const VK_HASH_EXPECTED: [u8; 32] = [
-    0x16, 0xbf, 0x49, 0xee, 0x90, 0xd0, 0xff, 0x0b,
-    0x07, 0xb0, 0x7b, 0x49, 0x13, 0xac, 0x22, 0xd9,
-    0x29, 0xe7, 0xe1, 0x62, 0x6d, 0x21, 0x9e, 0x17,
-    0x8d, 0x37, 0x61, 0xa6, 0x15, 0x5b, 0xe8, 0xb7,
-];
+use ultrahonk_soroban_verifier::{verifier::UltraHonkVerifier, VerificationKey};
 
-const PROOF_MIN_LEN: u32 = 64;
-const PROOF_MAX_LEN: u32 = 8192;
-
-const EXPECTED_PUBLIC_INPUTS: u32 = 7;
+pub const CIRCUIT_TTL: u32 = 518_400;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    InvalidProof         = 1,
-    InvalidPublicInputs  = 2,
-    VerificationFailed   = 3,
-    CircuitNotRegistered = 4,
-    VkHashMismatch       = 5,
+    InvalidProof           = 1,
+    InvalidPublicInputs    = 2,
+    VerificationFailed     = 3,
+    CircuitNotRegistered   = 4,
+    VkHashMismatch         = 5,
+    InvalidVerificationKey = 6,
+    CircuitAlreadyExists   = 7,
+    InvalidCircuitStatus   = 8,
 }
 
+/// Lifecycle of a registered circuit. Deprecated circuits still verify so
+/// games in flight can settle; revoked circuits never verify again.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitStatus { Active, Deprecated, Revoked }
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct CircuitInfo {
-    pub circuit_hash: BytesN<32>,
-    pub name:         Symbol,
-    pub version:      u32,
+    pub circuit_hash:      BytesN<32>,
+    pub name:              Symbol,
+    pub version:           u32,
+    pub num_public_inputs: u32,
+    pub status:            CircuitStatus,
+}
+
+#[contracttype]
+enum DataKey {
+    Admin,
+    BundledVk,
+    Circuit(BytesN<32>),
+    Vk(BytesN<32>),
 }
 
 #[contract]
@@ -46,6 +53,17 @@ pub struct NoirVerifier;
 #[allow(deprecated)]
 impl NoirVerifier {
 
+    /// Sets the admin and registers the zk-porrinha key written by
+    /// `bb write_vk` (passed in at deploy), caching its hash for
+    /// [`NoirVerifier::vk_hash`].
+    pub fn __constructor(env: Env, admin: Address, vk: Bytes) -> Result<(), Error> {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        let hash = Self::add_circuit(&env, &vk, soroban_sdk::symbol_short!("zkporr"), 1)?;
+        env.storage().instance().set(&DataKey::BundledVk, &hash);
+        Ok(())
+    }
+
+    /// Verifies `proof` against the circuit registered under `vk_hash`.
     pub fn verify(
         env:           Env,
         proof:         Bytes,
@@ -53,73 +71,180 @@ impl NoirVerifier {
         vk_hash:       BytesN<32>,
     ) -> bool {
 
-        if !Self::check_vk_hash(&vk_hash) {
-            env.events().publish((soroban_sdk::symbol_short!("vk_err"),), "VK hash mismatch");
-            return false;
-        }
+        let info: CircuitInfo = match Self::load_circuit(&env, &vk_hash) {
+            Some(info) => info,
+            None => {
+                env.events().publish((soroban_sdk::symbol_short!("vk_err"),), vk_hash);
+                return false;
+            }
+        };
 
-        if public_inputs.len() != EXPECTED_PUBLIC_INPUTS {
-            env.events().publish((soroban_sdk::symbol_short!("inp_err"),), public_inputs.len());
-            return false;
+        match info.status {
+            CircuitStatus::Active => {}
+            CircuitStatus::Deprecated => {
+                env.events().publish((soroban_sdk::symbol_short!("vk_depr"),), vk_hash.clone());
+            }
+            CircuitStatus::Revoked => {
+                env.events().publish((soroban_sdk::symbol_short!("vk_revoke"),), vk_hash);
+                return false;
+            }
         }
 
-        let proof_len = proof.len();
-        if proof_len < PROOF_MIN_LEN || proof_len > PROOF_MAX_LEN {
-            env.events().publish((soroban_sdk::symbol_short!("size_err"),), proof_len);
+        if public_inputs.len() != info.num_public_inputs {
+            env.events().publish((soroban_sdk::symbol_short!("inp_err"),), public_inputs.len());
             return false;
         }
 
-        let vk_bytes = Bytes::from_array(&env, vk::VK_BYTES);
+        let vk_key = DataKey::Vk(vk_hash);
+        let vk_bytes: Bytes = env.storage().persistent().get(&vk_key).unwrap();
+        env.storage().persistent().extend_ttl(&vk_key, CIRCUIT_TTL, CIRCUIT_TTL);
 
-        let verified = UltraHonkVerifier::verify(&env, &proof, &public_inputs, &vk_bytes);
+        let result = UltraHonkVerifier::verify(&env, &proof, &public_inputs, &vk_bytes);
 
-        if verified {
-            env.events().publish(
+        match result {
+            Ok(()) => env.events().publish(
                 (soroban_sdk::symbol_short!("verified"),),
-                (proof_len, public_inputs.len()),
-            );
-        } else {
-            env.events().publish(
+                (proof.len(), public_inputs.len()),
+            ),
+            Err(e) if e.is_malformed() => env.events().publish(
+                (soroban_sdk::symbol_short!("bad_input"),),
+                e as u32,
+            ),
+            Err(e) => env.events().publish(
                 (soroban_sdk::symbol_short!("zk_fail"),),
-                proof_len,
-            );
+                e as u32,
+            ),
         }
 
-        verified
+        result.is_ok()
     }
 
+    /// SHA-256 of the zk-porrinha key, computed on-chain at construction.
     pub fn vk_hash(env: Env) -> BytesN<32> {
-        BytesN::from_array(&env, &VK_HASH_EXPECTED)
+        env.storage()
+            .instance()
+            .get(&DataKey::BundledVk)
+            .expect("Bundled VK not registered")
     }
 
-    pub fn vk_info(_env: Env) -> (u32, u32, u32) {
-        (vk::VK_CIRCUIT_SIZE, vk::VK_NUM_PUBLIC_INPUTS, 7)
+    /// Circuit size, public input count and registered version of the
+    /// zk-porrinha key.
+    pub fn vk_info(env: Env) -> Result<(u32, u32, u32), Error> {
+        let vk_hash = Self::vk_hash(env.clone());
+        let info = Self::load_circuit(&env, &vk_hash).ok_or(Error::CircuitNotRegistered)?;
+        let vk_bytes = Self::get_vk(env.clone(), vk_hash).ok_or(Error::CircuitNotRegistered)?;
+        let vk = VerificationKey::from_bytes(&env, &vk_bytes)
+            .map_err(|_| Error::InvalidVerificationKey)?;
+        Ok((vk.circuit_size as u32, info.num_public_inputs, info.version))
     }
 
-    pub fn register_circuit(env: Env, circuit_hash: BytesN<32>, name: Symbol, version: u32) {
-        let info = CircuitInfo { circuit_hash: circuit_hash.clone(), name: name.clone(), version };
-        env.storage().instance().set(&circuit_hash, &info);
-        env.events().publish(
-            (soroban_sdk::symbol_short!("circuit"),),
-            (circuit_hash, name, version),
-        );
+    /// Stores a `bb write_vk` verification key under its SHA-256 hash and
+    /// returns that hash. The public input count is read from the key header.
+    /// Admin only; a hash can be registered once.
+    pub fn register_circuit(
+        env:     Env,
+        vk:      Bytes,
+        name:    Symbol,
+        version: u32,
+    ) -> Result<BytesN<32>, Error> {
+        Self::get_admin(env.clone()).require_auth();
+        Self::add_circuit(&env, &vk, name, version)
+    }
+
+    /// Marks an active circuit as deprecated. Admin only.
+    pub fn deprecate_circuit(env: Env, circuit_hash: BytesN<32>) -> Result<(), Error> {
+        Self::set_status(&env, circuit_hash, CircuitStatus::Deprecated)
+    }
+
+    /// Permanently disables an active or deprecated circuit. Admin only.
+    pub fn revoke_circuit(env: Env, circuit_hash: BytesN<32>) -> Result<(), Error> {
+        Self::set_status(&env, circuit_hash, CircuitStatus::Revoked)
     }
 
     pub fn get_circuit(env: Env, circuit_hash: BytesN<32>) -> Option<CircuitInfo> {
-        env.storage().instance().get(&circuit_hash)
+        Self::load_circuit(&env, &circuit_hash)
+    }
+
+    pub fn get_vk(env: Env, circuit_hash: BytesN<32>) -> Option<Bytes> {
+        env.storage().persistent().get(&DataKey::Vk(circuit_hash))
     }
 
     pub fn version(_env: Env) -> u32 { 7 }
 
-    fn check_vk_hash(provided: &BytesN<32>) -> bool {
-        let mut ok = true;
-        for i in 0..32u32 {
-            let got = provided.get(i).unwrap_or(0xff);
-            if got != VK_HASH_EXPECTED[i as usize] {
-                ok = false;
-            }
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Self::get_admin(env.clone()).require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    fn add_circuit(env: &Env, vk: &Bytes, name: Symbol, version: u32) -> Result<BytesN<32>, Error> {
+        let parsed = VerificationKey::from_bytes(env, vk)
+            .map_err(|_| Error::InvalidVerificationKey)?;
+        let circuit_hash: BytesN<32> = env.crypto().sha256(vk).into();
+        if env.storage().persistent().has(&DataKey::Circuit(circuit_hash.clone())) {
+            return Err(Error::CircuitAlreadyExists);
+        }
+
+        let info = CircuitInfo {
+            circuit_hash:      circuit_hash.clone(),
+            name:              name.clone(),
+            version,
+            num_public_inputs: parsed.num_public_inputs(),
+            status:            CircuitStatus::Active,
+        };
+        Self::save_circuit(env, &info);
+        Self::save_vk(env, &circuit_hash, vk);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("circuit"),),
+            (circuit_hash.clone(), name, version),
+        );
+        Ok(circuit_hash)
+    }
+
+    fn set_status(env: &Env, circuit_hash: BytesN<32>, status: CircuitStatus) -> Result<(), Error> {
+        Self::get_admin(env.clone()).require_auth();
+        let mut info = Self::load_circuit(env, &circuit_hash).ok_or(Error::CircuitNotRegistered)?;
+        let allowed = match status {
+            CircuitStatus::Deprecated => info.status == CircuitStatus::Active,
+            CircuitStatus::Revoked    => info.status != CircuitStatus::Revoked,
+            CircuitStatus::Active     => false,
+        };
+        if !allowed {
+            return Err(Error::InvalidCircuitStatus);
         }
-        ok
+        info.status = status;
+        Self::save_circuit(env, &info);
+        env.events().publish(
+            (soroban_sdk::symbol_short!("vk_status"),),
+            (circuit_hash, status),
+        );
+        Ok(())
+    }
+
+    fn load_circuit(env: &Env, circuit_hash: &BytesN<32>) -> Option<CircuitInfo> {
+        let key = DataKey::Circuit(circuit_hash.clone());
+        let info = env.storage().persistent().get(&key)?;
+        env.storage().persistent().extend_ttl(&key, CIRCUIT_TTL, CIRCUIT_TTL);
+        Some(info)
+    }
+
+    fn save_circuit(env: &Env, info: &CircuitInfo) {
+        let key = DataKey::Circuit(info.circuit_hash.clone());
+        env.storage().persistent().set(&key, info);
+        env.storage().persistent().extend_ttl(&key, CIRCUIT_TTL, CIRCUIT_TTL);
+    }
+
+    fn save_vk(env: &Env, circuit_hash: &BytesN<32>, vk: &Bytes) {
+        let key = DataKey::Vk(circuit_hash.clone());
+        env.storage().persistent().set(&key, vk);
+        env.storage().persistent().extend_ttl(&key, CIRCUIT_TTL, CIRCUIT_TTL);
     }
 }
 
@@ -127,28 +252,70 @@ impl NoirVerifier {
 #[allow(deprecated)]
 mod test {
     use super::*;
-    use soroban_sdk::{vec, Env, BytesN, Bytes};
+    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+    use soroban_sdk::{vec, Env, BytesN, Bytes, IntoVal};
+    use ultrahonk_soroban_verifier::{PROOF_BYTES, VK_BYTES};
 
     fn make_env() -> (Env, soroban_sdk::Address) {
         let env = Env::default();
-        let id  = env.register(NoirVerifier, ());
+        env.cost_estimate().budget().reset_unlimited();
+        env.mock_all_auths();
+        let admin = soroban_sdk::Address::generate(&env);
+        let id    = env.register(NoirVerifier, (&admin, zk_porrinha_vk(&env)));
         (env, id)
     }
 
+    /// Stand-in for the zk-porrinha key passed at construction: a circuit
+    /// of size 4096 with 7 public inputs.
+    fn zk_porrinha_vk(env: &Env) -> Bytes {
+        let mut vk = [0u8; VK_BYTES];
+        vk[..8].copy_from_slice(&4096u64.to_be_bytes());
+        vk[8..16].copy_from_slice(&12u64.to_be_bytes());
+        vk[16..24].copy_from_slice(&23u64.to_be_bytes());
+        vk[24..32].copy_from_slice(&1u64.to_be_bytes());
+        Bytes::from_array(env, &vk)
+    }
+
+    /// Key for a circuit of size 8192 whose commitments are all at infinity.
+    /// Together with an all-zero proof every check in the protocol holds.
+    fn trivial_vk(env: &Env, num_public_inputs: u64) -> Bytes {
+        let mut vk = [0u8; VK_BYTES];
+        vk[..8].copy_from_slice(&8192u64.to_be_bytes());
+        vk[8..16].copy_from_slice(&13u64.to_be_bytes());
+        vk[16..24].copy_from_slice(&(num_public_inputs + 16).to_be_bytes());
+        vk[24..32].copy_from_slice(&1u64.to_be_bytes());
+        Bytes::from_array(env, &vk)
+    }
+
+    fn trivial_proof(env: &Env) -> Bytes {
+        Bytes::from_array(env, &[0u8; PROOF_BYTES])
+    }
+
+    fn field(env: &Env, v: u8) -> BytesN<32> {
+        let mut b = [0u8; 32];
+        b[31] = v;
+        BytesN::from_array(env, &b)
+    }
+
     fn make_public_inputs(env: &Env) -> Vec<BytesN<32>> {
         vec![env,
-            BytesN::from_array(env, &[0xaa_u8; 32]),                              // h1
-            BytesN::from_array(env, &[0xbb_u8; 32]),                              // h2
-            BytesN::from_array(env, &{ let mut b = [0u8;32]; b[31]=1; b }),       // parity1
-            BytesN::from_array(env, &[0u8; 32]),                                   // parity2
-            BytesN::from_array(env, &{ let mut b = [0u8;32]; b[31]=3; b }),       // exact1
-            BytesN::from_array(env, &{ let mut b = [0u8;32]; b[31]=4; b }),       // exact2
-            BytesN::from_array(env, &{ let mut b = [0u8;32]; b[31]=7; b }),       // total_sum
+            field(env, 0xaa), // h1
+            field(env, 0xbb), // h2
+            field(env, 1),    // parity1
+            field(env, 0),    // parity2
+            field(env, 3),    // exact1
+            field(env, 4),    // exact2
+            field(env, 7),    // total_sum
         ]
     }
 
-    fn correct_vk_hash(env: &Env) -> BytesN<32> {
-        BytesN::from_array(env, &VK_HASH_EXPECTED)
+    fn register(c: &NoirVerifierClient, vk: &Bytes) -> BytesN<32> {
+        c.register_circuit(vk, &soroban_sdk::symbol_short!("zkporr"), &1)
+    }
+
+    fn sha256(env: &Env, bytes: &[u8]) -> BytesN<32> {
+        use sha2::{Digest, Sha256};
+        BytesN::from_array(env, &Sha256::digest(bytes).into())
     }
 
     #[test]
@@ -161,7 +328,19 @@ mod test {
     fn test_vk_hash_getter() {
         let (env, id) = make_env();
         let got = NoirVerifierClient::new(&env, &id).vk_hash();
-        assert_eq!(got, correct_vk_hash(&env));
+        let mut raw = [0u8; VK_BYTES];
+        zk_porrinha_vk(&env).copy_into_slice(&mut raw);
+        assert_eq!(got, sha256(&env, &raw));
+    }
+
+    #[test]
+    fn test_bundled_circuit_is_registered() {
+        let (env, id) = make_env();
+        let c    = NoirVerifierClient::new(&env, &id);
+        let info = c.get_circuit(&c.vk_hash()).unwrap();
+        assert_eq!(info.status, CircuitStatus::Active);
+        assert_eq!(info.num_public_inputs, 7);
+        assert_eq!(c.get_vk(&c.vk_hash()), Some(zk_porrinha_vk(&env)));
     }
 
     #[test]
@@ -170,67 +349,175 @@ mod test {
         let (size, npub, ver) = NoirVerifierClient::new(&env, &id).vk_info();
         assert_eq!(size, 4096);
         assert_eq!(npub, 7);
-        assert_eq!(ver,  7);
+        assert_eq!(ver,  1);
+    }
+
+    #[test]
+    fn test_register_and_get_circuit() {
+        let (env, id) = make_env();
+        let c  = NoirVerifierClient::new(&env, &id);
+        let vk = trivial_vk(&env, 7);
+
+        let hash = register(&c, &vk);
+        let mut raw = [0u8; VK_BYTES];
+        vk.copy_into_slice(&mut raw);
+        assert_eq!(hash, sha256(&env, &raw));
+
+        let info = c.get_circuit(&hash).unwrap();
+        assert_eq!(info.circuit_hash, hash);
+        assert_eq!(info.version, 1);
+        assert_eq!(info.num_public_inputs, 7);
+        assert_eq!(c.get_vk(&hash), Some(vk));
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_vk() {
+        let (env, id) = make_env();
+        let c = NoirVerifierClient::new(&env, &id);
+        let bad = Bytes::from_array(&env, &[0u8; 64]);
+        let res = c.try_register_circuit(&bad, &soroban_sdk::symbol_short!("bad"), &1);
+        assert_eq!(res, Err(Ok(Error::InvalidVerificationKey)));
+    }
+
+    #[test]
+    fn test_verifies_registered_circuit() {
+        let (env, id) = make_env();
+        let c   = NoirVerifierClient::new(&env, &id);
+        let vkh = register(&c, &trivial_vk(&env, 7));
+        assert!(c.verify(&trivial_proof(&env), &make_public_inputs(&env), &vkh));
+    }
+
+    #[test]
+    fn test_serves_multiple_circuits() {
+        let (env, id) = make_env();
+        let c      = NoirVerifierClient::new(&env, &id);
+        let seven  = register(&c, &trivial_vk(&env, 7));
+        let two    = register(&c, &trivial_vk(&env, 2));
+        assert_ne!(seven, two);
+
+        let pair = vec![&env, field(&env, 1), field(&env, 2)];
+        assert!(c.verify(&trivial_proof(&env), &pair, &two));
+        assert!(!c.verify(&trivial_proof(&env), &pair, &seven));
+        assert!(c.verify(&trivial_proof(&env), &make_public_inputs(&env), &seven));
     }
 
     #[test]
-    fn test_rejects_wrong_vk_hash() {
+    fn test_rejects_unregistered_vk_hash() {
         let (env, id) = make_env();
         let c = NoirVerifierClient::new(&env, &id);
+        register(&c, &trivial_vk(&env, 7));
         let bad = BytesN::from_array(&env, &[0x00_u8; 32]);
-        let proof = Bytes::from_array(&env, &[0xab_u8; 2144]);
-        assert!(!c.verify(&proof, &make_public_inputs(&env), &bad));
+        assert!(!c.verify(&trivial_proof(&env), &make_public_inputs(&env), &bad));
     }
 
     #[test]
     fn test_rejects_wrong_input_count() {
         let (env, id) = make_env();
         let c   = NoirVerifierClient::new(&env, &id);
-        let vkh = correct_vk_hash(&env);
-        let p   = Bytes::from_array(&env, &[0xab_u8; 2144]);
+        let vkh = register(&c, &trivial_vk(&env, 7));
         let six = vec![&env,
-            BytesN::from_array(&env, &[1u8;32]),
-            BytesN::from_array(&env, &[2u8;32]),
-            BytesN::from_array(&env, &[3u8;32]),
-            BytesN::from_array(&env, &[4u8;32]),
-            BytesN::from_array(&env, &[5u8;32]),
-            BytesN::from_array(&env, &[6u8;32]),
+            field(&env, 1),
+            field(&env, 2),
+            field(&env, 3),
+            field(&env, 4),
+            field(&env, 5),
+            field(&env, 6),
         ];
-        assert!(!c.verify(&p, &six, &vkh));
+        assert!(!c.verify(&trivial_proof(&env), &six, &vkh));
     }
 
     #[test]
     fn test_rejects_empty_proof() {
         let (env, id) = make_env();
-        let c = NoirVerifierClient::new(&env, &id);
-        assert!(!c.verify(
-            &Bytes::new(&env),
-            &make_public_inputs(&env),
-            &correct_vk_hash(&env),
-        ));
+        let c   = NoirVerifierClient::new(&env, &id);
+        let vkh = register(&c, &trivial_vk(&env, 7));
+        assert!(!c.verify(&Bytes::new(&env), &make_public_inputs(&env), &vkh));
     }
 
     #[test]
-    fn test_register_and_get_circuit() {
+    fn test_register_requires_admin() {
+        let env   = Env::default();
+        let admin = soroban_sdk::Address::generate(&env);
+        let other = soroban_sdk::Address::generate(&env);
+        let id    = env.register(NoirVerifier, (&admin, zk_porrinha_vk(&env)));
+        let c     = NoirVerifierClient::new(&env, &id);
+        let vk    = trivial_vk(&env, 7);
+        let name  = soroban_sdk::symbol_short!("zkporr");
+
+        env.mock_auths(&[MockAuth {
+            address: &other,
+            invoke: &MockAuthInvoke {
+                contract: &id,
+                fn_name: "register_circuit",
+                args: (&vk, &name, &1u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(c.try_register_circuit(&vk, &name, &1).is_err());
+
+        env.mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &id,
+                fn_name: "register_circuit",
+                args: (&vk, &name, &1u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(c.try_register_circuit(&vk, &name, &1).is_ok());
+    }
+
+    #[test]
+    fn test_register_twice_fails() {
         let (env, id) = make_env();
-        let c    = NoirVerifierClient::new(&env, &id);
-        let hash = BytesN::from_array(&env, &[0x01u8; 32]);
-        let name = soroban_sdk::symbol_short!("zkporr");
-        c.register_circuit(&hash, &name, &7);
-        let info = c.get_circuit(&hash);
-        assert!(info.is_some());
-        assert_eq!(info.unwrap().version, 7);
+        let c  = NoirVerifierClient::new(&env, &id);
+        let vk = trivial_vk(&env, 7);
+        register(&c, &vk);
+        let res = c.try_register_circuit(&vk, &soroban_sdk::symbol_short!("again"), &2);
+        assert_eq!(res, Err(Ok(Error::CircuitAlreadyExists)));
     }
 
     #[test]
-    fn test_vk_file_hash_matches_constant() {
-        use sha2::{Digest, Sha256};
-        let bytes: &[u8] = include_bytes!("../../../circuits/zk-porrinha/target/vk");
-        let mut hasher = Sha256::new();
-        hasher.update(bytes);
-        let result = hasher.finalize();
-        let mut arr = [0u8; 32];
-        arr.copy_from_slice(&result);
-        assert_eq!(arr, VK_HASH_EXPECTED, "local vk file hash does not match embedded constant");
-    }
-}
\ No newline at end of file
+    fn test_deprecated_circuit_still_verifies() {
+        let (env, id) = make_env();
+        let c   = NoirVerifierClient::new(&env, &id);
+        let vkh = register(&c, &trivial_vk(&env, 7));
+        c.deprecate_circuit(&vkh);
+        assert_eq!(c.get_circuit(&vkh).unwrap().status, CircuitStatus::Deprecated);
+        assert!(c.verify(&trivial_proof(&env), &make_public_inputs(&env), &vkh));
+    }
+
+    #[test]
+    fn test_revoked_circuit_rejected() {
+        let (env, id) = make_env();
+        let c   = NoirVerifierClient::new(&env, &id);
+        let vkh = register(&c, &trivial_vk(&env, 7));
+        c.revoke_circuit(&vkh);
+        assert_eq!(c.get_circuit(&vkh).unwrap().status, CircuitStatus::Revoked);
+        assert!(!c.verify(&trivial_proof(&env), &make_public_inputs(&env), &vkh));
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        let (env, id) = make_env();
+        let c   = NoirVerifierClient::new(&env, &id);
+        let vkh = register(&c, &trivial_vk(&env, 7));
+        let unknown = BytesN::from_array(&env, &[9u8; 32]);
+
+        assert_eq!(c.try_deprecate_circuit(&unknown), Err(Ok(Error::CircuitNotRegistered)));
+        c.deprecate_circuit(&vkh);
+        assert_eq!(c.try_deprecate_circuit(&vkh), Err(Ok(Error::InvalidCircuitStatus)));
+        c.revoke_circuit(&vkh);
+        assert_eq!(c.try_revoke_circuit(&vkh), Err(Ok(Error::InvalidCircuitStatus)));
+        assert_eq!(c.try_deprecate_circuit(&vkh), Err(Ok(Error::InvalidCircuitStatus)));
+    }
+
+    #[test]
+    fn test_set_admin() {
+        let (env, id) = make_env();
+        let c         = NoirVerifierClient::new(&env, &id);
+        let new_admin = soroban_sdk::Address::generate(&env);
+        c.set_admin(&new_admin);
+        assert_eq!(c.get_admin(), new_admin);
+    }
+}
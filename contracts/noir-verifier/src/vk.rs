@@ -1,204 +0,0 @@
-// Auto-generated by scripts/gen-vk-rs.mjs
-// VK do circuito zk-porrinha (Barretenberg UltraPlonk, Noir 0.36)
-// Nao editar manualmente.
-
-#![allow(dead_code)]
-
-/// Tamanho do circuito (potencia de 2)
-pub const VK_CIRCUIT_SIZE: u32 = 4096;
-
-/// Numero de public inputs do circuito
-pub const VK_NUM_PUBLIC_INPUTS: u32 = 7;
-
-/// Commitment G1 para polinomio ID_1 (x||y, 32+32 bytes, big-endian)
-pub const VK_ID_1: [u8; 64] = [
-    0x21, 0x92, 0xf0, 0xc0, 0xd5, 0x02, 0xbd, 0x52, 0x2e, 0x9e, 0xd5, 0x28, 0x98, 0x26, 0xc7, 0x28,
-    0xb1, 0x4b, 0xf6, 0x0a, 0x64, 0xd7, 0x7a, 0xd1, 0x18, 0xc6, 0x6b, 0xe5, 0x06, 0xf3, 0xd4, 0x22,
-    0x1f, 0xa3, 0x8f, 0x55, 0xf1, 0x80, 0x8c, 0x22, 0x12, 0x6f, 0x17, 0xf2, 0xf7, 0xe1, 0xea, 0xcf,
-    0x6c, 0x5d, 0x95, 0xbc, 0xdd, 0x78, 0xcd, 0x59, 0x69, 0x78, 0xb3, 0xb6, 0x9f, 0x8a, 0x43, 0x1b
-];
-
-/// Commitment G1 para polinomio ID_2 (x||y, 32+32 bytes, big-endian)
-pub const VK_ID_2: [u8; 64] = [
-    0x00, 0x73, 0xa8, 0xd4, 0x2d, 0xd1, 0x13, 0x36, 0x98, 0xa0, 0x03, 0x3b, 0x32, 0xfa, 0xca, 0xaa,
-    0x92, 0xfa, 0x4f, 0x1c, 0x70, 0xd9, 0x77, 0x9b, 0x62, 0xe2, 0x35, 0xa4, 0x6a, 0xb9, 0xf8, 0x53,
-    0x0d, 0x8b, 0xe5, 0x97, 0x40, 0x82, 0x63, 0x77, 0x73, 0x9a, 0xf0, 0x02, 0x5f, 0x36, 0x80, 0x9b,
-    0xe8, 0x85, 0x88, 0x8d, 0x1b, 0x69, 0xff, 0xc6, 0xac, 0xda, 0xba, 0xb1, 0xe7, 0x85, 0x9a, 0x69
-];
-
-/// Commitment G1 para polinomio ID_3 (x||y, 32+32 bytes, big-endian)
-pub const VK_ID_3: [u8; 64] = [
-    0x06, 0x02, 0x81, 0x7c, 0xb0, 0xb8, 0x29, 0x57, 0xc8, 0xff, 0x4f, 0x37, 0x50, 0x94, 0xde, 0x3a,
-    0x50, 0x2b, 0xc5, 0x60, 0xa0, 0xcf, 0xb8, 0x39, 0x42, 0x90, 0x14, 0xf0, 0x7c, 0x40, 0x98, 0x51,
-    0x0c, 0xed, 0xa6, 0xec, 0x33, 0x44, 0xc0, 0x6b, 0xd1, 0x2b, 0x82, 0xa8, 0x54, 0xce, 0x2c, 0x0c,
-    0x11, 0x1e, 0x59, 0xaa, 0x21, 0x1c, 0x2b, 0xd8, 0x53, 0xc3, 0xef, 0x22, 0xe3, 0x02, 0xc5, 0x57
-];
-
-/// Commitment G1 para polinomio ID_4 (x||y, 32+32 bytes, big-endian)
-pub const VK_ID_4: [u8; 64] = [
-    0x13, 0x3d, 0xb9, 0x59, 0x55, 0x03, 0xf4, 0x7b, 0x0a, 0x9b, 0x1c, 0x38, 0xd3, 0xa1, 0xa5, 0xef,
-    0x6b, 0xa5, 0xa4, 0xf5, 0xad, 0x36, 0xef, 0x14, 0x89, 0xe2, 0x26, 0x42, 0xcb, 0xdd, 0x23, 0x1d,
-    0x0b, 0x16, 0xac, 0xbb, 0x1a, 0xef, 0x60, 0x61, 0xce, 0xb7, 0x19, 0x33, 0x34, 0x3c, 0xd0, 0x0c,
-    0xae, 0x02, 0x79, 0x48, 0xd0, 0x63, 0x3c, 0x98, 0xee, 0xb5, 0xae, 0xf4, 0x8e, 0x3b, 0x3d, 0xdc
-];
-
-/// Commitment G1 para polinomio Q_1 (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_1: [u8; 64] = [
-    0x16, 0xb4, 0xb9, 0x8e, 0xfd, 0x0e, 0x57, 0x4d, 0xde, 0x30, 0x4d, 0xaa, 0xc6, 0x25, 0x9b, 0xda,
-    0x0e, 0x65, 0x18, 0x10, 0x64, 0x2a, 0xf7, 0xd2, 0x59, 0x19, 0x87, 0x9b, 0x05, 0x31, 0x63, 0xd1,
-    0x0a, 0xcf, 0x33, 0x98, 0x61, 0x1d, 0xe5, 0x32, 0x01, 0xf9, 0x5a, 0xb4, 0xa8, 0xfb, 0x47, 0x0f,
-    0xbf, 0x8f, 0x6a, 0xe1, 0x74, 0x01, 0xc2, 0x6a, 0x76, 0xe8, 0x1e, 0x0b, 0x7c, 0x9a, 0x2e, 0xd5
-];
-
-/// Commitment G1 para polinomio Q_2 (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_2: [u8; 64] = [
-    0x05, 0x45, 0x88, 0xf2, 0x85, 0x25, 0xbd, 0x2b, 0x88, 0xb5, 0x5c, 0x35, 0xea, 0xe0, 0x29, 0xdb,
-    0x82, 0x34, 0x47, 0xb3, 0x75, 0x14, 0xcc, 0x24, 0x40, 0xff, 0x24, 0x14, 0xc2, 0x4d, 0x79, 0x33,
-    0x23, 0xd8, 0x55, 0xf7, 0x78, 0xab, 0x6d, 0x4e, 0x7d, 0xff, 0xf3, 0x0c, 0x31, 0xaf, 0x6f, 0xf0,
-    0xec, 0xb4, 0xa5, 0x31, 0xe9, 0x41, 0x5b, 0x1f, 0x2c, 0xeb, 0x46, 0x72, 0x3b, 0x98, 0xb1, 0xed
-];
-
-/// Commitment G1 para polinomio Q_3 (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_3: [u8; 64] = [
-    0x01, 0xb8, 0xe1, 0x01, 0xca, 0x79, 0x47, 0xbd, 0xe9, 0x22, 0xa2, 0x01, 0x96, 0xa7, 0x69, 0x4f,
-    0xf0, 0x87, 0xda, 0xfd, 0x3b, 0xc2, 0xbc, 0xb4, 0xe4, 0xe3, 0x39, 0x47, 0x97, 0xc6, 0x27, 0xe2,
-    0x1c, 0xe9, 0x1e, 0xef, 0x83, 0x79, 0xd0, 0xc8, 0xe3, 0xff, 0x8a, 0xc0, 0x98, 0x54, 0x75, 0x46,
-    0x79, 0xd1, 0x7c, 0x4c, 0x8d, 0x09, 0xf6, 0x47, 0x97, 0x19, 0xc0, 0x28, 0xc9, 0x7d, 0x00, 0xa2
-];
-
-/// Commitment G1 para polinomio Q_4 (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_4: [u8; 64] = [
-    0x02, 0x92, 0x13, 0xb1, 0x71, 0xea, 0x89, 0x8a, 0xb2, 0x33, 0x64, 0x45, 0xb2, 0xbd, 0x07, 0xe8,
-    0x42, 0x60, 0x96, 0x3f, 0x3e, 0x10, 0x15, 0x12, 0xcf, 0xb1, 0xc4, 0x90, 0x6c, 0xc9, 0xf0, 0xf4,
-    0x25, 0x40, 0x37, 0x81, 0x7c, 0x40, 0xd3, 0xdc, 0x58, 0x0a, 0xe9, 0xce, 0xa3, 0x51, 0xb8, 0x09,
-    0x33, 0xe0, 0x40, 0x19, 0x2a, 0x5c, 0x37, 0x01, 0x8f, 0x4d, 0x5d, 0x1f, 0x1f, 0x27, 0x25, 0x24
-];
-
-/// Commitment G1 para polinomio Q_ARITHMETIC (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_ARITHMETIC: [u8; 64] = [
-    0x0c, 0x00, 0xab, 0x0c, 0xa7, 0x2a, 0x16, 0x4c, 0xf4, 0xb9, 0x98, 0x25, 0x88, 0xa6, 0x5e, 0x23,
-    0xa4, 0xbb, 0x43, 0xf3, 0x64, 0xbc, 0xe4, 0x77, 0x03, 0x27, 0x6c, 0xb5, 0x70, 0xa4, 0xbc, 0x1f,
-    0x13, 0x81, 0xe0, 0x94, 0x36, 0xe7, 0xd6, 0x58, 0xf6, 0x05, 0xfa, 0xfc, 0xeb, 0x6a, 0x64, 0x34,
-    0xdb, 0xa6, 0xc7, 0x1d, 0x1b, 0x0e, 0x52, 0xab, 0xa2, 0x8e, 0xf0, 0xa7, 0x55, 0xfb, 0xef, 0x5a
-];
-
-/// Commitment G1 para polinomio Q_AUX (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_AUX: [u8; 64] = [
-    0x17, 0x50, 0xf4, 0x4d, 0x3f, 0x9d, 0xfa, 0xd7, 0x8a, 0x1e, 0x21, 0x27, 0xef, 0x91, 0x05, 0x1c,
-    0xe0, 0x18, 0xf2, 0x05, 0x36, 0xfe, 0x45, 0xca, 0x28, 0xdc, 0xc7, 0xb2, 0x48, 0x38, 0x9f, 0xc0,
-    0x1a, 0x05, 0x41, 0x8f, 0x50, 0x2a, 0x96, 0x5c, 0x39, 0x99, 0x4c, 0xd3, 0xf8, 0x3e, 0x39, 0x16,
-    0x4b, 0x49, 0xc0, 0xf2, 0x7d, 0x4f, 0x5a, 0xc4, 0x55, 0x07, 0x51, 0xcc, 0x0a, 0x24, 0xbb, 0x58
-];
-
-/// Commitment G1 para polinomio Q_C (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_C: [u8; 64] = [
-    0x08, 0x78, 0xcc, 0x66, 0xa6, 0x18, 0x81, 0x1c, 0x71, 0x91, 0x58, 0xbb, 0x76, 0xa8, 0x18, 0xfd,
-    0x14, 0xd7, 0x3a, 0xfd, 0xb2, 0xfe, 0x17, 0xff, 0x3f, 0x6e, 0x95, 0xd1, 0x5f, 0x56, 0x15, 0x53,
-    0x18, 0x14, 0xcf, 0x37, 0xc2, 0x53, 0xf1, 0x34, 0xb9, 0x7e, 0xff, 0xe6, 0x8e, 0x0c, 0x10, 0x10,
-    0xb8, 0xf4, 0x55, 0xaf, 0x0e, 0x97, 0xf9, 0xe4, 0x9c, 0xce, 0x55, 0xdd, 0x41, 0xbc, 0xe2, 0xeb
-];
-
-/// Commitment G1 para polinomio Q_ELLIPTIC (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_ELLIPTIC: [u8; 64] = [
-    0x13, 0x98, 0x2f, 0xd0, 0xcf, 0x8d, 0xa5, 0x08, 0x2a, 0x77, 0x56, 0x11, 0x13, 0xbb, 0x5e, 0xe5,
-    0x1e, 0x2e, 0x82, 0x38, 0x0d, 0xa3, 0xda, 0x5a, 0xd0, 0xf2, 0x4e, 0x49, 0xe5, 0xf3, 0x22, 0x08,
-    0x1a, 0xa5, 0xff, 0xd5, 0xaa, 0x4c, 0x16, 0xd1, 0xc6, 0x6e, 0x18, 0xc4, 0x57, 0x4a, 0x3a, 0xb0,
-    0xb2, 0x5e, 0x9b, 0x4e, 0x4e, 0x04, 0xad, 0x12, 0x80, 0xd1, 0xa2, 0x64, 0x23, 0x77, 0x17, 0xe0
-];
-
-/// Commitment G1 para polinomio Q_M (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_M: [u8; 64] = [
-    0x1f, 0x99, 0xab, 0x32, 0x13, 0x83, 0xba, 0xb6, 0xbc, 0xd6, 0x06, 0x6e, 0x1d, 0x4a, 0x7b, 0x95,
-    0x45, 0x9d, 0x27, 0x47, 0x11, 0x74, 0x05, 0xe1, 0x96, 0xcc, 0xae, 0xbc, 0xba, 0x1b, 0xaf, 0x91,
-    0x0d, 0x7d, 0x83, 0x51, 0x0f, 0x2b, 0x89, 0xa6, 0x6c, 0xdd, 0xb5, 0x44, 0x3b, 0xad, 0xfd, 0x9e,
-    0xdc, 0x0e, 0x50, 0xd1, 0xed, 0xf9, 0x9b, 0x7f, 0xa6, 0xfb, 0x11, 0x4c, 0xd0, 0x78, 0xa3, 0xb0
-];
-
-/// Commitment G1 para polinomio Q_SORT (x||y, 32+32 bytes, big-endian)
-pub const VK_Q_SORT: [u8; 64] = [
-    0x04, 0xa9, 0x20, 0x47, 0xa2, 0xce, 0xc0, 0x78, 0x54, 0xef, 0x25, 0xe7, 0xe0, 0xbb, 0xae, 0xb0,
-    0x17, 0x42, 0xff, 0x06, 0x38, 0xc0, 0x9a, 0x28, 0xf7, 0xa3, 0x90, 0xe7, 0xbe, 0x1d, 0x58, 0xfa,
-    0x2d, 0xb9, 0xd5, 0x43, 0x1f, 0xea, 0x85, 0xaf, 0x7d, 0x4b, 0x1a, 0x1f, 0x88, 0xaf, 0xb7, 0x42,
-    0x17, 0x7a, 0xa5, 0x8c, 0xbd, 0x65, 0x6f, 0x49, 0xa4, 0xc3, 0x6c, 0x3a, 0x07, 0x75, 0x4d, 0x19
-];
-
-/// Commitment G1 para polinomio SIGMA_1 (x||y, 32+32 bytes, big-endian)
-pub const VK_SIGMA_1: [u8; 64] = [
-    0x27, 0xa6, 0x60, 0xa0, 0xef, 0x4a, 0x1c, 0x88, 0x3d, 0xb1, 0x9b, 0x8d, 0x5a, 0xb6, 0xd9, 0xaa,
-    0xed, 0xba, 0x70, 0x41, 0x0f, 0xf5, 0x13, 0xd9, 0xbe, 0xda, 0xfc, 0x73, 0x61, 0x5d, 0xda, 0xb4,
-    0x0e, 0x85, 0x06, 0x27, 0x44, 0xe9, 0x81, 0x86, 0x29, 0xf5, 0xc2, 0xd6, 0xd6, 0xce, 0x75, 0x4a,
-    0xb0, 0x4b, 0xfd, 0x67, 0xb8, 0x4b, 0xca, 0x95, 0xac, 0x95, 0xdd, 0x63, 0x9f, 0x9d, 0xcc, 0x40
-];
-
-/// Commitment G1 para polinomio SIGMA_2 (x||y, 32+32 bytes, big-endian)
-pub const VK_SIGMA_2: [u8; 64] = [
-    0x06, 0x83, 0x8c, 0xa1, 0x0e, 0x8d, 0xa9, 0x23, 0x3b, 0x4a, 0x3b, 0x3d, 0xe9, 0xab, 0x93, 0x7e,
-    0x45, 0xaf, 0x30, 0x47, 0xcd, 0x94, 0xe6, 0x82, 0x04, 0x07, 0x85, 0x79, 0x71, 0x75, 0x38, 0xa4,
-    0x28, 0x7c, 0x0e, 0xe7, 0x07, 0x07, 0xee, 0xbe, 0x68, 0x53, 0x0d, 0x48, 0xed, 0xb3, 0x94, 0xbc,
-    0x8e, 0xa8, 0x33, 0x30, 0x03, 0x4d, 0xf2, 0xf0, 0x8f, 0xfa, 0x68, 0xea, 0x01, 0x59, 0x61, 0xd9
-];
-
-/// Commitment G1 para polinomio SIGMA_3 (x||y, 32+32 bytes, big-endian)
-pub const VK_SIGMA_3: [u8; 64] = [
-    0x18, 0x21, 0x5f, 0x72, 0xdc, 0xd7, 0x13, 0x11, 0x9e, 0xf6, 0x16, 0x18, 0xd1, 0xcb, 0xfa, 0x81,
-    0x45, 0xbb, 0x17, 0x41, 0xd3, 0x04, 0xf1, 0x97, 0x7a, 0xa8, 0x38, 0x8b, 0xed, 0xe8, 0x4e, 0xad,
-    0x24, 0x92, 0x9b, 0x2f, 0x31, 0x26, 0x95, 0x92, 0xe4, 0x8e, 0xda, 0xc5, 0x85, 0x46, 0xa0, 0x5d,
-    0x5c, 0x11, 0x1f, 0xe5, 0x64, 0x1f, 0x9c, 0xa0, 0xbd, 0x46, 0x00, 0xda, 0xa4, 0x72, 0xfc, 0x87
-];
-
-/// Commitment G1 para polinomio SIGMA_4 (x||y, 32+32 bytes, big-endian)
-pub const VK_SIGMA_4: [u8; 64] = [
-    0x2d, 0x39, 0xaa, 0x95, 0x44, 0x3f, 0xd4, 0xf3, 0x3a, 0x58, 0x63, 0x5e, 0x5f, 0xa0, 0x1e, 0x13,
-    0x4c, 0x96, 0xa6, 0x5c, 0xe6, 0x51, 0x78, 0xd4, 0x3b, 0x03, 0x82, 0xa1, 0x17, 0x20, 0xbf, 0x79,
-    0x2f, 0x06, 0x32, 0x92, 0x8f, 0x46, 0xfa, 0x8a, 0x34, 0x0c, 0x33, 0xd1, 0x46, 0xae, 0xe3, 0x7b,
-    0x27, 0x03, 0x7b, 0x33, 0xa9, 0x8b, 0x58, 0x5b, 0xc5, 0xf2, 0x7c, 0xdc, 0x65, 0xc8, 0xfa, 0xf2
-];
-
-/// Commitment G1 para polinomio TABLE_1 (x||y, 32+32 bytes, big-endian)
-pub const VK_TABLE_1: [u8; 64] = [
-    0x25, 0x9f, 0x45, 0x2d, 0xc7, 0xfd, 0x2d, 0xda, 0x40, 0x13, 0xdb, 0xa2, 0x19, 0x68, 0x52, 0xbc,
-    0xf4, 0x3c, 0x28, 0x5b, 0x1d, 0x1f, 0x7f, 0x85, 0x34, 0x1f, 0x36, 0x15, 0xd2, 0x5f, 0xe9, 0x7b,
-    0x11, 0x75, 0x00, 0x55, 0x5d, 0xd8, 0x86, 0x20, 0x9c, 0x0b, 0x10, 0xee, 0x8c, 0xd1, 0x0e, 0x71,
-    0x1e, 0x89, 0x0a, 0x1c, 0x99, 0xa8, 0xf6, 0x89, 0x41, 0x9d, 0xa8, 0xc5, 0x2d, 0x2e, 0x8e, 0x9d
-];
-
-/// Commitment G1 para polinomio TABLE_2 (x||y, 32+32 bytes, big-endian)
-pub const VK_TABLE_2: [u8; 64] = [
-    0x25, 0x77, 0xf5, 0x42, 0x17, 0x8a, 0x07, 0xda, 0xc2, 0x62, 0xfd, 0xab, 0xad, 0x6f, 0x55, 0xa8,
-    0x4f, 0xca, 0x32, 0xb1, 0x3b, 0x92, 0xe5, 0x20, 0xbb, 0x91, 0xa7, 0x45, 0x5f, 0x78, 0xcc, 0xf1,
-    0x0f, 0xc8, 0x7a, 0xe2, 0x71, 0x22, 0xe6, 0x0e, 0xaa, 0xcc, 0x07, 0x0b, 0xd5, 0x9a, 0xaf, 0xe1,
-    0x26, 0x44, 0xa8, 0x2e, 0xe1, 0x34, 0x54, 0x97, 0xf8, 0xc0, 0x30, 0x2e, 0x92, 0x92, 0x5c, 0x68
-];
-
-/// Commitment G1 para polinomio TABLE_3 (x||y, 32+32 bytes, big-endian)
-pub const VK_TABLE_3: [u8; 64] = [
-    0x16, 0x12, 0xf5, 0x01, 0x33, 0x5a, 0x4b, 0x72, 0xac, 0x55, 0xdb, 0xe2, 0xfd, 0x1a, 0x75, 0xe5,
-    0xfe, 0x2c, 0x04, 0x16, 0x87, 0x60, 0x3b, 0x65, 0x77, 0x58, 0x1d, 0x67, 0x3d, 0x13, 0xbe, 0x50,
-    0x16, 0x0e, 0x8e, 0xf7, 0xff, 0x53, 0x15, 0xcb, 0x64, 0x0e, 0xc8, 0x2e, 0x96, 0x5d, 0xb2, 0x70,
-    0x84, 0x6e, 0x90, 0x4b, 0xb7, 0xd1, 0xc7, 0xff, 0x02, 0xf3, 0x28, 0x23, 0xde, 0x6c, 0x1c, 0x71
-];
-
-/// Commitment G1 para polinomio TABLE_4 (x||y, 32+32 bytes, big-endian)
-pub const VK_TABLE_4: [u8; 64] = [
-    0x2d, 0x54, 0x0f, 0xf1, 0x65, 0x3b, 0x38, 0xac, 0xbc, 0xb9, 0xcd, 0xa3, 0x15, 0x44, 0x23, 0x64,
-    0x00, 0x76, 0x33, 0xf5, 0x29, 0x47, 0x6b, 0x2f, 0x16, 0x9a, 0x0f, 0xf1, 0x31, 0xbf, 0xb3, 0x19,
-    0x21, 0x98, 0xb9, 0xfe, 0xb6, 0x1f, 0x81, 0x60, 0xe3, 0x57, 0xb8, 0xbb, 0x7c, 0xa3, 0x29, 0x71,
-    0x38, 0x98, 0x65, 0x5c, 0xc9, 0x4a, 0x0a, 0xc2, 0xd8, 0x49, 0x44, 0xc7, 0x37, 0xcf, 0x57, 0xe5
-];
-
-/// Commitment G1 para polinomio TABLE_TYPE (x||y, 32+32 bytes, big-endian)
-pub const VK_TABLE_TYPE: [u8; 64] = [
-    0x11, 0xc8, 0xdf, 0x52, 0xc3, 0xef, 0x75, 0x4f, 0x80, 0xd1, 0x17, 0x92, 0xce, 0xa4, 0xb7, 0xad,
-    0x74, 0x61, 0x2e, 0x48, 0x65, 0x96, 0xcb, 0xe7, 0xf7, 0xd6, 0xa0, 0x5f, 0x19, 0xc6, 0x94, 0x44,
-    0x10, 0xc8, 0xa3, 0x6c, 0xbb, 0x2f, 0xd9, 0xed, 0x88, 0x75, 0xb5, 0x10, 0x6a, 0x37, 0x16, 0x2a,
-    0xc2, 0x93, 0x2f, 0x3b, 0xd1, 0xb6, 0x94, 0x2b, 0x13, 0x25, 0x46, 0xd2, 0x11, 0x0a, 0x63, 0xe2
-];
-// Raw VK bytes (Barretenberg output). Keep as a static slice so the binary
-// can be included at compile time and used by the verifier. This points to
-// the circuit vk generated in the repo at `circuits/zk-porrinha/target/vk`.
-//
-// Note: include_bytes! expands to a `&'static [u8; N]` which coerces to
-// `&'static [u8]` and can be passed to `Bytes::from_array` through the
-// soroban SDK bindings used in `noir-verifier`.
-pub static VK_BYTES: &'static [u8; 1760] = include_bytes!("../../../circuits/zk-porrinha/target/vk");
-
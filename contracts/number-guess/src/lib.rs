@@ -3,36 +3,19 @@
 //! # Number Guess Game
 //!
 //! A simple two-player guessing game where players guess a number between 1 and 10.
-//! The player whose guess is closest to the randomly generated number wins.
+//! The player whose guess is closest to the randomly generated number wins;
+//! equally close guesses are a draw and both players get their points back.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
+use game_hub_interface::{admin, GameAdmin};
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+pub use game_hub_interface::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -64,14 +47,13 @@ pub struct Game {
     pub player2_guess: Option<u32>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    pub is_draw: bool,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
@@ -100,10 +82,7 @@ impl NumberGuessContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -135,15 +114,8 @@ impl NumberGuessContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = admin::hub(&env);
 
         // Call Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -166,6 +138,7 @@ impl NumberGuessContract {
             player2_guess: None,
             winning_number: None,
             winner: None,
+            is_draw: false,
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -193,7 +166,7 @@ impl NumberGuessContract {
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -205,8 +178,8 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or draw yet)
+        if game.winner.is_some() || game.is_draw {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -241,8 +214,8 @@ impl NumberGuessContract {
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -251,9 +224,9 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended (has a winner or was drawn)
+        if game.winner.is_some() || game.is_draw {
+            return Ok(game.winner);
         }
 
         // Check both players have guessed
@@ -293,44 +266,33 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
-        } else {
-            winning_number - guess1
-        };
+        let distance1 = guess1.abs_diff(winning_number);
 
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
+        let distance2 = guess2.abs_diff(winning_number);
 
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
+        // Determine winner (equal distances are a draw)
+        let winner = match distance1.cmp(&distance2) {
+            core::cmp::Ordering::Less => Some(game.player1.clone()),
+            core::cmp::Ordering::Greater => Some(game.player2.clone()),
+            core::cmp::Ordering::Equal => None,
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with the result (this marks the game as ended)
+        game.winner = winner.clone();
+        game.is_draw = winner.is_none();
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = admin::hub(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        // Event emitted by the Game Hub contract (GameSettled)
+        let outcome = match &winner {
+            Some(winner) => GameOutcome::Win(vec![&env, winner.clone()]),
+            None => GameOutcome::Draw,
+        };
+        game_hub.end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
 
         Ok(winner)
     }
@@ -349,84 +311,20 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)
     }
+}
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
-
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
-
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
-    }
-
-    /// Set a new GameHub contract address
-    ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
-    }
-
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+// ============================================================================
+// Admin Functions
+// ============================================================================
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-}
+// get_admin, set_admin, get_hub, set_hub and upgrade, shared by every game
+#[contractimpl(contracttrait)]
+impl GameAdmin for NumberGuessContract {}
 
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains, clippy::manual_abs_diff)]
 mod test;
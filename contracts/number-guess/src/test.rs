@@ -4,9 +4,11 @@
 // These tests verify game logic independently of the full GameHub system.
 //
 // Note: These tests use a minimal mock for isolation and speed.
-// For full integration tests with the real Game Hub contract, see the platform repo.
+// Integration tests against mock-game-hub, which escrows points and keeps
+// standings, are at the end of this file.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{Error, GameOutcome, NumberGuessContract, NumberGuessContractClient};
+use mock_game_hub as hub;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
 
@@ -31,7 +33,7 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    pub fn end_game_with_outcome(_env: Env, _game_id: Address, _session_id: u32, _outcome: GameOutcome) {
         // Mock implementation - does nothing
     }
 
@@ -166,14 +168,13 @@ fn test_complete_game() {
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &7);
 
-    // Reveal winner
+    // Reveal winner (a 6 would make it a draw)
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
     // Verify game is ended and winning number is now set
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_eq!(final_game.winner, winner);
+    assert_eq!(final_game.is_draw, winner.is_none()); // Game has ended
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
     assert!(winning_number >= 1 && winning_number <= 10);
@@ -237,7 +238,8 @@ fn test_closest_guess_wins() {
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &10);
 
-    let winner = client.reveal_winner(&session_id);
+    // 5 and 10 can never be equally close, so there is always a winner
+    let winner = client.reveal_winner(&session_id).unwrap();
 
     // Get the final game state to check the winning number
     let game = client.get_game(&session_id);
@@ -255,7 +257,7 @@ fn test_closest_guess_wins() {
         winning_number - 10
     };
 
-    let expected_winner = if distance1 <= distance2 {
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
@@ -267,7 +269,7 @@ fn test_closest_guess_wins() {
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
+fn test_tie_game_is_draw() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
@@ -278,7 +280,8 @@ fn test_tie_game_player1_wins() {
     client.make_guess(&session_id, &player2, &5);
 
     let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    assert_eq!(winner, None, "A tie should be a draw");
+    assert!(client.get_game(&session_id).is_draw);
 }
 
 #[test]
@@ -293,7 +296,7 @@ fn test_exact_guess_wins() {
     client.make_guess(&session_id, &player1, &5);
     client.make_guess(&session_id, &player2, &10);
 
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
@@ -308,7 +311,7 @@ fn test_exact_guess_wins() {
     } else {
         winning_number - 10
     };
-    let expected_winner = if distance1 <= distance2 {
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
@@ -438,7 +441,6 @@ fn test_cannot_reveal_twice() {
 
     // First reveal succeeds
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
     // Second reveal should return same winner (idempotent)
     let winner2 = client.reveal_winner(&session_id);
@@ -472,15 +474,15 @@ fn test_multiple_games_independent() {
     let winner1 = client.reveal_winner(&session1);
     let winner2 = client.reveal_winner(&session2);
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    assert!(winner1.iter().all(|w| *w == player1 || *w == player2));
+    assert!(winner2.iter().all(|w| *w == player3 || *w == player4));
 
     // Verify both games are independent
     let final_game1 = client.get_game(&session1);
     let final_game2 = client.get_game(&session2);
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    assert!(final_game1.winner.is_some() || final_game1.is_draw); // Game 1 has ended
+    assert!(final_game2.winner.is_some() || final_game2.is_draw); // Game 2 has ended
 
     // Note: winning numbers could be the same by chance, so we just verify they're both set
     assert!(final_game1.winning_number.is_some());
@@ -507,7 +509,7 @@ fn test_asymmetric_points() {
 
     // Game completes successfully with asymmetric points
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    assert!(final_game.is_draw); // Game has ended
 }
 
 // ============================================================================
@@ -537,3 +539,82 @@ fn test_upgrade_function_exists() {
     // This confirms the authorization check passed
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Integration Tests (mock-game-hub)
+// ============================================================================
+
+fn setup_with_hub() -> (
+    Env,
+    NumberGuessContractClient<'static>,
+    hub::MockGameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(hub::MockGameHub, (&admin,));
+    let game_hub = hub::MockGameHubClient::new(&env, &hub_addr);
+
+    let contract_id = env.register(NumberGuessContract, (&admin, &hub_addr));
+    let client = NumberGuessContractClient::new(&env, &contract_id);
+
+    // The hub only opens sessions for registered games
+    game_hub.add_game(&contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, game_hub, player1, player2)
+}
+
+#[test]
+fn test_hub_pays_stakes_to_winner() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &40_0000000);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 100_0000000);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 40_0000000);
+
+    // 1 and 10 can never be equally close, so there is always a winner
+    client.make_guess(&session_id, &player1, &1);
+    client.make_guess(&session_id, &player2, &10);
+    let winner = client.reveal_winner(&session_id).unwrap();
+
+    if winner == player1 {
+        assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS + 40_0000000);
+        assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 40_0000000);
+    } else {
+        assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 100_0000000);
+        assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS + 100_0000000);
+    }
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Ended);
+}
+
+#[test]
+fn test_hub_standings_accumulate_across_sessions() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    // Equal guesses are always a draw
+    client.start_game(&1, &player1, &player2, &10_0000000, &10_0000000);
+    client.make_guess(&1, &player1, &3);
+    client.make_guess(&1, &player2, &3);
+    assert_eq!(client.reveal_winner(&1), None);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS);
+
+    client.start_game(&2, &player1, &player2, &10_0000000, &10_0000000);
+    client.make_guess(&2, &player1, &1);
+    client.make_guess(&2, &player2, &10);
+    let winner = client.reveal_winner(&2).unwrap();
+
+    let standing = game_hub.get_standing(&winner);
+    assert_eq!((standing.games, standing.wins, standing.draws), (2, 1, 1));
+    assert_eq!(standing.points_won, 10_0000000);
+
+    let board = game_hub.get_leaderboard(&10);
+    assert_eq!(board.len(), 2);
+    assert_eq!(board.get_unchecked(0).player, winner);
+}
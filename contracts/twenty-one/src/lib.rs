@@ -6,31 +6,36 @@
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
 //!
+//! **Dealing:**
+//! Each player commits to the tip of a hash chain (`keccak256` applied at
+//! least `SEED_CHAIN_LENGTH` times to a secret) and reveals one link, walking
+//! back towards the secret, for every deal. Cards are seeded from both
+//! players' links, and a link may only be revealed once its deal is pending,
+//! so nobody knows a card before the hit that draws it. A game never deals more than
+//! `SEED_CHAIN_LENGTH` times: once the chains are spent no one can hit, and a
+//! tie ends as a draw. A player who does not reveal within
+//! `DEAL_TIMEOUT_LEDGERS`, or neither hits nor sticks within
+//! `TURN_TIMEOUT_LEDGERS` of a deal, forfeits.
+//!
+//! **Hidden hands:**
+//! Games started with `start_hidden_game` keep the cards off-chain. Each player
+//! also commits to a hand secret, and each of their cards is dealt from that
+//! secret mixed with the public deal seed, so only they can read it. Only the
+//! card seeds are stored during play; both players reveal their secret with
+//! `reveal_hand` once they have stuck, and `reveal_winner` scores the revealed
+//! hands. A busted hand loses there instead of on the hit.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
+    Address, Bytes, BytesN, Env, IntoVal, Vec, contract, contracterror,
     contractimpl, contracttype, vec
 };
+use game_hub_interface::{admin, GameAdmin};
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
+pub use game_hub_interface::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -51,6 +56,20 @@ pub enum Error {
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    SeedAlreadyCommitted = 12,
+    SeedNotCommitted = 13,
+    InvalidReveal = 14,
+    AlreadyRevealed = 15,
+    DealPending = 16,
+    NoDealPending = 17,
+    DeadlineNotReached = 18,
+    HandNotCommitted = 19,
+    HandAlreadyCommitted = 20,
+    NotHiddenGame = 21,
+    HandsNotRevealed = 22,
+    HandHidden = 23,
+    SeedChainTooShort = 24,
+    SeedChainExhausted = 25,
 }
 
 // ============================================================================
@@ -80,15 +99,43 @@ pub struct Game {
     pub player1_stuck: bool,
     pub player2_stuck: bool,
     pub winner: Option<Address>,
+    /// Equal hands after the last deal the seed chains allow
+    pub is_draw: bool,
     pub round: u32,
+    /// Latest revealed link of each player's seed chain (the tip until the first reveal)
+    pub player1_seed: Option<BytesN<32>>,
+    pub player2_seed: Option<BytesN<32>>,
+    /// Whether each player has revealed their link for the pending deal
+    pub player1_ready: bool,
+    pub player2_ready: bool,
+    /// Player waiting for the card of their hit
+    pub pending_hit: Option<Address>,
+    /// Last ledger to reveal for the pending deal (`None` when nothing is pending)
+    pub deal_deadline: Option<u32>,
+    /// Last ledger for the players still in the round to hit or stick (`None`
+    /// while a deal is pending or once both have stuck)
+    pub turn_deadline: Option<u32>,
+    /// Deals so far, each spending one link of both seed chains
+    pub deals: u32,
+    /// Players who did not reveal or play in time (set by `claim_timeout`)
+    pub forfeited: Vec<Address>,
+    /// Hidden-hand game: hands stay empty until revealed with `reveal_hand`
+    pub hidden: bool,
+    /// `keccak256(hand secret)` of each player in a hidden game
+    pub player1_hand_commitment: Option<BytesN<32>>,
+    pub player2_hand_commitment: Option<BytesN<32>>,
+    /// Seed of each card dealt in a hidden game; the card is
+    /// `deal_card(keccak256(seed || hand secret))`
+    pub player1_card_seeds: Vec<BytesN<32>>,
+    pub player2_card_seeds: Vec<BytesN<32>>,
+    /// Last ledger to reveal hidden hands, set once both players have stuck
+    pub reveal_deadline: Option<u32>,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
@@ -101,6 +148,15 @@ pub enum DataKey {
 /// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// Ledgers both players have to reveal for a pending deal (~1 hour)
+pub const DEAL_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Ledgers the players still in a round have to hit or stick after a deal (~1 hour)
+pub const TURN_TIMEOUT_LEDGERS: u32 = 720;
+
+/// Links each seed chain must hold, and the most deals a game makes
+pub const SEED_CHAIN_LENGTH: u32 = 64;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -134,6 +190,46 @@ fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
     env.prng().gen_range::<u64>(1..=13) as u8
 }
 
+/// Seed for one card of a deal: [card_index, player] appended to the deal's seed
+fn card_seed(env: &Env, base_seed: &BytesN<32>, card_index: u8, player: u8) -> BytesN<32> {
+    let mut card_seed_bytes = Bytes::new(env);
+    card_seed_bytes.append(&Bytes::from(base_seed.clone()));
+    card_seed_bytes.append(&Bytes::from_array(env, &[card_index, player]));
+    env.crypto().keccak256(&card_seed_bytes).into()
+}
+
+/// Card of a hidden hand, from its public seed and the player's hand secret
+fn hidden_card(env: &Env, card_seed: &BytesN<32>, secret: &BytesN<32>) -> u8 {
+    let mut seed_bytes = Bytes::from(card_seed.clone());
+    seed_bytes.append(&Bytes::from(secret.clone()));
+    deal_card(env, env.crypto().keccak256(&seed_bytes).into())
+}
+
+/// Whether the game has a result (a winner, a draw, or forfeited players)
+fn has_ended(game: &Game) -> bool {
+    game.winner.is_some() || game.is_draw || !game.forfeited.is_empty()
+}
+
+/// Whether the current round's hands have been dealt
+fn dealt(game: &Game) -> bool {
+    if game.hidden {
+        !game.player1_card_seeds.is_empty()
+    } else {
+        !game.player1_hand.is_empty()
+    }
+}
+
+/// Whether cards are waiting to be dealt (a new round or a hit)
+fn deal_pending(game: &Game) -> bool {
+    !dealt(game) || game.pending_hit.is_some()
+}
+
+/// Hand value for scoring; a busted hand counts as 0
+fn score(hand: &Bytes) -> Result<u32, Error> {
+    let value = calculate_hand_value(hand)?;
+    Ok(if value > 21 { 0 } else { value })
+}
+
 // ============================================================================
 // Contract Definition
 // ============================================================================
@@ -150,10 +246,7 @@ impl TwentyOneContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        admin::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -186,98 +279,197 @@ impl TwentyOneContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
+        Self::create_game(&env, session_id, player1, player2, player1_points, player2_points, false)
+    }
+
+    /// Start a new hidden-hand game. Like `start_game`, but cards stay
+    /// off-chain until `reveal_hand`; each player must also `commit_hand`
+    /// before the first deal of every round.
+    ///
+    /// Players authorize `(session_id, points, true)`, so a consent to a
+    /// regular game cannot start a hidden one.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    pub fn start_hidden_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env), true.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env), true.into_val(&env)]);
+
+        Self::create_game(&env, session_id, player1, player2, player1_points, player2_points, true)
+    }
+
+    /// Commit to the tip of the player's seed chain.
+    /// The tip is `keccak256` applied `length` times to a 32-byte secret kept
+    /// off-chain; every deal then reveals the previous link of the chain. The
+    /// chain must hold at least `SEED_CHAIN_LENGTH` links so it cannot run out
+    /// before the game does.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `tip` - Last link of the player's hash chain
+    /// * `length` - Number of links in the chain
+    pub fn commit_seed(env: Env, session_id: u32, player: Address, tip: BytesN<32>, length: u32) -> Result<(), Error> {
+        player.require_auth();
+
+        if length < SEED_CHAIN_LENGTH {
+            return Err(Error::SeedChainTooShort);
+        }
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+        let seed = if player == game.player1 {
+            &mut game.player1_seed
+        } else if player == game.player2 {
+            &mut game.player2_seed
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if seed.is_some() {
+            return Err(Error::SeedAlreadyCommitted);
+        }
+        *seed = Some(tip);
 
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Create game
-        let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
-            player1_points,
-            player2_points,
-            player1_hand,
-            player2_hand,
-            player1_stuck: false,
-            player2_stuck: false,
-            winner: None,
-            round: 1,
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Commit to the player's hand secret for the current round of a hidden game.
+    /// Must be called before the round's first deal; a drawn round needs a new
+    /// secret, since the previous one was revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the committing player
+    /// * `commitment` - `keccak256(secret)` of a 32-byte secret kept off-chain
+    pub fn commit_hand(env: Env, session_id: u32, player: Address, commitment: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        let hand_commitment = if player == game.player1 {
+            &mut game.player1_hand_commitment
+        } else if player == game.player2 {
+            &mut game.player2_hand_commitment
+        } else {
+            return Err(Error::NotPlayer);
         };
+        if hand_commitment.is_some() {
+            return Err(Error::HandAlreadyCommitted);
+        }
+        *hand_commitment = Some(commitment);
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        env.storage().temporary().set(&key, &game);
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
+        Ok(())
+    }
+
+    /// Reveal the player's next seed link for the pending deal.
+    /// Once both players have revealed, the cards are dealt: two each at the
+    /// start of a round, or one to the player who hit. A hit that goes over 21
+    /// ends the game. In a hidden game the player must have committed their
+    /// hand with `commit_hand` before revealing for a new round.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `link` - Preimage of the player's latest seed link
+    pub fn reveal_seed(env: Env, session_id: u32, player: Address, link: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Event emitted by GameHub contract (GameStarted)
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if game.player1_seed.is_none() || game.player2_seed.is_none() {
+            return Err(Error::SeedNotCommitted);
+        }
+        if !deal_pending(&game) {
+            return Err(Error::NoDealPending);
+        }
+        if game.hidden && !dealt(&game) {
+            let hand_commitment = if player == game.player1 {
+                &game.player1_hand_commitment
+            } else {
+                &game.player2_hand_commitment
+            };
+            if hand_commitment.is_none() {
+                return Err(Error::HandNotCommitted);
+            }
+        }
+
+        Self::reveal_link(&env, &mut game, &player, link)?;
+        if game.player1_ready && game.player2_ready {
+            Self::deal(&env, session_id, &mut game)?;
+            if !has_ended(&game) {
+                Self::start_turn(&env, &mut game);
+            }
+        }
+
+        env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
     /// Player draws another card ("hit").
-    /// If the player's hand value exceeds 21, they bust and lose immediately.
+    /// The player reveals their next seed link with the request; the card is
+    /// dealt when the opponent reveals theirs through `reveal_seed`. If the
+    /// player's hand value then exceeds 21, they bust and lose immediately;
+    /// a hidden hand is only checked at `reveal_winner`. Fails with
+    /// `SeedChainExhausted` once the game has dealt `SEED_CHAIN_LENGTH` times.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player drawing a card
-    pub fn hit(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `link` - Preimage of the player's latest seed link
+    pub fn hit(env: Env, session_id: u32, player: Address, link: BytesN<32>) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
@@ -288,8 +480,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no result yet)
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -309,59 +501,18 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
-
-        let card_count = if is_player1 {
-            game.player1_hand.len()
-        } else {
-            game.player2_hand.len()
-        };
-
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
-
-        // Add card to player's hand
-        if is_player1 {
-            game.player1_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player1_hand)?;
-            if hand_value > 21 {
-                // Player 1 busted, player 2 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player2.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
-        } else {
-            game.player2_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player2_hand)?;
-            if hand_value > 21 {
-                // Player 2 busted, player 1 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player1.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+        // One deal at a time
+        if deal_pending(&game) {
+            return Err(Error::DealPending);
         }
+        if game.deals >= SEED_CHAIN_LENGTH {
+            return Err(Error::SeedChainExhausted);
+        }
+
+        Self::reveal_link(&env, &mut game, &player, link)?;
+        game.pending_hit = Some(player);
+        game.deal_deadline = Some(env.ledger().sequence() + DEAL_TIMEOUT_LEDGERS);
+        game.turn_deadline = None;
 
         // Store updated game
         env.storage().temporary().set(&key, &game);
@@ -370,7 +521,9 @@ impl TwentyOneContract {
     }
 
     /// Player chooses to stick (end their turn with current hand).
-    /// If both players have stuck, the game can be revealed.
+    /// If both players have stuck, the game can be revealed. In a hidden game
+    /// this starts the `DEAL_TIMEOUT_LEDGERS` window for `reveal_hand`;
+    /// otherwise the opponent gets a fresh `TURN_TIMEOUT_LEDGERS` to play.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -386,11 +539,16 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no result yet)
+        if has_ended(&game) {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // A player sticks on a dealt hand, not on a hit still being dealt
+        if !dealt(&game) || game.pending_hit.as_ref() == Some(&player) {
+            return Err(Error::DealPending);
+        }
+
         // Mark player as stuck
         if player == game.player1 {
             if game.player1_stuck {
@@ -406,23 +564,87 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        if game.hidden && game.player1_stuck && game.player2_stuck {
+            game.reveal_deadline = Some(env.ledger().sequence() + DEAL_TIMEOUT_LEDGERS);
+        }
+        if !deal_pending(&game) {
+            Self::start_turn(&env, &mut game);
+        }
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
+    /// Reveal the player's hand secret in a hidden game.
+    /// Only allowed once both players have stuck. The secret must match the
+    /// hand commitment; the player's cards are then derived and stored.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the revealing player
+    /// * `secret` - The secret whose `keccak256` was committed in `commit_hand`
+    pub fn reveal_hand(env: Env, session_id: u32, player: Address, secret: BytesN<32>) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+        if !game.player1_stuck || !game.player2_stuck {
+            return Err(Error::BothPlayersNotStuck);
+        }
+
+        let (commitment, card_seeds, hand) = if player == game.player1 {
+            (&game.player1_hand_commitment, &game.player1_card_seeds, &mut game.player1_hand)
+        } else {
+            (&game.player2_hand_commitment, &game.player2_card_seeds, &mut game.player2_hand)
+        };
+        if !hand.is_empty() {
+            return Err(Error::AlreadyRevealed);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(secret.clone())).into();
+        if commitment.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        for card_seed in card_seeds.iter() {
+            hand.push_back(hidden_card(&env, &card_seed, &secret));
+        }
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
     /// Can only be called after both players have stuck.
-    /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// This calculates hand values and determines the winner (closest to 21).
+    /// Equal hands start a new round: hands are cleared and dealt again once
+    /// both players reveal their next seed link. If the seed chains have no
+    /// link left for that deal, the game ends as a draw instead and both
+    /// stakes are returned.
+    /// In a hidden game both hands must first be revealed with `reveal_hand`;
+    /// a busted hand loses, and two busted hands are a draw.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` on a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -431,9 +653,9 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended (has a winner or was forfeited)
+        if has_ended(&game) {
+            return Ok(game.winner);
         }
 
         // Check both players have stuck
@@ -441,20 +663,33 @@ impl TwentyOneContract {
             return Err(Error::BothPlayersNotStuck);
         }
 
-        // Calculate hand values
-        let player1_value = calculate_hand_value(&game.player1_hand)?;
-        let player2_value = calculate_hand_value(&game.player2_hand)?;
+        // Hidden hands are only known once both players revealed them
+        if game.hidden && (game.player1_hand.is_empty() || game.player2_hand.is_empty()) {
+            return Err(Error::HandsNotRevealed);
+        }
+
+        // Calculate hand values (busted hands score 0)
+        let player1_value = score(&game.player1_hand)?;
+        let player2_value = score(&game.player2_hand)?;
 
         // Determine winner (closest to 21 without going over)
-        // Note: Bust conditions are already handled in hit(), so values should be <= 21
+        // Note: Regular games end on a bust when the hit is dealt; hidden
+        // hands are only checked here
         let winner = if player1_value > player2_value {
             // Player 1 is closer to 21
             game.player1.clone()
         } else if player2_value > player1_value {
             // Player 2 is closer to 21
             game.player2.clone()
+        } else if game.deals >= SEED_CHAIN_LENGTH {
+            // Draw with no link left for another deal - end the game
+            game.is_draw = true;
+            env.storage().temporary().set(&key, &game);
+            admin::hub(&env).end_game_with_outcome(&env.current_contract_address(), &session_id, &GameOutcome::Draw);
+
+            return Ok(None);
         } else {
-            // Draw - deal new hands and continue
+            // Draw - start a new round, dealt once both players reveal
             game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
 
             // Reset stuck flags
@@ -464,47 +699,95 @@ impl TwentyOneContract {
             // Clear hands
             game.player1_hand = Bytes::new(&env);
             game.player2_hand = Bytes::new(&env);
+            game.deal_deadline = Some(env.ledger().sequence() + DEAL_TIMEOUT_LEDGERS);
 
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
+            // Revealed hand secrets are spent; hidden games commit new ones
+            game.player1_hand_commitment = None;
+            game.player2_hand_commitment = None;
+            game.player1_card_seeds = Vec::new(&env);
+            game.player2_card_seeds = Vec::new(&env);
+            game.reveal_deadline = None;
 
-            // Store updated game and return error to indicate draw
             env.storage().temporary().set(&key, &game);
 
-            return Err(Error::Draw);
+            return Ok(None);
         };
 
         // Call GameHub FIRST (before setting winner)
-        let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        Self::end_game_with_hub(&env, session_id, &winner)?;
 
         // Only update game with winner AFTER GameHub succeeds
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
+        Ok(Some(winner))
+    }
+
+    /// Settle a game whose deal, turn or hand reveal deadline has passed.
+    /// A player who did not commit or reveal for the pending deal, neither hit
+    /// nor stuck in time, or did not reveal their hidden hand, forfeits to the
+    /// one who did; if neither did, the session is cancelled and both stakes
+    /// are returned.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Option<Address>` - Address of the winning player, or `None` if there is none
+    pub fn claim_timeout(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if has_ended(&game) {
+            return Err(Error::GameAlreadyEnded);
+        }
+        let deadline = game
+            .deal_deadline
+            .or(game.turn_deadline)
+            .or(game.reveal_deadline)
+            .ok_or(Error::NoDealPending)?;
+        if env.ledger().sequence() <= deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // Waiting on seed links for a deal, on a hit or stick, or on hidden
+        // hands after both stuck
+        let (player1_done, player2_done) = if game.deal_deadline.is_some() {
+            (game.player1_ready, game.player2_ready)
+        } else if game.turn_deadline.is_some() {
+            (game.player1_stuck, game.player2_stuck)
+        } else {
+            (!game.player1_hand.is_empty(), !game.player2_hand.is_empty())
+        };
+
+        let mut quitters = Vec::new(&env);
+        if !player1_done {
+            quitters.push_back(game.player1.clone());
+        }
+        if !player2_done {
+            quitters.push_back(game.player2.clone());
+        }
+
+        let winner = match quitters.len() {
+            1 if player1_done => Some(game.player1.clone()),
+            1 => Some(game.player2.clone()),
+            _ => None,
+        };
+        game.winner = winner.clone();
+        game.forfeited = quitters.clone();
+        env.storage().temporary().set(&key, &game);
+
+        // A single quitter forfeits their stake; if both quit nobody wins
+        let outcome = match winner {
+            Some(_) => GameOutcome::Forfeit(quitters),
+            None => GameOutcome::Cancelled,
+        };
+        admin::hub(&env).end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
+
         Ok(winner)
     }
 
@@ -524,6 +807,7 @@ impl TwentyOneContract {
     }
 
     /// Get the current hand value for a player.
+    /// Fails with `HandHidden` for a hidden hand that has not been revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -539,116 +823,219 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if player == game.player1 {
-            calculate_hand_value(&game.player1_hand)
+        let hand = if player == game.player1 {
+            &game.player1_hand
         } else if player == game.player2 {
-            calculate_hand_value(&game.player2_hand)
+            &game.player2_hand
         } else {
-            Err(Error::NotPlayer)
+            return Err(Error::NotPlayer);
+        };
+        if game.hidden && hand.is_empty() {
+            return Err(Error::HandHidden);
         }
+        calculate_hand_value(hand)
     }
 
     // ========================================================================
     // Internal Helper Functions
     // ========================================================================
 
-    /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
+    /// Open the Game Hub session and store a new game (shared by both start functions).
+    fn create_game(
+        env: &Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        hidden: bool,
+    ) -> Result<(), Error> {
         // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        let game_hub = admin::hub(env);
 
-        // Call the Game Hub to end the session
-        // This unlocks points and updates standings
-        // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            &player1,
+            &player2,
+            &player1_points,
+            &player2_points,
+        );
 
-        Ok(())
-    }
+        // Create game with empty hands: cards are dealt once both players
+        // have committed a seed chain and revealed its first link
+        let game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_hand: Bytes::new(env),
+            player2_hand: Bytes::new(env),
+            player1_stuck: false,
+            player2_stuck: false,
+            winner: None,
+            is_draw: false,
+            round: 1,
+            player1_seed: None,
+            player2_seed: None,
+            player1_ready: false,
+            player2_ready: false,
+            pending_hit: None,
+            deal_deadline: Some(env.ledger().sequence() + DEAL_TIMEOUT_LEDGERS),
+            turn_deadline: None,
+            deals: 0,
+            forfeited: Vec::new(env),
+            hidden,
+            player1_hand_commitment: None,
+            player2_hand_commitment: None,
+            player1_card_seeds: Vec::new(env),
+            player2_card_seeds: Vec::new(env),
+            reveal_deadline: None,
+        };
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, &game);
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
+        // Set TTL to ensure game is retained for at least 30 days
         env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
+            .temporary()
+            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
-    /// Set a new admin address
-    ///
-    /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        // Event emitted by GameHub contract (GameStarted)
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
     }
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+    /// Check `link` against the player's latest seed link and mark the player
+    /// ready for the pending deal.
+    fn reveal_link(env: &Env, game: &mut Game, player: &Address, link: BytesN<32>) -> Result<(), Error> {
+        let (seed, ready) = if *player == game.player1 {
+            (&mut game.player1_seed, &mut game.player1_ready)
+        } else {
+            (&mut game.player2_seed, &mut game.player2_ready)
+        };
+        if *ready {
+            return Err(Error::AlreadyRevealed);
+        }
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(link.clone())).into();
+        if seed.as_ref() != Some(&hash) {
+            return Err(Error::InvalidReveal);
+        }
+        *seed = Some(link);
+        *ready = true;
+        Ok(())
     }
 
-    /// Set a new GameHub contract address
+    /// Deal the pending cards from both players' latest seed links.
     ///
-    /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// Seed components (all deterministic and identical between sim/submit):
+    /// 1. Session ID and round
+    /// 2. Both players' links, unknown to the other player until the deal is pending
+    fn deal(env: &Env, session_id: u32, game: &mut Game) -> Result<(), Error> {
+        let (Some(player1_seed), Some(player2_seed)) =
+            (game.player1_seed.clone(), game.player2_seed.clone())
+        else {
+            return Err(Error::SeedNotCommitted);
+        };
+        let mut seed_bytes = Bytes::new(env);
+        seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+        seed_bytes.append(&Bytes::from_array(env, &game.round.to_be_bytes()));
+        seed_bytes.append(&Bytes::from(player1_seed));
+        seed_bytes.append(&Bytes::from(player2_seed));
+        let base_seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+        game.player1_ready = false;
+        game.player2_ready = false;
+        game.deal_deadline = None;
+        game.deals += 1;
+
+        let Some(hitter) = game.pending_hit.take() else {
+            // New round: deal 2 cards to each player
+            for i in 0..2 {
+                if game.hidden {
+                    game.player1_card_seeds.push_back(card_seed(env, &base_seed, i, 1));
+                    game.player2_card_seeds.push_back(card_seed(env, &base_seed, i, 2));
+                } else {
+                    game.player1_hand.push_back(deal_card(env, card_seed(env, &base_seed, i, 1)));
+                    game.player2_hand.push_back(deal_card(env, card_seed(env, &base_seed, i, 2)));
+                }
+            }
+            return Ok(());
+        };
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        // Hidden hands only learn the card seed; busts are found at reveal
+        if game.hidden {
+            let (card_seeds, player) = if hitter == game.player1 {
+                (&mut game.player1_card_seeds, 1)
+            } else {
+                (&mut game.player2_card_seeds, 2)
+            };
+            let card_index = card_seeds.len() as u8;
+            card_seeds.push_back(card_seed(env, &base_seed, card_index, player));
+            return Ok(());
+        }
+
+        let (hand, player, opponent) = if hitter == game.player1 {
+            (&mut game.player1_hand, 1, game.player2.clone())
+        } else {
+            (&mut game.player2_hand, 2, game.player1.clone())
+        };
+        let card_index = hand.len() as u8;
+        hand.push_back(deal_card(env, card_seed(env, &base_seed, card_index, player)));
+
+        // Check if the hitting player busted
+        if calculate_hand_value(hand)? > 21 {
+            // Call GameHub FIRST (before setting winner)
+            Self::end_game_with_hub(env, session_id, &opponent)?;
+
+            // Only set winner AFTER GameHub succeeds
+            game.winner = Some(opponent);
+        }
+
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// Give the players still in the round `TURN_TIMEOUT_LEDGERS` to hit or
+    /// stick, or clear the turn deadline once both have stuck.
+    fn start_turn(env: &Env, game: &mut Game) {
+        game.turn_deadline = if game.player1_stuck && game.player2_stuck {
+            None
+        } else {
+            Some(env.ledger().sequence() + TURN_TIMEOUT_LEDGERS)
+        };
+    }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Helper to end game with the Game Hub. Draws are replayed rather than
+    /// reported while the seed chains last (see `reveal_winner`).
+    fn end_game_with_hub(env: &Env, session_id: u32, winner: &Address) -> Result<(), Error> {
+        // Create GameHub client
+        let game_hub = admin::hub(env);
+
+        // Call the Game Hub to end the session
+        // This unlocks points and updates standings
+        // Event emitted by the Game Hub contract (GameSettled)
+        let outcome = GameOutcome::Win(vec![env, winner.clone()]);
+        game_hub.end_game_with_outcome(&env.current_contract_address(), &session_id, &outcome);
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+// get_admin, set_admin, get_hub, set_hub and upgrade, shared by every game
+#[contractimpl(contracttrait)]
+impl GameAdmin for TwentyOneContract {}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::manual_range_contains, clippy::unnecessary_unwrap)]
 mod test;
@@ -4,12 +4,16 @@
 // These tests verify game logic independently of the full GameHub system.
 //
 // Note: These tests use a minimal mock for isolation and speed.
-// For full integration tests with the real GameHub contract, see:
-// contracts/game_hub/src/tests/twenty_one_integration.rs
-
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+// Integration tests against mock-game-hub, which escrows points and keeps
+// standings, are at the end of this file.
+
+use crate::{
+    DataKey, Error, Game, GameOutcome, TwentyOneContract, TwentyOneContractClient, DEAL_TIMEOUT_LEDGERS,
+    SEED_CHAIN_LENGTH, TURN_TIMEOUT_LEDGERS,
+};
+use mock_game_hub as hub;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -32,7 +36,7 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
+    pub fn end_game_with_outcome(_env: Env, _game_id: Address, _session_id: u32, _outcome: GameOutcome) {
         // Mock implementation - does nothing
     }
 
@@ -97,7 +101,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_hit(&session_id, &player);
+/// let result = client.try_hit(&session_id, &player, &next_link(&client, session_id, &player));
 /// assert_twenty_one_error(&result, Error::AlreadyStuck);
 /// ```
 ///
@@ -140,6 +144,124 @@ fn assert_twenty_one_error<T, E>(
     }
 }
 
+/// Length of the test players' seed chains
+const CHAIN_LEN: u32 = SEED_CHAIN_LENGTH;
+
+/// Secret at the root of a player's seed chain
+fn chain_secret(env: &Env, player: &Address) -> BytesN<32> {
+    env.crypto().keccak256(&player.to_string().to_bytes()).into()
+}
+
+/// Tip of a player's seed chain: `keccak256` applied `CHAIN_LEN` times to the secret
+fn chain_tip(env: &Env, player: &Address) -> BytesN<32> {
+    let mut link = chain_secret(env, player);
+    for _ in 0..CHAIN_LEN {
+        link = env.crypto().keccak256(&Bytes::from(link)).into();
+    }
+    link
+}
+
+/// Preimage of the player's latest seed link (a dummy for unknown games or players)
+fn next_link(client: &TwentyOneContractClient, session_id: u32, player: &Address) -> BytesN<32> {
+    let env = &client.env;
+    let current = match client.try_get_game(&session_id) {
+        Ok(Ok(game)) if *player == game.player1 => game.player1_seed,
+        Ok(Ok(game)) if *player == game.player2 => game.player2_seed,
+        _ => None,
+    };
+    let mut link = chain_secret(env, player);
+    for _ in 0..CHAIN_LEN {
+        let hash: BytesN<32> = env.crypto().keccak256(&Bytes::from(link.clone())).into();
+        if current.as_ref() == Some(&hash) {
+            return link;
+        }
+        link = hash;
+    }
+    BytesN::from_array(env, &[0u8; 32])
+}
+
+/// Both players reveal their next link, dealing the pending cards
+fn reveal_both(client: &TwentyOneContractClient, session_id: u32) {
+    let game = client.get_game(&session_id);
+    client.reveal_seed(&session_id, &game.player1, &next_link(client, session_id, &game.player1));
+    client.reveal_seed(&session_id, &game.player2, &next_link(client, session_id, &game.player2));
+}
+
+/// Start a game, commit both seed chains and deal the first hands
+fn start_game(
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: &i128,
+    player2_points: &i128,
+) {
+    client.start_game(session_id, player1, player2, player1_points, player2_points);
+    client.commit_seed(session_id, player1, &chain_tip(&client.env, player1), &CHAIN_LEN);
+    client.commit_seed(session_id, player2, &chain_tip(&client.env, player2), &CHAIN_LEN);
+    reveal_both(client, *session_id);
+}
+
+/// The player hits and the opponent reveals, dealing the card
+fn hit(client: &TwentyOneContractClient, session_id: &u32, player: &Address) {
+    let game = client.get_game(session_id);
+    let opponent = if *player == game.player1 { game.player2 } else { game.player1 };
+    client.hit(session_id, player, &next_link(client, *session_id, player));
+    client.reveal_seed(session_id, &opponent, &next_link(client, *session_id, &opponent));
+}
+
+/// Secret a player commits to for the cards of a hidden hand
+fn hand_secret(env: &Env, player: &Address) -> BytesN<32> {
+    let mut secret_bytes = player.to_string().to_bytes();
+    secret_bytes.append(&Bytes::from_slice(env, b"hand"));
+    env.crypto().keccak256(&secret_bytes).into()
+}
+
+/// Start a hidden-hand game, commit both seed chains and hands, and deal the first hands
+fn start_hidden_game(
+    client: &TwentyOneContractClient,
+    session_id: &u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: &i128,
+    player2_points: &i128,
+) {
+    let env = &client.env;
+    client.start_hidden_game(session_id, player1, player2, player1_points, player2_points);
+    for player in [player1, player2] {
+        client.commit_seed(session_id, player, &chain_tip(env, player), &CHAIN_LEN);
+        let commitment = env.crypto().keccak256(&Bytes::from(hand_secret(env, player)));
+        client.commit_hand(session_id, player, &commitment.into());
+    }
+    reveal_both(client, *session_id);
+}
+
+/// The player's hidden hand as only they can compute it, from the dealt card seeds
+fn hidden_hand(client: &TwentyOneContractClient, session_id: &u32, player: &Address) -> Bytes {
+    let env = &client.env;
+    let game = client.get_game(session_id);
+    let card_seeds = if *player == game.player1 { game.player1_card_seeds } else { game.player2_card_seeds };
+    let secret = hand_secret(env, player);
+    let mut hand = Bytes::new(env);
+    env.as_contract(&client.address, || {
+        for card_seed in card_seeds.iter() {
+            hand.push_back(crate::hidden_card(env, &card_seed, &secret));
+        }
+    });
+    hand
+}
+
+/// Record every deal the seed chains allow as spent
+fn spend_seed_chains(client: &TwentyOneContractClient, session_id: u32) {
+    let env = &client.env;
+    env.as_contract(&client.address, || {
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env.storage().temporary().get(&key).unwrap();
+        game.deals = SEED_CHAIN_LENGTH;
+        env.storage().temporary().set(&key, &game);
+    });
+}
+
 /// Helper to calculate hand value from Bytes
 fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     let mut total = 0u32;
@@ -163,7 +285,7 @@ fn test_complete_game_simple() {
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    start_game(&client, &session_id, &player1, &player2, &points, &points);
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
@@ -181,14 +303,15 @@ fn test_complete_game_simple() {
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner
+    // Reveal winner (equal hands start a new round, see test_draw_starts_new_round)
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    if let Some(winner) = &winner {
+        assert!(*winner == player1 || *winner == player2);
+    }
 
-    // Verify game is ended
+    // Verify the result is stored
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_eq!(final_game.winner, winner);
 }
 
 #[test]
@@ -196,7 +319,7 @@ fn test_initial_cards_dealt() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
 
@@ -220,7 +343,7 @@ fn test_get_hand_value() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Get hand values
     let player1_value = client.get_hand_value(&session_id, &player1);
@@ -244,13 +367,13 @@ fn test_hit_adds_card() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
     // Player 1 hits
-    client.hit(&session_id, &player1);
+    hit(&client, &session_id, &player1);
 
     let after_hit_game = client.get_game(&session_id);
     assert_eq!(after_hit_game.player1_hand.len(), initial_hand_size + 1);
@@ -261,13 +384,13 @@ fn test_stick_prevents_further_hits() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
 
     // Try to hit after sticking - should fail
-    let result = client.try_hit(&session_id, &player1);
+    let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
     assert_twenty_one_error(&result, Error::AlreadyStuck);
 }
 
@@ -276,26 +399,20 @@ fn test_multiple_hits_allowed() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let initial_game = client.get_game(&session_id);
     let initial_hand_size = initial_game.player1_hand.len();
 
-    // Player 1 hits multiple times (be careful not to bust in deterministic test)
-    // This test may fail if player1 busts, but we're testing the mechanics
-    let result1 = client.try_hit(&session_id, &player1);
+    // Player 1 hits twice unless the first card busts them
+    hit(&client, &session_id, &player1);
+    let mid_game = client.get_game(&session_id);
+    assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
 
-    // If first hit succeeds (didn't bust), try another
-    if result1.is_ok() {
-        let mid_game = client.get_game(&session_id);
-        assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
-
-        let result2 = client.try_hit(&session_id, &player1);
-        // Could succeed or fail (bust), both are valid
-        if result2.is_ok() {
-            let final_game = client.get_game(&session_id);
-            assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
-        }
+    if mid_game.winner.is_none() {
+        hit(&client, &session_id, &player1);
+        let final_game = client.get_game(&session_id);
+        assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
     }
 }
 
@@ -308,26 +425,26 @@ fn test_closer_to_21_wins() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players stick
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    let winner = client.reveal_winner(&session_id);
-
-    // Get final hand values
+    // Get final hand values (a draw clears the hands)
     let game = client.get_game(&session_id);
     let player1_value = calculate_hand_value_helper(&game.player1_hand);
     let player2_value = calculate_hand_value_helper(&game.player2_hand);
 
-    // Winner should be closer to 21
-    if player1_value > player2_value {
-        assert_eq!(winner, player1);
-    } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
-    }
-    // If equal, test will continue (draw handling)
+    let winner = client.reveal_winner(&session_id);
+
+    // Winner should be closer to 21; equal hands have no winner yet
+    let expected = match player1_value.cmp(&player2_value) {
+        core::cmp::Ordering::Greater => Some(player1),
+        core::cmp::Ordering::Less => Some(player2),
+        core::cmp::Ordering::Equal => None,
+    };
+    assert_eq!(winner, expected);
 }
 
 #[test]
@@ -335,7 +452,7 @@ fn test_reveal_winner_requires_both_stuck() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
@@ -354,14 +471,14 @@ fn test_bust_detection() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Keep hitting until player1 busts
     // Note: With enough hits, player will eventually bust (hand value > 21)
     let mut busted = false;
     for _ in 0..20 {
         // Hit succeeds even when busting (returns Ok), but game ends
-        client.hit(&session_id, &player1);
+        hit(&client, &session_id, &player1);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
@@ -370,7 +487,7 @@ fn test_bust_detection() {
             assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
 
             // After a bust, subsequent operations should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
+            let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
             assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
@@ -384,12 +501,12 @@ fn test_cannot_hit_after_bust() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Hit until bust (game ends)
     let mut did_bust = false;
     for _ in 0..20 {
-        client.hit(&session_id, &player1);
+        hit(&client, &session_id, &player1);
 
         // Check if game ended (player busted)
         let game = client.get_game(&session_id);
@@ -398,7 +515,7 @@ fn test_cannot_hit_after_bust() {
             assert_eq!(game.winner.unwrap(), player2);
 
             // Try to hit again after busting - should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
+            let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
             assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
@@ -415,32 +532,150 @@ fn test_cannot_hit_after_bust() {
 fn test_draw_starts_new_round() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 11u32;
+    // Hands depend on the players' seeds, so play sessions until one ties
+    let mut session_id = 100u32;
+    loop {
+        session_id += 1;
+        assert!(session_id <= 300, "no tie in 200 sessions");
+        start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+
+        let game = client.get_game(&session_id);
+        if calculate_hand_value_helper(&game.player1_hand)
+            == calculate_hand_value_helper(&game.player2_hand)
+        {
+            break;
+        }
+    }
+
+    // A draw has no winner and starts a new round
+    assert_eq!(client.reveal_winner(&session_id), None);
+
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.round, 2); // Round should increment
+    assert!(!game_after.player1_stuck); // Flags reset
+    assert!(!game_after.player2_stuck);
+    assert!(game_after.player1_hand.is_empty()); // Dealt once both reveal
+    assert!(game_after.deal_deadline.is_some());
+
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DealPending);
+
+    reveal_both(&client, session_id);
+    let game_after = client.get_game(&session_id);
+    assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
+    assert_eq!(game_after.player2_hand.len(), 2);
+}
+
+// ============================================================================
+// Dealing Tests
+// ============================================================================
+
+#[test]
+fn test_cards_dealt_after_both_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Both players stick immediately
+    let game = client.get_game(&session_id);
+    assert!(game.player1_hand.is_empty());
+    assert_eq!(game.deal_deadline, Some(100 + DEAL_TIMEOUT_LEDGERS));
+
+    // Links can only be revealed once both chains are committed
+    client.commit_seed(&session_id, &player1, &chain_tip(&env, &player1), &CHAIN_LEN);
+    let result = client.try_reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::SeedNotCommitted);
+    let result = client.try_commit_seed(&session_id, &player1, &chain_tip(&env, &player1), &CHAIN_LEN);
+    assert_twenty_one_error(&result, Error::SeedAlreadyCommitted);
+
+    client.commit_seed(&session_id, &player2, &chain_tip(&env, &player2), &CHAIN_LEN);
+    client.reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert!(client.get_game(&session_id).player1_hand.is_empty());
+
+    client.reveal_seed(&session_id, &player2, &next_link(&client, session_id, &player2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 2);
+    assert_eq!(game.player2_hand.len(), 2);
+    assert!(!game.player1_ready && !game.player2_ready);
+    assert_eq!(game.deal_deadline, None);
+
+    // Nothing is pending once the hands are dealt
+    let result = client.try_reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::NoDealPending);
+}
+
+#[test]
+fn test_seed_chain_must_cover_every_deal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_commit_seed(&session_id, &player1, &chain_tip(&env, &player1), &(SEED_CHAIN_LENGTH - 1));
+    assert_twenty_one_error(&result, Error::SeedChainTooShort);
+    client.commit_seed(&session_id, &player1, &chain_tip(&env, &player1), &SEED_CHAIN_LENGTH);
+}
+
+#[test]
+fn test_cannot_hit_once_seed_chains_spent() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game(&session_id).deals, 1);
+
+    spend_seed_chains(&client, session_id);
+    let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::SeedChainExhausted);
     client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+}
+
+#[test]
+fn test_hit_dealt_after_opponent_reveals() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
+    client.hit(&session_id, &player1, &next_link(&client, session_id, &player1));
     let game = client.get_game(&session_id);
-    let player1_value = calculate_hand_value_helper(&game.player1_hand);
-    let player2_value = calculate_hand_value_helper(&game.player2_hand);
+    assert_eq!(game.player1_hand.len(), 2);
+    assert_eq!(game.pending_hit, Some(player1.clone()));
 
-    // Only test draw behavior if hands are equal
-    if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
-
-        // Verify new round was created
-        let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
-    }
-    // If not a draw, test passes (no assertion needed)
+    // One deal at a time, and the hitter cannot stick on a pending card
+    let result = client.try_hit(&session_id, &player2, &next_link(&client, session_id, &player2));
+    assert_twenty_one_error(&result, Error::DealPending);
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DealPending);
+    let result = client.try_reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    client.reveal_seed(&session_id, &player2, &next_link(&client, session_id, &player2));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand.len(), 3);
+    assert_eq!(game.player2_hand.len(), 2);
+    assert_eq!(game.pending_hit, None);
+}
+
+#[test]
+fn test_reveal_must_extend_chain() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // The seed just revealed is not its own preimage
+    let current = client.get_game(&session_id).player1_seed.unwrap();
+    let result = client.try_hit(&session_id, &player1, &current);
+    assert_twenty_one_error(&result, Error::InvalidReveal);
+
+    // Neither is another player's link
+    let other = Address::generate(&env);
+    let result = client.try_hit(&session_id, &player1, &chain_secret(&env, &other));
+    assert_twenty_one_error(&result, Error::InvalidReveal);
+    assert_eq!(client.get_game(&session_id).pending_hit, None);
 }
 
 // ============================================================================
@@ -452,7 +687,7 @@ fn test_cannot_stick_twice() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -468,10 +703,10 @@ fn test_non_player_cannot_hit() {
     let non_player = Address::generate(&env);
 
     let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to hit
-    let result = client.try_hit(&session_id, &non_player);
+    let result = client.try_hit(&session_id, &non_player, &next_link(&client, session_id, &non_player));
     assert_twenty_one_error(&result, Error::NotPlayer);
 }
 
@@ -481,7 +716,7 @@ fn test_non_player_cannot_stick() {
     let non_player = Address::generate(&env);
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
@@ -494,7 +729,7 @@ fn test_non_player_cannot_get_hand_value() {
     let non_player = Address::generate(&env);
 
     let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -505,7 +740,7 @@ fn test_non_player_cannot_get_hand_value() {
 fn test_cannot_hit_nonexistent_game() {
     let (_env, client, _hub, player1, _player2) = setup_test();
 
-    let result = client.try_hit(&999, &player1);
+    let result = client.try_hit(&999, &player1, &next_link(&client, 999, &player1));
     assert_twenty_one_error(&result, Error::GameNotFound);
 }
 
@@ -538,7 +773,7 @@ fn test_cannot_hit_after_game_ended() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players stick
     client.stick(&session_id, &player1);
@@ -546,10 +781,10 @@ fn test_cannot_hit_after_game_ended() {
 
     // Reveal winner (may panic if draw, but that's okay)
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Some(_))) = result {
         // Game ended successfully
         // Try to hit after game ended
-        let hit_result = client.try_hit(&session_id, &player1);
+        let hit_result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
         assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
     }
 }
@@ -559,7 +794,7 @@ fn test_cannot_stick_after_game_ended() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -569,7 +804,7 @@ fn test_cannot_stick_after_game_ended() {
 
     // Reveal winner
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Some(_))) = result {
         // Game ended, verify stuck flag is set
         let game = client.get_game(&session_id);
         assert!(game.player1_stuck);
@@ -585,7 +820,7 @@ fn test_reveal_winner_idempotent() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
@@ -615,12 +850,12 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    start_game(&client, &session1, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session2, &player3, &player4, &50_0000000, &50_0000000);
 
-    // Play both games independently (use try_ methods to handle potential busts)
-    let _ = client.try_hit(&session1, &player1);
-    let _ = client.try_hit(&session2, &player3);
+    // Play both games independently (a hit may bust and end the game)
+    hit(&client, &session1, &player1);
+    hit(&client, &session2, &player3);
 
     // Only continue if games haven't ended from busts
     let game1_check = client.get_game(&session1);
@@ -653,8 +888,8 @@ fn test_multiple_sessions() {
     let session1 = 22u32;
     let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    start_game(&client, &session1, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session2, &player3, &player4, &50_0000000, &50_0000000);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -674,7 +909,7 @@ fn test_asymmetric_points() {
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    start_game(&client, &session_id, &player1, &player2, &points1, &points2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
@@ -698,7 +933,7 @@ fn test_face_cards_worth_10() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
 
@@ -717,7 +952,7 @@ fn test_hand_value_calculation() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -827,23 +1062,23 @@ fn test_upgrade_function_exists() {
 
 #[test]
 fn test_deterministic_card_dealing() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, game_hub, player1, player2) = setup_test();
 
     let session_id = 27u32;
-
-    // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    hit(&client, &session_id, &player2);
     let game1 = client.get_game(&session_id);
 
-    // Start second game with same session_id in new environment (should be identical)
-    let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
+    // The same players and seed chains deal the same cards in another deployment
+    let contract_id = env.register(TwentyOneContract, (&client.get_admin(), &game_hub.address));
+    let client2 = TwentyOneContractClient::new(&env, &contract_id);
+    start_game(&client2, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    hit(&client2, &session_id, &player2);
     let game2 = client2.get_game(&session_id);
 
-    // Note: Since we generate new addresses each time, the cards will be different
-    // But we can verify that within the same session, cards are consistent
-    assert_eq!(game1.player1_hand.len(), 2);
-    assert_eq!(game2.player1_hand.len(), 2);
+    assert_eq!(game1.player1_hand, game2.player1_hand);
+    assert_eq!(game1.player2_hand, game2.player2_hand);
+    assert_eq!(game1.player2_hand.len(), 3);
 }
 
 #[test]
@@ -851,7 +1086,7 @@ fn test_round_counter() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.round, 1); // First round
@@ -870,3 +1105,369 @@ fn test_cannot_play_against_self() {
     let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
+
+// ============================================================================
+// Hidden Hand Tests
+// ============================================================================
+
+#[test]
+fn test_hidden_hands_stay_off_chain() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    start_hidden_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Only the card seeds are stored; the cards need each player's secret
+    let game = client.get_game(&session_id);
+    assert!(game.hidden);
+    assert!(game.player1_hand.is_empty());
+    assert!(game.player2_hand.is_empty());
+    assert_eq!(game.player1_card_seeds.len(), 2);
+    assert_eq!(game.player2_card_seeds.len(), 2);
+    assert_twenty_one_error(&client.try_get_hand_value(&session_id, &player1), Error::HandHidden);
+
+    hit(&client, &session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert!(game.player1_hand.is_empty());
+    assert_eq!(game.player1_card_seeds.len(), 3);
+    assert_eq!(game.player2_card_seeds.len(), 2);
+}
+
+#[test]
+fn test_hidden_game_requires_hand_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_hidden_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_seed(&session_id, &player1, &chain_tip(&env, &player1), &CHAIN_LEN);
+    client.commit_seed(&session_id, &player2, &chain_tip(&env, &player2), &CHAIN_LEN);
+
+    let result = client.try_reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::HandNotCommitted);
+
+    let commitment: BytesN<32> = env.crypto().keccak256(&Bytes::from(hand_secret(&env, &player1))).into();
+    client.commit_hand(&session_id, &player1, &commitment);
+    let result = client.try_commit_hand(&session_id, &player1, &commitment);
+    assert_twenty_one_error(&result, Error::HandAlreadyCommitted);
+    client.reveal_seed(&session_id, &player1, &next_link(&client, session_id, &player1));
+}
+
+#[test]
+fn test_reveal_hand_checks_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 32u32;
+    start_hidden_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let secret = hand_secret(&env, &player1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret);
+    assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    assert_twenty_one_error(&client.try_reveal_winner(&session_id), Error::HandsNotRevealed);
+
+    let result = client.try_reveal_hand(&session_id, &player1, &hand_secret(&env, &player2));
+    assert_twenty_one_error(&result, Error::InvalidReveal);
+
+    client.reveal_hand(&session_id, &player1, &secret);
+    assert_eq!(client.get_game(&session_id).player1_hand, hidden_hand(&client, &session_id, &player1));
+    assert_twenty_one_error(&client.try_reveal_hand(&session_id, &player1, &secret), Error::AlreadyRevealed);
+    assert_twenty_one_error(&client.try_reveal_winner(&session_id), Error::HandsNotRevealed);
+}
+
+#[test]
+fn test_hidden_reveal_winner_scores_revealed_hands() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 33u32;
+    start_hidden_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let hand1 = hidden_hand(&client, &session_id, &player1);
+    let hand2 = hidden_hand(&client, &session_id, &player2);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &hand_secret(&env, &player1));
+    client.reveal_hand(&session_id, &player2, &hand_secret(&env, &player2));
+
+    let value1 = client.get_hand_value(&session_id, &player1);
+    let value2 = client.get_hand_value(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_hand, hand1);
+    assert_eq!(game.player2_hand, hand2);
+
+    let winner = client.reveal_winner(&session_id);
+    if value1 > value2 {
+        assert_eq!(winner, Some(player1));
+    } else if value2 > value1 {
+        assert_eq!(winner, Some(player2));
+    } else {
+        // A draw needs fresh hand commitments for the next round
+        assert_eq!(winner, None);
+        let game = client.get_game(&session_id);
+        assert_eq!(game.player1_hand_commitment, None);
+        assert!(game.player1_card_seeds.is_empty());
+    }
+}
+
+#[test]
+fn test_hand_commands_require_hidden_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 34u32;
+    start_game(&client, &session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let secret = hand_secret(&env, &player1);
+    assert_twenty_one_error(&client.try_commit_hand(&session_id, &player1, &secret), Error::NotHiddenGame);
+    assert_twenty_one_error(&client.try_reveal_hand(&session_id, &player1, &secret), Error::NotHiddenGame);
+}
+
+// ============================================================================
+// Integration Tests (mock-game-hub)
+// ============================================================================
+
+fn setup_with_hub() -> (
+    Env,
+    TwentyOneContractClient<'static>,
+    hub::MockGameHubClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hub_addr = env.register(hub::MockGameHub, (&admin,));
+    let game_hub = hub::MockGameHubClient::new(&env, &hub_addr);
+
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+
+    // The hub only opens sessions for registered games
+    game_hub.add_game(&contract_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    (env, client, game_hub, player1, player2)
+}
+
+#[test]
+fn test_hub_settles_stakes_on_reveal() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 1u32;
+    start_game(&client, &session_id, &player1, &player2, &70_0000000, &30_0000000);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 70_0000000);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 30_0000000);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    match client.reveal_winner(&session_id) {
+        Some(winner) => {
+            let (loser, loser_stake) = if winner == player1 {
+                (player2.clone(), 30_0000000)
+            } else {
+                (player1.clone(), 70_0000000)
+            };
+            assert_eq!(game_hub.get_balance(&winner), hub::STARTING_POINTS + loser_stake);
+            assert_eq!(game_hub.get_balance(&loser), hub::STARTING_POINTS - loser_stake);
+            assert_eq!(game_hub.get_standing(&winner).wins, 1);
+            assert_eq!(game_hub.get_standing(&loser).losses, 1);
+            assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Ended);
+        }
+        None => {
+            // A draw leaves the session open with both stakes still locked
+            assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Active);
+            assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 70_0000000);
+        }
+    }
+}
+
+#[test]
+fn test_hub_pays_opponent_of_bust_player() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 2u32;
+    start_game(&client, &session_id, &player1, &player2, &50_0000000, &50_0000000);
+
+    // Player1 hits until bust, which ends the session in player2's favour
+    while client.get_game(&session_id).winner.is_none() {
+        hit(&client, &session_id, &player1);
+    }
+
+    assert_eq!(client.reveal_winner(&session_id), Some(player2.clone()));
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS + 50_0000000);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 50_0000000);
+    assert_eq!(game_hub.get_leaderboard(&10).get_unchecked(0).player, player2);
+}
+
+#[test]
+fn test_hub_pays_player_when_opponent_stops_revealing() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 3u32;
+    start_game(&client, &session_id, &player1, &player2, &50_0000000, &20_0000000);
+
+    // Player 2 never reveals the link that would deal player 1's hit
+    client.hit(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&client.try_claim_timeout(&session_id), Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).deal_deadline.unwrap();
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.claim_timeout(&session_id), Some(player1.clone()));
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.forfeited, vec![&env, player2.clone()]);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS + 20_0000000);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 20_0000000);
+    let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_cancels_game_nobody_seeds() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 4u32;
+    client.start_game(&session_id, &player1, &player2, &50_0000000, &20_0000000);
+
+    env.ledger().set_sequence_number(DEAL_TIMEOUT_LEDGERS + 1);
+    assert_eq!(client.claim_timeout(&session_id), None);
+
+    assert_eq!(client.get_game(&session_id).forfeited.len(), 2);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Cancelled);
+}
+
+#[test]
+fn test_claim_timeout_requires_pending_deal() {
+    let (_env, client, _game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 5u32;
+    start_game(&client, &session_id, &player1, &player2, &50_0000000, &50_0000000);
+    assert_twenty_one_error(&client.try_claim_timeout(&session_id), Error::DeadlineNotReached);
+
+    // Once both stuck, anyone can reveal the winner; there is nothing to wait on
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    assert_twenty_one_error(&client.try_claim_timeout(&session_id), Error::NoDealPending);
+}
+
+#[test]
+fn test_hub_pays_stuck_player_when_opponent_stalls_turn() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 8u32;
+    start_game(&client, &session_id, &player1, &player2, &50_0000000, &20_0000000);
+    assert_eq!(client.get_game(&session_id).turn_deadline, Some(TURN_TIMEOUT_LEDGERS));
+
+    // Player 1 sticks, restarting the clock; player 2 never plays
+    env.ledger().set_sequence_number(10);
+    client.stick(&session_id, &player1);
+    let deadline = client.get_game(&session_id).turn_deadline.unwrap();
+    assert_eq!(deadline, 10 + TURN_TIMEOUT_LEDGERS);
+    env.ledger().set_sequence_number(deadline);
+    assert_twenty_one_error(&client.try_claim_timeout(&session_id), Error::DeadlineNotReached);
+
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.claim_timeout(&session_id), Some(player1.clone()));
+    assert_eq!(client.get_game(&session_id).forfeited, vec![&env, player2.clone()]);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS + 20_0000000);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 20_0000000);
+}
+
+#[test]
+fn test_hub_cancels_game_nobody_plays() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 9u32;
+    start_game(&client, &session_id, &player1, &player2, &50_0000000, &20_0000000);
+
+    env.ledger().set_sequence_number(TURN_TIMEOUT_LEDGERS + 1);
+    assert_eq!(client.claim_timeout(&session_id), None);
+    assert_eq!(client.get_game(&session_id).forfeited.len(), 2);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS);
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Cancelled);
+}
+
+#[test]
+fn test_hub_refunds_tie_once_seed_chains_spent() {
+    let (_env, client, game_hub, player1, player2) = setup_with_hub();
+
+    // Hands depend on the players' seeds, so play sessions until one ties
+    let mut session_id = 100u32;
+    loop {
+        session_id += 1;
+        assert!(session_id <= 300, "no tie in 200 sessions");
+        start_game(&client, &session_id, &player1, &player2, &50_0000000, &20_0000000);
+        let game = client.get_game(&session_id);
+        if calculate_hand_value_helper(&game.player1_hand) == calculate_hand_value_helper(&game.player2_hand) {
+            break;
+        }
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_winner(&session_id);
+    }
+
+    // No link is left for another round, so the tie is final
+    spend_seed_chains(&client, session_id);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    assert_eq!(client.reveal_winner(&session_id), None);
+
+    let game = client.get_game(&session_id);
+    assert!(game.is_draw);
+    assert_eq!(game.round, 1);
+    assert_eq!(game_hub.get_session(&client.address, &session_id).status, hub::SessionStatus::Ended);
+    let result = client.try_hit(&session_id, &player1, &next_link(&client, session_id, &player1));
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_hidden_bust_loses_at_reveal() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 6u32;
+    start_hidden_game(&client, &session_id, &player1, &player2, &50_0000000, &50_0000000);
+
+    // Player 1 keeps hitting past 21; nobody else can tell until the reveal
+    while crate::calculate_hand_value(&hidden_hand(&client, &session_id, &player1)).unwrap() <= 21 {
+        hit(&client, &session_id, &player1);
+    }
+    assert_eq!(client.get_game(&session_id).winner, None);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &hand_secret(&env, &player1));
+    client.reveal_hand(&session_id, &player2, &hand_secret(&env, &player2));
+
+    assert_eq!(client.reveal_winner(&session_id), Some(player2.clone()));
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS + 50_0000000);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS - 50_0000000);
+}
+
+#[test]
+fn test_hub_pays_player_when_opponent_hides_hand() {
+    let (env, client, game_hub, player1, player2) = setup_with_hub();
+
+    let session_id = 7u32;
+    start_hidden_game(&client, &session_id, &player1, &player2, &50_0000000, &20_0000000);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    // Player 2 never reveals their hand
+    client.reveal_hand(&session_id, &player1, &hand_secret(&env, &player1));
+    assert_twenty_one_error(&client.try_claim_timeout(&session_id), Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).reveal_deadline.unwrap();
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.claim_timeout(&session_id), Some(player1.clone()));
+
+    assert_eq!(client.get_game(&session_id).forfeited, vec![&env, player2.clone()]);
+    assert_eq!(game_hub.get_balance(&player1), hub::STARTING_POINTS + 20_0000000);
+    assert_eq!(game_hub.get_balance(&player2), hub::STARTING_POINTS - 20_0000000);
+}
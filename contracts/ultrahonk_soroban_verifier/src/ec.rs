@@ -0,0 +1,96 @@
+use crate::error::VerifierError;
+use crate::field::{Fq, Fr};
+use soroban_sdk::{
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr as HostFr},
+    Env, Vec, U256,
+};
+
+/// G2 generator, Ethereum encoding (`x.c1 || x.c0 || y.c1 || y.c0`).
+const G2_GENERATOR_BYTES: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+/// `[x]_2` from the Aztec Ignition SRS, same encoding as the generator.
+const G2_SRS_X_BYTES: [u8; 128] = [
+    0x26, 0x0e, 0x01, 0xb2, 0x51, 0xf6, 0xf1, 0xc7, 0xe7, 0xff, 0x4e, 0x58, 0x07, 0x91, 0xde, 0xe8,
+    0xea, 0x51, 0xd8, 0x7a, 0x35, 0x8e, 0x03, 0x8b, 0x4e, 0xfe, 0x30, 0xfa, 0xc0, 0x93, 0x83, 0xc1,
+    0x01, 0x18, 0xc4, 0xd5, 0xb8, 0x37, 0xbc, 0xc2, 0xbc, 0x89, 0xb5, 0xb3, 0x98, 0xb5, 0x97, 0x4e,
+    0x9f, 0x59, 0x44, 0x07, 0x3b, 0x32, 0x07, 0x8b, 0x7e, 0x23, 0x1f, 0xec, 0x93, 0x88, 0x83, 0xb0,
+    0x04, 0xfc, 0x63, 0x69, 0xf7, 0x11, 0x0f, 0xe3, 0xd2, 0x51, 0x56, 0xc1, 0xbb, 0x9a, 0x72, 0x85,
+    0x9c, 0xf2, 0xa0, 0x46, 0x41, 0xf9, 0x9b, 0xa4, 0xee, 0x41, 0x3c, 0x80, 0xda, 0x6a, 0x5f, 0xe4,
+    0x22, 0xfe, 0xbd, 0xa3, 0xc0, 0xc0, 0x63, 0x2a, 0x56, 0x47, 0x5b, 0x42, 0x14, 0xe5, 0x61, 0x5e,
+    0x11, 0xe6, 0xdd, 0x3f, 0x96, 0xe6, 0xce, 0xa2, 0x85, 0x4a, 0x87, 0xd4, 0xda, 0xcc, 0x5e, 0x55,
+];
+
+pub fn g1_infinity(env: &Env) -> Bn254G1Affine {
+    Bn254G1Affine::from_array(env, &[0u8; 64])
+}
+
+pub fn g1_generator(env: &Env) -> Bn254G1Affine {
+    let mut xy = [0u8; 64];
+    xy[31] = 1;
+    xy[63] = 2;
+    Bn254G1Affine::from_array(env, &xy)
+}
+
+/// Builds a G1 point from big-endian coordinates, checking that both are
+/// canonical and that the point lies on `y^2 = x^3 + 3`. `(0, 0)` encodes
+/// the point at infinity.
+pub fn g1_from_xy(env: &Env, x: &[u8; 32], y: &[u8; 32]) -> Result<Bn254G1Affine, VerifierError> {
+    let fx = Fq::from_canonical_bytes(x).ok_or(VerifierError::InvalidPointEncoding)?;
+    let fy = Fq::from_canonical_bytes(y).ok_or(VerifierError::InvalidPointEncoding)?;
+    let is_infinity = fx.is_zero() && fy.is_zero();
+    if !is_infinity && fy.square() != fx.square() * fx + Fq::from_u64(3) {
+        return Err(VerifierError::PointNotOnCurve);
+    }
+    let mut xy = [0u8; 64];
+    xy[..32].copy_from_slice(x);
+    xy[32..].copy_from_slice(y);
+    Ok(Bn254G1Affine::from_array(env, &xy))
+}
+
+fn to_host_fr(env: &Env, s: &Fr) -> HostFr {
+    HostFr::from_u256(U256::from_be_bytes(
+        env,
+        &soroban_sdk::Bytes::from_array(env, &s.to_bytes()),
+    ))
+}
+
+/// `sum(scalars[i] * points[i])`, skipping zero scalars.
+pub fn g1_msm(env: &Env, points: &[Bn254G1Affine], scalars: &[Fr]) -> Bn254G1Affine {
+    let bn254 = env.crypto().bn254();
+    let mut acc: Option<Bn254G1Affine> = None;
+    for (p, s) in points.iter().zip(scalars.iter()) {
+        if s.is_zero() {
+            continue;
+        }
+        let term = if *s == Fr::one() {
+            p.clone()
+        } else {
+            bn254.g1_mul(p, &to_host_fr(env, s))
+        };
+        acc = Some(match acc {
+            Some(a) => bn254.g1_add(&a, &term),
+            None => term,
+        });
+    }
+    acc.unwrap_or_else(|| g1_infinity(env))
+}
+
+/// Checks `e(p0, [1]_2) * e(p1, [x]_2) == 1`.
+pub fn pairing_check(env: &Env, p0: &Bn254G1Affine, p1: &Bn254G1Affine) -> bool {
+    let mut g1s: Vec<Bn254G1Affine> = Vec::new(env);
+    g1s.push_back(p0.clone());
+    g1s.push_back(p1.clone());
+    let mut g2s: Vec<Bn254G2Affine> = Vec::new(env);
+    g2s.push_back(Bn254G2Affine::from_array(env, &G2_GENERATOR_BYTES));
+    g2s.push_back(Bn254G2Affine::from_array(env, &G2_SRS_X_BYTES));
+    env.crypto().bn254().pairing_check(g1s, g2s)
+}
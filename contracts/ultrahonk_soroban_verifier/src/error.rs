@@ -0,0 +1,44 @@
+/// Reasons a verification key or proof is rejected.
+///
+/// Variants up to `InvalidGeminiPadding` mean the input is malformed;
+/// `SumcheckFailed` and `PairingCheckFailed` mean a well-formed proof did
+/// not verify. See [`VerifierError::is_malformed`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum VerifierError {
+    /// Verification key is not exactly [`crate::VK_BYTES`] long.
+    InvalidVkLength = 1,
+    /// `log_circuit_size` is out of range or `circuit_size != 2^log_circuit_size`.
+    InvalidCircuitSize = 2,
+    /// The VK public input count or offset does not fit the circuit.
+    InvalidPublicInputLayout = 3,
+    /// Proof is not exactly [`crate::PROOF_BYTES`] long.
+    InvalidProofLength = 4,
+    /// A point coordinate or limb is not a canonical base field element.
+    InvalidPointEncoding = 5,
+    /// A point is not on the BN254 curve.
+    PointNotOnCurve = 6,
+    /// A scalar is not a canonical field element.
+    InvalidScalarEncoding = 7,
+    /// Number of public inputs differs from the verification key.
+    PublicInputCountMismatch = 8,
+    /// A public input is not a canonical field element.
+    InvalidPublicInput = 9,
+    /// Gemini evaluations past `log_circuit_size` are not zero.
+    InvalidGeminiPadding = 10,
+    /// A sumcheck round or the final relation check failed.
+    SumcheckFailed = 11,
+    /// The final KZG pairing check failed.
+    PairingCheckFailed = 12,
+}
+
+impl VerifierError {
+    /// `true` when the inputs could not be decoded or do not match the key,
+    /// `false` when a well-formed proof was checked and rejected.
+    pub fn is_malformed(&self) -> bool {
+        !matches!(
+            self,
+            VerifierError::SumcheckFailed | VerifierError::PairingCheckFailed
+        )
+    }
+}
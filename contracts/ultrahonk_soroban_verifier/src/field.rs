@@ -0,0 +1,283 @@
+//! Software arithmetic for the BN254 scalar field `Fr` and base field `Fq`.
+//!
+//! soroban-sdk 25 exposes G1 addition/multiplication and the pairing check as
+//! host functions, but no scalar-field arithmetic, so the verifier does its
+//! field work here. Elements are kept in Montgomery form as four
+//! little-endian 64-bit limbs.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, ((t >> 64) as u64) & 1)
+}
+
+#[inline(always)]
+const fn mac(acc: u64, a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (acc as u128) + (a as u128) * (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+#[inline(always)]
+fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+#[inline(always)]
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut out = [0u64; 4];
+    let mut borrow = 0;
+    for i in 0..4 {
+        let (d, b) = sbb(a[i], b[i], borrow);
+        out[i] = d;
+        borrow = b;
+    }
+    (out, borrow)
+}
+
+#[inline(always)]
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut out = [0u64; 4];
+    let mut carry = 0;
+    for i in 0..4 {
+        let (s, c) = adc(a[i], b[i], carry);
+        out[i] = s;
+        carry = c;
+    }
+    (out, carry)
+}
+
+/// Montgomery multiplication (CIOS) for a modulus below 2^255.
+#[inline(always)]
+fn mont_mul(a: &[u64; 4], b: &[u64; 4], m: &[u64; 4], inv: u64) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    for &bi in b.iter() {
+        let mut carry = 0;
+        for j in 0..4 {
+            let (lo, hi) = mac(t[j], a[j], bi, carry);
+            t[j] = lo;
+            carry = hi;
+        }
+        let (lo, hi) = adc(t[4], carry, 0);
+        t[4] = lo;
+        t[5] = hi;
+
+        let k = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], k, m[0], 0);
+        for j in 1..4 {
+            let (lo, hi) = mac(t[j], k, m[j], carry);
+            t[j - 1] = lo;
+            carry = hi;
+        }
+        let (lo, hi) = adc(t[4], carry, 0);
+        t[3] = lo;
+        t[4] = t[5] + hi;
+    }
+    let r = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || geq(&r, m) {
+        sub_limbs(&r, m).0
+    } else {
+        r
+    }
+}
+
+fn limbs_from_be(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    for (i, limb) in out.iter_mut().enumerate() {
+        let start = 24 - i * 8;
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[start..start + 8]);
+        *limb = u64::from_be_bytes(word);
+    }
+    out
+}
+
+fn limbs_to_be(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (i, limb) in limbs.iter().enumerate() {
+        let start = 24 - i * 8;
+        out[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    out
+}
+
+macro_rules! prime_field {
+    ($(#[$doc:meta])* $name:ident, modulus: $m:expr, r: $r:expr, r2: $r2:expr, inv: $inv:expr) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+        pub struct $name([u64; 4]);
+
+        #[allow(dead_code)]
+        impl $name {
+            const MODULUS: [u64; 4] = $m;
+            const R: [u64; 4] = $r;
+            const R2: [u64; 4] = $r2;
+            const INV: u64 = $inv;
+
+            pub const fn zero() -> Self {
+                Self([0; 4])
+            }
+
+            pub const fn one() -> Self {
+                Self(Self::R)
+            }
+
+            pub fn from_u64(x: u64) -> Self {
+                Self(mont_mul(&[x, 0, 0, 0], &Self::R2, &Self::MODULUS, Self::INV))
+            }
+
+            /// Parses a big-endian integer, reducing it modulo the field order.
+            pub fn from_bytes(bytes: &[u8; 32]) -> Self {
+                let mut v = limbs_from_be(bytes);
+                while geq(&v, &Self::MODULUS) {
+                    v = sub_limbs(&v, &Self::MODULUS).0;
+                }
+                Self(mont_mul(&v, &Self::R2, &Self::MODULUS, Self::INV))
+            }
+
+            /// Parses a big-endian integer, rejecting values outside `[0, modulus)`.
+            pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+                let v = limbs_from_be(bytes);
+                if geq(&v, &Self::MODULUS) {
+                    return None;
+                }
+                Some(Self(mont_mul(&v, &Self::R2, &Self::MODULUS, Self::INV)))
+            }
+
+            pub fn to_bytes(self) -> [u8; 32] {
+                limbs_to_be(&mont_mul(&self.0, &[1, 0, 0, 0], &Self::MODULUS, Self::INV))
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0 == [0; 4]
+            }
+
+            pub fn square(&self) -> Self {
+                *self * *self
+            }
+
+            pub fn pow(&self, mut exp: u64) -> Self {
+                let mut base = *self;
+                let mut acc = Self::one();
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        acc = acc * base;
+                    }
+                    base = base.square();
+                    exp >>= 1;
+                }
+                acc
+            }
+
+            /// Multiplicative inverse via Fermat's little theorem; `None` for zero.
+            pub fn inverse(&self) -> Option<Self> {
+                if self.is_zero() {
+                    return None;
+                }
+                let exp = sub_limbs(&Self::MODULUS, &[2, 0, 0, 0]).0;
+                let mut acc = Self::one();
+                for limb in exp.iter().rev() {
+                    for bit in (0..64).rev() {
+                        acc = acc.square();
+                        if (limb >> bit) & 1 == 1 {
+                            acc = acc * *self;
+                        }
+                    }
+                }
+                Some(acc)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                let (s, _) = add_limbs(&self.0, &rhs.0);
+                if geq(&s, &Self::MODULUS) {
+                    Self(sub_limbs(&s, &Self::MODULUS).0)
+                } else {
+                    Self(s)
+                }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                let (d, borrow) = sub_limbs(&self.0, &rhs.0);
+                if borrow != 0 {
+                    Self(add_limbs(&d, &Self::MODULUS).0)
+                } else {
+                    Self(d)
+                }
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, rhs: Self) -> Self {
+                Self(mont_mul(&self.0, &rhs.0, &Self::MODULUS, Self::INV))
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self::zero() - self
+            }
+        }
+    };
+}
+
+prime_field!(
+    /// Element of the BN254 scalar field
+    /// `r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001`.
+    Fr,
+    modulus: [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029],
+    r: [0xac96341c4ffffffb, 0x36fc76959f60cd29, 0x666ea36f7879462e, 0x0e0a77c19a07df2f],
+    r2: [0x1bb8e645ae216da7, 0x53fe3ab1e35c59e3, 0x8c49833d53bb8085, 0x0216d0b17f4e44a5],
+    inv: 0xc2e1f593efffffff
+);
+
+prime_field!(
+    /// Element of the BN254 base field
+    /// `q = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47`.
+    Fq,
+    modulus: [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029],
+    r: [0xd35d438dc58f0d9d, 0x0a78eb28f5c70b3d, 0x666ea36f7879462c, 0x0e0a77c19a07df2f],
+    r2: [0xf32cfc5b538afa89, 0xb5e71911d44501fb, 0x47ab1eff0a417ff6, 0x06d89f71cab8351f],
+    inv: 0x87d20782e4866389
+);
+
+/// Inverts every element of `vals` into `out` with a single field inversion.
+pub fn batch_inverse(vals: &[Fr], out: &mut [Fr]) -> Result<(), &'static str> {
+    if vals.len() != out.len() {
+        return Err("batch_inverse: length mismatch");
+    }
+    let mut acc = Fr::one();
+    for (v, o) in vals.iter().zip(out.iter_mut()) {
+        *o = acc;
+        acc = acc * *v;
+    }
+    let mut inv = acc.inverse().ok_or("batch_inverse: zero element")?;
+    for (v, o) in vals.iter().zip(out.iter_mut()).rev() {
+        *o = *o * inv;
+        inv = inv * *v;
+    }
+    Ok(())
+}
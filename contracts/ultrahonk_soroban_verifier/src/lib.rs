@@ -1,24 +1,25 @@
 #![no_std]
 
-use soroban_sdk::{Env, Bytes, BytesN, Vec};
+//! UltraHonk proof verifier for Soroban.
+//!
+//! Verifies proofs produced by Barretenberg 0.87.0 (nargo 1.0.0-beta.9) with
+//! `--scheme ultra_honk --oracle_hash keccak`, i.e. the non-ZK UltraKeccak
+//! flavor over BN254. The verification flow follows rs-soroban-ultrahonk
+//! (MIT OR Apache-2.0), adapted to soroban-sdk 25: G1 arithmetic and the
+//! pairing check run on the host, scalar-field arithmetic runs in `field`.
 
-pub mod verifier {
-    use soroban_sdk::{Env, Bytes, BytesN, Vec};
+mod ec;
+mod error;
+mod field;
+mod relations;
+mod shplemini;
+mod sumcheck;
+mod transcript;
+mod types;
+pub mod verifier;
 
-    pub struct UltraHonkVerifier;
+pub use error::VerifierError;
+pub use types::{Proof, VerificationKey, PROOF_BYTES, PROOF_FIELDS, VK_BYTES};
 
-    impl UltraHonkVerifier {
-        // Minimal verify signature matching caller in noir-verifier
-        pub fn verify(
-            _env: &Env,
-            _proof: &Bytes,
-            _public_inputs: &Vec<BytesN<32>>,
-            _vk_bytes: &Bytes,
-        ) -> bool {
-            // Stub: in local/test builds we optimistically return true.
-            // For production you MUST replace this crate with the real
-            // ultrahonk verifier implementation or link the proper crate.
-            true
-        }
-    }
-}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,311 @@
+//! UltraHonk subrelations evaluated at the sumcheck point.
+
+use crate::field::Fr;
+use crate::types::{RelationParameters, Wire, NUMBER_OF_SUBRELATIONS};
+use core::ops::Index;
+
+/// Sumcheck evaluations addressed by entity.
+struct Evals<'a>(&'a [Fr]);
+
+impl Index<Wire> for Evals<'_> {
+    type Output = Fr;
+
+    #[inline(always)]
+    fn index(&self, wire: Wire) -> &Fr {
+        &self.0[wire as usize]
+    }
+}
+
+fn fr(hex: &[u8; 32]) -> Fr {
+    Fr::from_bytes(hex)
+}
+
+/// `-1/2 mod r`.
+const NEG_HALF: [u8; 32] = [
+    0x18, 0x32, 0x27, 0x39, 0x70, 0x98, 0xd0, 0x14, 0xdc, 0x28, 0x22, 0xdb, 0x40, 0xc0, 0xac, 0x2e,
+    0x94, 0x19, 0xf4, 0x24, 0x3c, 0xdc, 0xb8, 0x48, 0xa1, 0xf0, 0xfa, 0xc9, 0xf8, 0x00, 0x00, 0x00,
+];
+
+/// Diagonal of the Poseidon2 internal matrix minus one (t = 4).
+const POSEIDON2_INTERNAL_DIAG: [[u8; 32]; 4] = [
+    [
+        0x10, 0xdc, 0x6e, 0x9c, 0x00, 0x6e, 0xa3, 0x8b, 0x04, 0xb1, 0xe0, 0x3b, 0x4b, 0xd9, 0x49,
+        0x0c, 0x0d, 0x03, 0xf9, 0x89, 0x29, 0xca, 0x1d, 0x7f, 0xb5, 0x68, 0x21, 0xfd, 0x19, 0xd3,
+        0xb6, 0xe7,
+    ],
+    [
+        0x0c, 0x28, 0x14, 0x5b, 0x6a, 0x44, 0xdf, 0x3e, 0x01, 0x49, 0xb3, 0xd0, 0xa3, 0x0b, 0x3b,
+        0xb5, 0x99, 0xdf, 0x97, 0x56, 0xd4, 0xdd, 0x9b, 0x84, 0xa8, 0x6b, 0x38, 0xcf, 0xb4, 0x5a,
+        0x74, 0x0b,
+    ],
+    [
+        0x00, 0x54, 0x4b, 0x83, 0x38, 0x79, 0x15, 0x18, 0xb2, 0xc7, 0x64, 0x5a, 0x50, 0x39, 0x27,
+        0x98, 0xb2, 0x1f, 0x75, 0xbb, 0x60, 0xe3, 0x59, 0x61, 0x70, 0x06, 0x7d, 0x00, 0x14, 0x1c,
+        0xac, 0x15,
+    ],
+    [
+        0x22, 0x2c, 0x01, 0x17, 0x57, 0x18, 0x38, 0x6f, 0x2e, 0x2e, 0x82, 0xeb, 0x12, 0x27, 0x89,
+        0xe3, 0x52, 0xe1, 0x05, 0xa3, 0xb8, 0xfa, 0x85, 0x26, 0x13, 0xbc, 0x53, 0x44, 0x33, 0xee,
+        0x42, 0x8b,
+    ],
+];
+
+fn arithmetic(p: &Evals, evals: &mut [Fr], dom: Fr) {
+    let one = Fr::one();
+    let q_arith = p[Wire::QArith];
+
+    let mut accum = (q_arith - Fr::from_u64(3)) * p[Wire::Qm] * p[Wire::Wr] * p[Wire::Wl]
+        * fr(&NEG_HALF);
+    accum = accum
+        + p[Wire::Ql] * p[Wire::Wl]
+        + p[Wire::Qr] * p[Wire::Wr]
+        + p[Wire::Qo] * p[Wire::Wo]
+        + p[Wire::Q4] * p[Wire::W4]
+        + p[Wire::Qc];
+    evals[0] = (accum + (q_arith - one) * p[Wire::W4Shift]) * q_arith * dom;
+
+    let accum = p[Wire::Wl] + p[Wire::W4] - p[Wire::WlShift] + p[Wire::Qm];
+    evals[1] = accum * (q_arith - Fr::from_u64(2)) * (q_arith - one) * q_arith * dom;
+}
+
+fn permutation(p: &Evals, rp: &RelationParameters, evals: &mut [Fr], dom: Fr) {
+    let term = |w: Wire, s: Wire| p[w] + p[s] * rp.beta + rp.gamma;
+    let numerator = term(Wire::Wl, Wire::Id1)
+        * term(Wire::Wr, Wire::Id2)
+        * term(Wire::Wo, Wire::Id3)
+        * term(Wire::W4, Wire::Id4);
+    let denominator = term(Wire::Wl, Wire::Sigma1)
+        * term(Wire::Wr, Wire::Sigma2)
+        * term(Wire::Wo, Wire::Sigma3)
+        * term(Wire::W4, Wire::Sigma4);
+
+    evals[2] = ((p[Wire::ZPerm] + p[Wire::LagrangeFirst]) * numerator
+        - (p[Wire::ZPermShift] + p[Wire::LagrangeLast] * rp.public_inputs_delta) * denominator)
+        * dom;
+    evals[3] = p[Wire::LagrangeLast] * p[Wire::ZPermShift] * dom;
+}
+
+fn log_derivative_lookup(p: &Evals, rp: &RelationParameters, evals: &mut [Fr], dom: Fr) {
+    let write_term = p[Wire::Table1]
+        + rp.gamma
+        + p[Wire::Table2] * rp.eta
+        + p[Wire::Table3] * rp.eta_two
+        + p[Wire::Table4] * rp.eta_three;
+
+    let derived_entry_2 = p[Wire::Wr] + p[Wire::Qm] * p[Wire::WrShift];
+    let derived_entry_3 = p[Wire::Wo] + p[Wire::Qc] * p[Wire::WoShift];
+    let read_term = p[Wire::Wl]
+        + rp.gamma
+        + p[Wire::Qr] * p[Wire::WlShift]
+        + derived_entry_2 * rp.eta
+        + derived_entry_3 * rp.eta_two
+        + p[Wire::Qo] * rp.eta_three;
+
+    let inv = p[Wire::LookupInverses];
+    let read_tags = p[Wire::LookupReadTags];
+    let q_lookup = p[Wire::QLookup];
+    let inv_exists = read_tags + q_lookup - read_tags * q_lookup;
+
+    evals[4] = (read_term * write_term * inv - inv_exists) * dom;
+    evals[5] = q_lookup * (write_term * inv) - p[Wire::LookupReadCounts] * (read_term * inv);
+}
+
+fn delta_range(p: &Evals, evals: &mut [Fr], dom: Fr) {
+    let deltas = [
+        p[Wire::Wr] - p[Wire::Wl],
+        p[Wire::Wo] - p[Wire::Wr],
+        p[Wire::W4] - p[Wire::Wo],
+        p[Wire::WlShift] - p[Wire::W4],
+    ];
+    let q_range_dom = p[Wire::QRange] * dom;
+    for (i, delta) in deltas.iter().enumerate() {
+        let mut acc = *delta;
+        for n in 1..=3 {
+            acc = acc * (*delta - Fr::from_u64(n));
+        }
+        evals[6 + i] = acc * q_range_dom;
+    }
+}
+
+fn elliptic(p: &Evals, evals: &mut [Fr], dom: Fr) {
+    let x1 = p[Wire::Wr];
+    let y1 = p[Wire::Wo];
+    let x2 = p[Wire::WlShift];
+    let y2 = p[Wire::W4Shift];
+    let x3 = p[Wire::WrShift];
+    let y3 = p[Wire::WoShift];
+    let q_sign = p[Wire::Ql];
+    let q_double = p[Wire::Qm];
+
+    let delta_x = x2 - x1;
+    let y1_sq = y1 * y1;
+
+    let y1y2 = y1 * y2 * q_sign;
+    let x_add_id = (x3 + x2 + x1) * delta_x * delta_x - y2 * y2 - y1_sq + y1y2 + y1y2;
+    let y_add_id = (y1 + y3) * delta_x + (x3 - x1) * (y2 * q_sign - y1);
+
+    // Grumpkin: y^2 = x^3 - 17.
+    let x_pow_4 = (y1_sq + Fr::from_u64(17)) * x1;
+    let x_double_id = (x3 + x1 + x1) * (y1_sq + y1_sq + y1_sq + y1_sq) - x_pow_4 * Fr::from_u64(9);
+    let y_double_id = (x1 + x1 + x1) * x1 * (x1 - x3) - (y1 + y1) * (y1 + y3);
+
+    let q_gate_dom = p[Wire::QElliptic] * dom;
+    let add_factor = (Fr::one() - q_double) * q_gate_dom;
+    let double_factor = q_double * q_gate_dom;
+
+    evals[10] = x_add_id * add_factor + x_double_id * double_factor;
+    evals[11] = y_add_id * add_factor + y_double_id * double_factor;
+}
+
+fn auxiliary(p: &Evals, rp: &RelationParameters, evals: &mut [Fr], dom: Fr) {
+    let one = Fr::one();
+    let mut limb_size_bytes = [0u8; 32];
+    limb_size_bytes[23] = 0x10;
+    let limb_size = fr(&limb_size_bytes); // 2^68
+    let sublimb_shift = Fr::from_u64(1 << 14);
+
+    let wl = p[Wire::Wl];
+    let wr = p[Wire::Wr];
+    let wo = p[Wire::Wo];
+    let w4 = p[Wire::W4];
+    let wl_shift = p[Wire::WlShift];
+    let wr_shift = p[Wire::WrShift];
+    let wo_shift = p[Wire::WoShift];
+    let w4_shift = p[Wire::W4Shift];
+    let ql = p[Wire::Ql];
+    let qr = p[Wire::Qr];
+    let qo = p[Wire::Qo];
+    let q4 = p[Wire::Q4];
+    let qm = p[Wire::Qm];
+    let qc = p[Wire::Qc];
+    let q_aux = p[Wire::QAux];
+    let q_arith = p[Wire::QArith];
+
+    // Non-native field arithmetic.
+    let mut limb_subproduct = wl * wr_shift + wl_shift * wr;
+    let non_native_field_gate_2 =
+        ((wl * w4 + wr * wo - wo_shift) * limb_size - w4_shift + limb_subproduct) * q4;
+    limb_subproduct = limb_size * limb_subproduct + wl_shift * wr_shift;
+    let non_native_field_gate_1 = (limb_subproduct - (wo + w4)) * qo;
+    let non_native_field_gate_3 = (w4 - (wo_shift + w4_shift) + limb_subproduct) * qm;
+    let non_native_field_identity =
+        (non_native_field_gate_1 + non_native_field_gate_2 + non_native_field_gate_3) * qr;
+
+    // Limb accumulation.
+    let mut limb_accumulator_1 = wr_shift * sublimb_shift + wl_shift;
+    limb_accumulator_1 = limb_accumulator_1 * sublimb_shift + wo;
+    limb_accumulator_1 = limb_accumulator_1 * sublimb_shift + wr;
+    limb_accumulator_1 = limb_accumulator_1 * sublimb_shift + wl;
+    limb_accumulator_1 = (limb_accumulator_1 - w4) * q4;
+    let mut limb_accumulator_2 = wo_shift * sublimb_shift + wr_shift;
+    limb_accumulator_2 = limb_accumulator_2 * sublimb_shift + wl_shift;
+    limb_accumulator_2 = limb_accumulator_2 * sublimb_shift + w4;
+    limb_accumulator_2 = limb_accumulator_2 * sublimb_shift + wo;
+    limb_accumulator_2 = (limb_accumulator_2 - w4_shift) * qm;
+    let limb_accumulator_identity = (limb_accumulator_1 + limb_accumulator_2) * qo;
+
+    // Memory (ROM / RAM).
+    let memory_record_check = wo * rp.eta_three + wr * rp.eta_two + wl * rp.eta + qc;
+    let access_type = w4 - memory_record_check;
+    let memory_record_check = memory_record_check - w4;
+
+    let index_delta = wl_shift - wl;
+    let record_delta = w4_shift - w4;
+    let index_is_monotonically_increasing = index_delta * index_delta - index_delta;
+    let adjacent_values_match_if_adjacent_indices_match = (one - index_delta) * record_delta;
+
+    let rom_gate_common = ql * qr * q_aux * dom;
+    evals[13] = adjacent_values_match_if_adjacent_indices_match * rom_gate_common;
+    evals[14] = index_is_monotonically_increasing * rom_gate_common;
+
+    let access_check = access_type * access_type - access_type;
+    let next_gate_access_type =
+        w4_shift - (wo_shift * rp.eta_three + wr_shift * rp.eta_two + wl_shift * rp.eta);
+    let value_delta = wo_shift - wo;
+    let adjacent_values_match_on_read =
+        (one - index_delta) * value_delta * (one - next_gate_access_type);
+
+    let ram_gate_common = q_arith * q_aux * dom;
+    evals[15] = adjacent_values_match_on_read * ram_gate_common;
+    evals[16] = index_is_monotonically_increasing * ram_gate_common;
+    evals[17] = (next_gate_access_type * next_gate_access_type - next_gate_access_type)
+        * ram_gate_common;
+
+    let rom_consistency_check_identity = memory_record_check * ql * qr;
+    let ram_timestamp_check_identity = (one - index_delta) * (wr_shift - wr) - wo;
+    let ram_consistency_check_identity = access_check * q_arith;
+    let memory_identity = rom_consistency_check_identity
+        + ram_timestamp_check_identity * q4 * ql
+        + memory_record_check * qm * ql
+        + ram_consistency_check_identity;
+
+    let auxiliary_identity = memory_identity + non_native_field_identity + limb_accumulator_identity;
+    evals[12] = auxiliary_identity * q_aux * dom;
+}
+
+fn poseidon2_external(p: &Evals, evals: &mut [Fr], dom: Fr) {
+    let u1 = (p[Wire::Wl] + p[Wire::Ql]).pow(5);
+    let u2 = (p[Wire::Wr] + p[Wire::Qr]).pow(5);
+    let u3 = (p[Wire::Wo] + p[Wire::Qo]).pow(5);
+    let u4 = (p[Wire::W4] + p[Wire::Q4]).pow(5);
+
+    // External matrix M_E = [[5,7,1,3],[4,6,1,1],[1,3,5,7],[1,1,4,6]].
+    let t0 = u1 + u2;
+    let t1 = u3 + u4;
+    let t2 = u2 + u2 + t1;
+    let t3 = u4 + u4 + t0;
+    let v4 = t1 + t1 + t1 + t1 + t3;
+    let v2 = t0 + t0 + t0 + t0 + t2;
+    let v1 = t3 + v2;
+    let v3 = t2 + v4;
+
+    let q_dom = p[Wire::QPoseidon2External] * dom;
+    evals[18] = (v1 - p[Wire::WlShift]) * q_dom;
+    evals[19] = (v2 - p[Wire::WrShift]) * q_dom;
+    evals[20] = (v3 - p[Wire::WoShift]) * q_dom;
+    evals[21] = (v4 - p[Wire::W4Shift]) * q_dom;
+}
+
+fn poseidon2_internal(p: &Evals, evals: &mut [Fr], dom: Fr) {
+    let u = [
+        (p[Wire::Wl] + p[Wire::Ql]).pow(5),
+        p[Wire::Wr],
+        p[Wire::Wo],
+        p[Wire::W4],
+    ];
+    let u_sum = u[0] + u[1] + u[2] + u[3];
+    let shifts = [Wire::WlShift, Wire::WrShift, Wire::WoShift, Wire::W4Shift];
+
+    let q_dom = p[Wire::QPoseidon2Internal] * dom;
+    for i in 0..4 {
+        let w = u[i] * fr(&POSEIDON2_INTERNAL_DIAG[i]) + u_sum;
+        evals[22 + i] = (w - p[shifts[i]]) * q_dom;
+    }
+}
+
+/// Evaluates every subrelation and batches them with the alpha challenges.
+pub fn accumulate_relation_evaluations(
+    purported_evaluations: &[Fr],
+    rp: &RelationParameters,
+    alphas: &[Fr],
+    pow_partial_evaluation: Fr,
+) -> Fr {
+    let p = Evals(purported_evaluations);
+    let dom = pow_partial_evaluation;
+    let mut evals = [Fr::zero(); NUMBER_OF_SUBRELATIONS];
+
+    arithmetic(&p, &mut evals, dom);
+    permutation(&p, rp, &mut evals, dom);
+    log_derivative_lookup(&p, rp, &mut evals, dom);
+    delta_range(&p, &mut evals, dom);
+    elliptic(&p, &mut evals, dom);
+    auxiliary(&p, rp, &mut evals, dom);
+    poseidon2_external(&p, &mut evals, dom);
+    poseidon2_internal(&p, &mut evals, dom);
+
+    let mut acc = evals[0];
+    for (e, alpha) in evals[1..].iter().zip(alphas.iter()) {
+        acc = acc + *e * *alpha;
+    }
+    acc
+}
@@ -0,0 +1,161 @@
+//! Shplemini batch opening: Gemini folding, Shplonk batching and the final
+//! KZG pairing check.
+
+use crate::ec::{g1_generator, g1_infinity, g1_msm, pairing_check};
+use crate::field::{batch_inverse, Fr};
+use crate::types::{
+    Proof, Transcript, VerificationKey, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ENTITIES,
+    NUMBER_UNSHIFTED,
+};
+use core::array;
+use soroban_sdk::{crypto::bn254::Bn254G1Affine, Env};
+
+/// shplonk_q + 35 unshifted commitments + 27 fold commitments + [1]_1 + kzg_quotient.
+const MSM_SIZE: usize = 1 + NUMBER_UNSHIFTED + (CONST_PROOF_SIZE_LOG_N - 1) + 2;
+
+pub fn verify_shplemini(
+    env: &Env,
+    proof: &Proof,
+    vk: &VerificationKey,
+    tp: &Transcript,
+    log_n: usize,
+) -> Result<(), &'static str> {
+    let one = Fr::one();
+    let mut r_pows = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
+    r_pows[0] = tp.gemini_r;
+    for i in 1..log_n {
+        r_pows[i] = r_pows[i - 1].square();
+    }
+
+    // Every denominator of the batch is inverted at once:
+    // [z - r, z + r, r, fold denominators (log_n), (z -/+ r^(2^j)) for j in 1..log_n].
+    const MAX_BATCH: usize = 3 * CONST_PROOF_SIZE_LOG_N + 1;
+    let batch_size = 3 + log_n + 2 * (log_n - 1);
+    let mut to_invert = [Fr::zero(); MAX_BATCH];
+    let mut inverted = [Fr::zero(); MAX_BATCH];
+    to_invert[0] = tp.shplonk_z - r_pows[0];
+    to_invert[1] = tp.shplonk_z + r_pows[0];
+    to_invert[2] = tp.gemini_r;
+    for j in (1..=log_n).rev() {
+        let u = tp.sumcheck_u_challenges[j - 1];
+        to_invert[3 + (log_n - j)] = r_pows[j - 1] * (one - u) + u;
+    }
+    let further_base = 3 + log_n;
+    for j in 1..log_n {
+        to_invert[further_base + 2 * (j - 1)] = tp.shplonk_z - r_pows[j];
+        to_invert[further_base + 2 * (j - 1) + 1] = tp.shplonk_z + r_pows[j];
+    }
+    batch_inverse(&to_invert[..batch_size], &mut inverted[..batch_size])?;
+
+    let pos0 = inverted[0];
+    let neg0 = inverted[1];
+    let gemini_r_inv = inverted[2];
+
+    let mut scalars = [Fr::zero(); MSM_SIZE];
+    let mut coms: [Bn254G1Affine; MSM_SIZE] = array::from_fn(|_| g1_infinity(env));
+
+    let unshifted = tp.shplonk_nu * neg0 + pos0;
+    let shifted = gemini_r_inv * (pos0 - tp.shplonk_nu * neg0);
+    scalars[0] = one;
+    coms[0] = proof.shplonk_q.clone();
+
+    // Batch the claimed evaluations with powers of rho.
+    let mut rho_pow = one;
+    let mut eval_acc = Fr::zero();
+    let mut eval_scalars = [Fr::zero(); NUMBER_OF_ENTITIES];
+    for (idx, eval) in proof.sumcheck_evaluations.iter().enumerate() {
+        let base = if idx < NUMBER_UNSHIFTED {
+            unshifted
+        } else {
+            shifted
+        };
+        eval_scalars[idx] = -base * rho_pow;
+        eval_acc = eval_acc + *eval * rho_pow;
+        rho_pow = rho_pow * tp.rho;
+    }
+    // Shifted evaluations open the same commitments as w1..w4 and z_perm.
+    for (unshifted, shifted) in [(27, 35), (28, 36), (29, 37), (30, 38), (31, 39)] {
+        eval_scalars[unshifted] = eval_scalars[unshifted] + eval_scalars[shifted];
+    }
+
+    let unshifted_coms = [
+        &vk.qm,
+        &vk.qc,
+        &vk.ql,
+        &vk.qr,
+        &vk.qo,
+        &vk.q4,
+        &vk.q_lookup,
+        &vk.q_arith,
+        &vk.q_delta_range,
+        &vk.q_elliptic,
+        &vk.q_aux,
+        &vk.q_poseidon2_external,
+        &vk.q_poseidon2_internal,
+        &vk.s1,
+        &vk.s2,
+        &vk.s3,
+        &vk.s4,
+        &vk.id1,
+        &vk.id2,
+        &vk.id3,
+        &vk.id4,
+        &vk.t1,
+        &vk.t2,
+        &vk.t3,
+        &vk.t4,
+        &vk.lagrange_first,
+        &vk.lagrange_last,
+        &proof.w1,
+        &proof.w2,
+        &proof.w3,
+        &proof.w4,
+        &proof.z_perm,
+        &proof.lookup_inverses,
+        &proof.lookup_read_counts,
+        &proof.lookup_read_tags,
+    ];
+    for (i, com) in unshifted_coms.iter().enumerate() {
+        coms[1 + i] = (*com).clone();
+        scalars[1 + i] = eval_scalars[i];
+    }
+
+    // Gemini: recover the positive fold evaluations from the negative ones.
+    let mut fold_pos = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
+    let mut cur = eval_acc;
+    for j in (1..=log_n).rev() {
+        let r2 = r_pows[j - 1];
+        let u = tp.sumcheck_u_challenges[j - 1];
+        let num = r2 * cur * Fr::from_u64(2) - proof.gemini_a_evaluations[j - 1] * (r2 * (one - u) - u);
+        cur = num * inverted[3 + (log_n - j)];
+        fold_pos[j - 1] = cur;
+    }
+
+    // Shplonk: batch the fold commitments.
+    let nu_sq = tp.shplonk_nu.square();
+    let mut const_acc = fold_pos[0] * pos0 + proof.gemini_a_evaluations[0] * tp.shplonk_nu * neg0;
+    let mut v_pow = nu_sq;
+    let base = 1 + NUMBER_UNSHIFTED;
+    for j in 1..log_n {
+        let sp = v_pow * inverted[further_base + 2 * (j - 1)];
+        let sn = v_pow * tp.shplonk_nu * inverted[further_base + 2 * (j - 1) + 1];
+        scalars[base + j - 1] = -(sp + sn);
+        const_acc = const_acc + proof.gemini_a_evaluations[j] * sn + fold_pos[j] * sp;
+        v_pow = v_pow * nu_sq;
+        coms[base + j - 1] = proof.gemini_fold_comms[j - 1].clone();
+    }
+
+    let one_idx = base + CONST_PROOF_SIZE_LOG_N - 1;
+    coms[one_idx] = g1_generator(env);
+    scalars[one_idx] = const_acc;
+    coms[one_idx + 1] = proof.kzg_quotient.clone();
+    scalars[one_idx + 1] = tp.shplonk_z;
+
+    let p0 = g1_msm(env, &coms, &scalars);
+    let p1 = -proof.kzg_quotient.clone();
+    if pairing_check(env, &p0, &p1) {
+        Ok(())
+    } else {
+        Err("shplonk pairing check failed")
+    }
+}
@@ -0,0 +1,80 @@
+use crate::field::{batch_inverse, Fr};
+use crate::relations::accumulate_relation_evaluations;
+use crate::types::{Proof, Transcript, BATCHED_RELATION_PARTIAL_LENGTH};
+
+/// Barycentric weights `1 / prod_{j != i} (i - j)` over the domain `0..8`,
+/// i.e. -5040, 720, -240, 144, -144, 240, -720, 5040 reduced mod r.
+fn barycentric_weights() -> [Fr; BATCHED_RELATION_PARTIAL_LENGTH] {
+    const WEIGHTS: [(bool, u64); BATCHED_RELATION_PARTIAL_LENGTH] = [
+        (true, 5040),
+        (false, 720),
+        (true, 240),
+        (false, 144),
+        (true, 144),
+        (false, 240),
+        (true, 720),
+        (false, 5040),
+    ];
+    WEIGHTS.map(|(negative, w)| {
+        let w = Fr::from_u64(w);
+        if negative {
+            -w
+        } else {
+            w
+        }
+    })
+}
+
+/// Evaluates the round univariate (given by its values on `0..8`) at `challenge`.
+fn compute_next_target_sum(
+    univariate: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+    challenge: Fr,
+    weights: &[Fr; BATCHED_RELATION_PARTIAL_LENGTH],
+) -> Result<Fr, &'static str> {
+    let mut denominators = [Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH];
+    let mut numerator = Fr::one();
+    for (i, (w, den)) in weights.iter().zip(denominators.iter_mut()).enumerate() {
+        let diff = challenge - Fr::from_u64(i as u64);
+        if diff.is_zero() {
+            return Ok(univariate[i]);
+        }
+        numerator = numerator * diff;
+        *den = *w * diff;
+    }
+    let mut inverses = [Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH];
+    batch_inverse(&denominators, &mut inverses)?;
+
+    let mut acc = Fr::zero();
+    for (u, inv) in univariate.iter().zip(inverses.iter()) {
+        acc = acc + *u * *inv;
+    }
+    Ok(numerator * acc)
+}
+
+pub fn verify_sumcheck(proof: &Proof, tp: &Transcript, log_n: usize) -> Result<(), &'static str> {
+    let weights = barycentric_weights();
+    let mut round_target = Fr::zero();
+    let mut pow_partial_evaluation = Fr::one();
+
+    for round in 0..log_n {
+        let univariate = &proof.sumcheck_univariates[round];
+        if univariate[0] + univariate[1] != round_target {
+            return Err("sumcheck round failed");
+        }
+        let challenge = tp.sumcheck_u_challenges[round];
+        round_target = compute_next_target_sum(univariate, challenge, &weights)?;
+        pow_partial_evaluation = pow_partial_evaluation
+            * (Fr::one() + challenge * (tp.gate_challenges[round] - Fr::one()));
+    }
+
+    let grand_relation_sum = accumulate_relation_evaluations(
+        &proof.sumcheck_evaluations,
+        &tp.rel_params,
+        &tp.alphas,
+        pow_partial_evaluation,
+    );
+    if grand_relation_sum != round_target {
+        return Err("sumcheck final relation mismatch");
+    }
+    Ok(())
+}
@@ -0,0 +1,396 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::field::Fr;
+use crate::relations::accumulate_relation_evaluations;
+use crate::types::{RelationParameters, NUMBER_OF_ALPHAS, NUMBER_OF_ENTITIES, PAIRING_POINTS_SIZE};
+use crate::verifier::UltraHonkVerifier;
+use crate::{Proof, VerificationKey, VerifierError, PROOF_BYTES, VK_BYTES};
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn setup() -> Env {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    env
+}
+
+fn fr_hex(hex: &str) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    out
+}
+
+/// A key whose commitments are all the point at infinity.
+fn empty_vk(env: &Env, log_n: u64, num_public_inputs: u64) -> Bytes {
+    let mut vk = [0u8; VK_BYTES];
+    vk[..8].copy_from_slice(&(1u64 << log_n).to_be_bytes());
+    vk[8..16].copy_from_slice(&log_n.to_be_bytes());
+    vk[16..24].copy_from_slice(&(num_public_inputs + PAIRING_POINTS_SIZE as u64).to_be_bytes());
+    vk[24..32].copy_from_slice(&1u64.to_be_bytes());
+    Bytes::from_array(env, &vk)
+}
+
+fn public_inputs(env: &Env, values: &[u64]) -> Vec<BytesN<32>> {
+    let mut out = Vec::new(env);
+    for v in values {
+        out.push_back(BytesN::from_array(env, &Fr::from_u64(*v).to_bytes()));
+    }
+    out
+}
+
+/// Byte offset of the first sumcheck univariate in a proof.
+const SUMCHECK_OFFSET: u32 = (PAIRING_POINTS_SIZE * 32 + 8 * 128) as u32;
+
+/// Per-transaction CPU instruction limit on mainnet.
+const TX_CPU_INSTRUCTIONS: u64 = 100_000_000;
+
+/// Verifies under the default (network-sized) budget and returns the CPU
+/// instructions spent. Natively run tests only meter host functions (keccak,
+/// G1 arithmetic, pairing); the in-contract field arithmetic is counted once
+/// the contract runs as wasm.
+fn verify_metered(env: &Env, proof: &Bytes, inputs: &Vec<BytesN<32>>, vk: &Bytes) -> (Result<(), VerifierError>, u64) {
+    env.cost_estimate().budget().reset_default();
+    let result = UltraHonkVerifier::verify(env, proof, inputs, vk);
+    (result, env.cost_estimate().budget().cpu_instruction_cost())
+}
+
+// ============================================================================
+// Field arithmetic
+// ============================================================================
+
+#[test]
+fn test_field_inverse() {
+    let x = Fr::from_u64(123_456_789);
+    assert_eq!(x * x.inverse().unwrap(), Fr::one());
+    assert!(Fr::zero().inverse().is_none());
+}
+
+#[test]
+fn test_field_reduces_modulus() {
+    let modulus = fr_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    assert_eq!(Fr::from_bytes(&modulus), Fr::zero());
+    assert!(Fr::from_canonical_bytes(&modulus).is_none());
+
+    let minus_one = fr_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000");
+    assert_eq!(Fr::from_bytes(&minus_one), -Fr::one());
+    assert_eq!(Fr::from_bytes(&minus_one).to_bytes(), minus_one);
+}
+
+#[test]
+fn test_field_neg_half() {
+    let neg_half = fr_hex("183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000000");
+    assert_eq!(Fr::from_bytes(&neg_half) * Fr::from_u64(2), -Fr::one());
+}
+
+// ============================================================================
+// Relations
+// ============================================================================
+
+#[test]
+fn test_relations_regression_vector() {
+    let mut evaluations = [Fr::zero(); NUMBER_OF_ENTITIES];
+    for (i, e) in evaluations.iter_mut().enumerate() {
+        *e = Fr::from_u64(i as u64);
+    }
+    let rp = RelationParameters {
+        eta: Fr::from_u64(100),
+        eta_two: Fr::from_u64(101),
+        eta_three: Fr::from_u64(102),
+        beta: Fr::from_u64(103),
+        gamma: Fr::from_u64(104),
+        public_inputs_delta: Fr::from_u64(105),
+    };
+    let mut alphas = [Fr::zero(); NUMBER_OF_ALPHAS];
+    for (i, a) in alphas.iter_mut().enumerate() {
+        *a = Fr::from_u64(200 + i as u64);
+    }
+
+    let result = accumulate_relation_evaluations(&evaluations, &rp, &alphas, Fr::from_u64(300));
+    assert_eq!(
+        result.to_bytes(),
+        fr_hex("1ec606befa857100f90267ac1dc687413b93e8586fdbb4682dfda24b864515aa")
+    );
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+fn set_header(vk: &mut Bytes, word: u32, value: u64) {
+    vk.copy_from_slice(word * 8, &value.to_be_bytes());
+}
+
+#[test]
+fn test_vk_from_bytes() {
+    let env = setup();
+    let vk = VerificationKey::from_bytes(&env, &empty_vk(&env, 12, 7)).unwrap();
+    assert_eq!(vk.circuit_size, 4096);
+    assert_eq!(vk.log_circuit_size, 12);
+    assert_eq!(vk.num_public_inputs(), 7);
+}
+
+#[test]
+fn test_vk_rejects_bad_header() {
+    let env = setup();
+    let short = Bytes::from_array(&env, &[0u8; 64]);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &short).err(),
+        Some(VerifierError::InvalidVkLength)
+    );
+
+    let mut vk = empty_vk(&env, 12, 7);
+    set_header(&mut vk, 0, 4095);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::InvalidCircuitSize)
+    );
+
+    let mut vk = empty_vk(&env, 29, 7);
+    set_header(&mut vk, 0, 1 << 29);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::InvalidCircuitSize)
+    );
+
+    let mut vk = empty_vk(&env, 12, 7);
+    set_header(&mut vk, 2, 15);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::InvalidPublicInputLayout)
+    );
+
+    let mut vk = empty_vk(&env, 12, 7);
+    set_header(&mut vk, 3, 4090);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::InvalidPublicInputLayout)
+    );
+}
+
+#[test]
+fn test_vk_rejects_point_off_curve() {
+    let env = setup();
+    let mut vk = empty_vk(&env, 12, 7);
+    // First commitment := (1, 1).
+    vk.set(32 + 31, 1);
+    vk.set(32 + 63, 1);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::PointNotOnCurve)
+    );
+
+    // (1, 2) is the generator.
+    vk.set(32 + 63, 2);
+    assert!(VerificationKey::from_bytes(&env, &vk).is_ok());
+
+    // x = q is not a canonical coordinate.
+    let q = fr_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+    vk.copy_from_slice(32, &q);
+    assert_eq!(
+        VerificationKey::from_bytes(&env, &vk).err(),
+        Some(VerifierError::InvalidPointEncoding)
+    );
+}
+
+#[test]
+fn test_proof_rejects_bad_encoding() {
+    let env = setup();
+    let zero = [0u8; PROOF_BYTES];
+
+    let short = Bytes::from_array(&env, &[0u8; 2144]);
+    assert_eq!(
+        Proof::from_bytes(&env, &short).err(),
+        Some(VerifierError::InvalidProofLength)
+    );
+
+    // First pairing point element := r.
+    let mut proof = Bytes::from_array(&env, &zero);
+    let r = fr_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    proof.copy_from_slice(0, &r);
+    assert_eq!(
+        Proof::from_bytes(&env, &proof).err(),
+        Some(VerifierError::InvalidScalarEncoding)
+    );
+
+    // w1.x_lo with bits above 136.
+    let mut proof = Bytes::from_array(&env, &zero);
+    proof.set((PAIRING_POINTS_SIZE * 32) as u32, 1);
+    assert_eq!(
+        Proof::from_bytes(&env, &proof).err(),
+        Some(VerifierError::InvalidPointEncoding)
+    );
+
+    // w1 := (1, 1).
+    let mut proof = Bytes::from_array(&env, &zero);
+    let w1 = (PAIRING_POINTS_SIZE * 32) as u32;
+    proof.set(w1 + 31, 1);
+    proof.set(w1 + 64 + 31, 1);
+    assert_eq!(
+        Proof::from_bytes(&env, &proof).err(),
+        Some(VerifierError::PointNotOnCurve)
+    );
+}
+
+// ============================================================================
+// End-to-end
+// ============================================================================
+
+// With every commitment at infinity and every evaluation zero, each check in
+// the protocol holds trivially. This exercises the full transcript, sumcheck
+// and pairing path without a real prover.
+
+#[test]
+fn test_trivial_proof_verifies() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let proof = Bytes::from_array(&env, &[0u8; PROOF_BYTES]);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(UltraHonkVerifier::verify(&env, &proof, &inputs, &vk), Ok(()));
+}
+
+#[test]
+fn test_trivial_proof_fits_budget() {
+    let env = Env::default();
+    let vk = empty_vk(&env, 12, 7);
+    let proof = Bytes::from_array(&env, &[0u8; PROOF_BYTES]);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    let (result, cpu) = verify_metered(&env, &proof, &inputs, &vk);
+    assert_eq!(result, Ok(()));
+    assert!(cpu < TX_CPU_INSTRUCTIONS, "verification used {cpu} instructions");
+}
+
+#[test]
+fn test_rejects_tampered_sumcheck() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let mut proof = [0u8; PROOF_BYTES];
+    proof[SUMCHECK_OFFSET as usize + 31] = 1;
+    let proof = Bytes::from_array(&env, &proof);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        UltraHonkVerifier::verify(&env, &proof, &inputs, &vk),
+        Err(VerifierError::SumcheckFailed)
+    );
+}
+
+#[test]
+fn test_rejects_failed_pairing() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let mut proof = [0u8; PROOF_BYTES];
+    // kzg_quotient := G1 generator (1, 2), last point in the proof.
+    let kzg = PROOF_BYTES - 128;
+    proof[kzg + 31] = 1;
+    proof[kzg + 64 + 31] = 2;
+    let proof = Bytes::from_array(&env, &proof);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        UltraHonkVerifier::verify(&env, &proof, &inputs, &vk),
+        Err(VerifierError::PairingCheckFailed)
+    );
+}
+
+#[test]
+fn test_rejects_nonzero_gemini_padding() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let mut proof = [0u8; PROOF_BYTES];
+    // Last gemini evaluation sits just before shplonk_q and kzg_quotient.
+    proof[PROOF_BYTES - 256 - 1] = 1;
+    let proof = Bytes::from_array(&env, &proof);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        UltraHonkVerifier::verify(&env, &proof, &inputs, &vk),
+        Err(VerifierError::InvalidGeminiPadding)
+    );
+}
+
+#[test]
+fn test_rejects_wrong_public_input_count() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let proof = Bytes::from_array(&env, &[0u8; PROOF_BYTES]);
+    let inputs = public_inputs(&env, &[1, 2, 3]);
+    assert_eq!(
+        UltraHonkVerifier::verify(&env, &proof, &inputs, &vk),
+        Err(VerifierError::PublicInputCountMismatch)
+    );
+}
+
+#[test]
+fn test_rejects_wrong_proof_length() {
+    let env = setup();
+    let vk = empty_vk(&env, 12, 7);
+    let proof = Bytes::from_array(&env, &[0u8; 2144]);
+    let inputs = public_inputs(&env, &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(
+        UltraHonkVerifier::verify(&env, &proof, &inputs, &vk),
+        Err(VerifierError::InvalidProofLength)
+    );
+}
+
+// ============================================================================
+// zk-porrinha fixtures
+// ============================================================================
+
+fn read_fixture(name: &str) -> std::vec::Vec<u8> {
+    let path = std::format!(
+        "{}/fixtures/zk_porrinha/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    std::fs::read(&path).unwrap_or_else(|e| panic!("missing fixture {path}: {e}"))
+}
+
+fn fixture_inputs(env: &Env) -> (Bytes, Vec<BytesN<32>>, Bytes) {
+    let proof = Bytes::from_slice(env, &read_fixture("proof"));
+    let vk = Bytes::from_slice(env, &read_fixture("vk"));
+    let mut inputs = Vec::new(env);
+    for chunk in read_fixture("public_inputs").chunks(32) {
+        inputs.push_back(BytesN::from_array(env, chunk.try_into().unwrap()));
+    }
+    (proof, inputs, vk)
+}
+
+#[test]
+#[ignore = "requires fixtures generated with nargo 1.0.0-beta.9 / bb 0.87.0, see fixtures/README.md"]
+fn test_zk_porrinha_fixture_verifies() {
+    let env = Env::default();
+    let (proof, inputs, vk) = fixture_inputs(&env);
+    let (result, cpu) = verify_metered(&env, &proof, &inputs, &vk);
+    assert_eq!(result, Ok(()));
+    assert!(cpu < TX_CPU_INSTRUCTIONS, "verification used {cpu} instructions");
+}
+
+#[test]
+#[ignore = "requires fixtures generated with nargo 1.0.0-beta.9 / bb 0.87.0, see fixtures/README.md"]
+fn test_zk_porrinha_fixture_rejects_modified_proof() {
+    let env = setup();
+    let (proof, inputs, vk) = fixture_inputs(&env);
+    let mut modified = proof.clone();
+    let at = SUMCHECK_OFFSET + 31;
+    modified.set(at, proof.get(at).unwrap() ^ 1);
+    let err = UltraHonkVerifier::verify(&env, &modified, &inputs, &vk).unwrap_err();
+    assert!(!err.is_malformed());
+}
+
+#[test]
+#[ignore = "requires fixtures generated with nargo 1.0.0-beta.9 / bb 0.87.0, see fixtures/README.md"]
+fn test_zk_porrinha_fixture_rejects_wrong_total() {
+    let env = setup();
+    let (proof, mut inputs, vk) = fixture_inputs(&env);
+    let last = inputs.len() - 1;
+    let total = Fr::from_bytes(&inputs.get(last).unwrap().to_array());
+    inputs.set(
+        last,
+        BytesN::from_array(&env, &(total + Fr::one()).to_bytes()),
+    );
+    let err = UltraHonkVerifier::verify(&env, &proof, &inputs, &vk).unwrap_err();
+    assert!(!err.is_malformed());
+}
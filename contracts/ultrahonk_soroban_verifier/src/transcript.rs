@@ -0,0 +1,171 @@
+//! Keccak Fiat-Shamir transcript, matching Barretenberg's `KeccakTranscript`.
+
+use crate::field::Fr;
+use crate::types::{
+    Proof, RelationParameters, Transcript, CONST_PROOF_SIZE_LOG_N, NUMBER_OF_ALPHAS,
+};
+use soroban_sdk::{crypto::bn254::Bn254G1Affine, Bytes, Env};
+
+fn hash_to_fr(data: &Bytes) -> Fr {
+    let hash = data.env().crypto().keccak256(data).to_array();
+    Fr::from_bytes(&hash)
+}
+
+/// Splits a challenge into its low 128 bits and high 126 bits.
+fn split_challenge(challenge: &Fr) -> (Fr, Fr) {
+    let bytes = challenge.to_bytes();
+    let mut lo = [0u8; 32];
+    lo[16..].copy_from_slice(&bytes[16..]);
+    let mut hi = [0u8; 32];
+    hi[16..].copy_from_slice(&bytes[..16]);
+    (Fr::from_bytes(&lo), Fr::from_bytes(&hi))
+}
+
+fn u64_to_be32(x: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[24..].copy_from_slice(&x.to_be_bytes());
+    out
+}
+
+/// Absorbs a point the way the prover serialized it: each coordinate as a
+/// 136-bit low limb followed by a 118-bit high limb.
+fn push_point(buf: &mut Bytes, pt: &Bn254G1Affine) {
+    let xy = pt.to_array();
+    for coord in [&xy[..32], &xy[32..]] {
+        let mut lo = [0u8; 32];
+        lo[15..].copy_from_slice(&coord[15..]);
+        buf.extend_from_slice(&lo);
+        let mut hi = [0u8; 32];
+        hi[17..].copy_from_slice(&coord[..15]);
+        buf.extend_from_slice(&hi);
+    }
+}
+
+/// Starts a round buffer seeded with the previous challenge.
+fn round(env: &Env, previous: &Fr) -> Bytes {
+    Bytes::from_array(env, &previous.to_bytes())
+}
+
+pub fn generate_transcript(
+    env: &Env,
+    proof: &Proof,
+    public_inputs: &Bytes,
+    circuit_size: u64,
+    public_inputs_size: u64,
+    pub_inputs_offset: u64,
+) -> Transcript {
+    // eta, eta_two, eta_three
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(&u64_to_be32(circuit_size));
+    data.extend_from_slice(&u64_to_be32(public_inputs_size));
+    data.extend_from_slice(&u64_to_be32(pub_inputs_offset));
+    data.append(public_inputs);
+    for fr in proof.pairing_point_object.iter() {
+        data.extend_from_slice(&fr.to_bytes());
+    }
+    for w in [&proof.w1, &proof.w2, &proof.w3] {
+        push_point(&mut data, w);
+    }
+    let mut previous = hash_to_fr(&data);
+    let (eta, eta_two) = split_challenge(&previous);
+    previous = hash_to_fr(&round(env, &previous));
+    let (eta_three, _) = split_challenge(&previous);
+
+    // beta, gamma
+    let mut data = round(env, &previous);
+    for w in [
+        &proof.lookup_read_counts,
+        &proof.lookup_read_tags,
+        &proof.w4,
+    ] {
+        push_point(&mut data, w);
+    }
+    previous = hash_to_fr(&data);
+    let (beta, gamma) = split_challenge(&previous);
+
+    // alphas
+    let mut data = round(env, &previous);
+    push_point(&mut data, &proof.lookup_inverses);
+    push_point(&mut data, &proof.z_perm);
+    previous = hash_to_fr(&data);
+    let mut alphas = [Fr::zero(); NUMBER_OF_ALPHAS];
+    (alphas[0], alphas[1]) = split_challenge(&previous);
+    for i in 1..(NUMBER_OF_ALPHAS / 2) {
+        previous = hash_to_fr(&round(env, &previous));
+        (alphas[2 * i], alphas[2 * i + 1]) = split_challenge(&previous);
+    }
+    if NUMBER_OF_ALPHAS % 2 == 1 {
+        previous = hash_to_fr(&round(env, &previous));
+        alphas[NUMBER_OF_ALPHAS - 1] = split_challenge(&previous).0;
+    }
+
+    // gate challenges
+    let mut gate_challenges = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
+    for challenge in gate_challenges.iter_mut() {
+        previous = hash_to_fr(&round(env, &previous));
+        *challenge = split_challenge(&previous).0;
+    }
+
+    // sumcheck challenges
+    let mut sumcheck_u_challenges = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
+    for (univariate, challenge) in proof
+        .sumcheck_univariates
+        .iter()
+        .zip(sumcheck_u_challenges.iter_mut())
+    {
+        let mut data = round(env, &previous);
+        for c in univariate.iter() {
+            data.extend_from_slice(&c.to_bytes());
+        }
+        previous = hash_to_fr(&data);
+        *challenge = split_challenge(&previous).0;
+    }
+
+    // rho
+    let mut data = round(env, &previous);
+    for e in proof.sumcheck_evaluations.iter() {
+        data.extend_from_slice(&e.to_bytes());
+    }
+    previous = hash_to_fr(&data);
+    let rho = split_challenge(&previous).0;
+
+    // gemini r
+    let mut data = round(env, &previous);
+    for pt in proof.gemini_fold_comms.iter() {
+        push_point(&mut data, pt);
+    }
+    previous = hash_to_fr(&data);
+    let gemini_r = split_challenge(&previous).0;
+
+    // shplonk nu
+    let mut data = round(env, &previous);
+    for a in proof.gemini_a_evaluations.iter() {
+        data.extend_from_slice(&a.to_bytes());
+    }
+    previous = hash_to_fr(&data);
+    let shplonk_nu = split_challenge(&previous).0;
+
+    // shplonk z
+    let mut data = round(env, &previous);
+    push_point(&mut data, &proof.shplonk_q);
+    previous = hash_to_fr(&data);
+    let shplonk_z = split_challenge(&previous).0;
+
+    Transcript {
+        rel_params: RelationParameters {
+            eta,
+            eta_two,
+            eta_three,
+            beta,
+            gamma,
+            public_inputs_delta: Fr::zero(),
+        },
+        alphas,
+        gate_challenges,
+        sumcheck_u_challenges,
+        rho,
+        gemini_r,
+        shplonk_nu,
+        shplonk_z,
+    }
+}
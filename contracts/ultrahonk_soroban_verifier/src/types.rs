@@ -0,0 +1,354 @@
+use crate::ec::g1_from_xy;
+use crate::error::VerifierError;
+use crate::field::Fr;
+use soroban_sdk::{crypto::bn254::Bn254G1Affine, Bytes, Env};
+
+pub const CONST_PROOF_SIZE_LOG_N: usize = 28;
+pub const NUMBER_OF_SUBRELATIONS: usize = 26;
+pub const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
+pub const NUMBER_OF_ENTITIES: usize = 40;
+pub const NUMBER_UNSHIFTED: usize = 35;
+pub const PAIRING_POINTS_SIZE: usize = 16;
+pub const NUMBER_OF_ALPHAS: usize = NUMBER_OF_SUBRELATIONS - 1;
+
+/// Number of 32-byte fields in an UltraKeccak proof.
+pub const PROOF_FIELDS: usize = PAIRING_POINTS_SIZE
+    + 8 * 4
+    + CONST_PROOF_SIZE_LOG_N * BATCHED_RELATION_PARTIAL_LENGTH
+    + NUMBER_OF_ENTITIES
+    + (CONST_PROOF_SIZE_LOG_N - 1) * 4
+    + CONST_PROOF_SIZE_LOG_N
+    + 2 * 4;
+pub const PROOF_BYTES: usize = PROOF_FIELDS * 32;
+
+/// Four big-endian `u64` header words followed by 27 uncompressed G1 points.
+pub const VK_BYTES: usize = 4 * 8 + 27 * 64;
+
+/// Index of each entity in the sumcheck evaluation array.
+#[derive(Copy, Clone, Debug)]
+pub enum Wire {
+    Qm = 0,
+    Qc = 1,
+    Ql = 2,
+    Qr = 3,
+    Qo = 4,
+    Q4 = 5,
+    QLookup = 6,
+    QArith = 7,
+    QRange = 8,
+    QElliptic = 9,
+    QAux = 10,
+    QPoseidon2External = 11,
+    QPoseidon2Internal = 12,
+    Sigma1 = 13,
+    Sigma2 = 14,
+    Sigma3 = 15,
+    Sigma4 = 16,
+    Id1 = 17,
+    Id2 = 18,
+    Id3 = 19,
+    Id4 = 20,
+    Table1 = 21,
+    Table2 = 22,
+    Table3 = 23,
+    Table4 = 24,
+    LagrangeFirst = 25,
+    LagrangeLast = 26,
+    Wl = 27,
+    Wr = 28,
+    Wo = 29,
+    W4 = 30,
+    ZPerm = 31,
+    LookupInverses = 32,
+    LookupReadCounts = 33,
+    LookupReadTags = 34,
+    WlShift = 35,
+    WrShift = 36,
+    WoShift = 37,
+    W4Shift = 38,
+    ZPermShift = 39,
+}
+
+/// Parsed UltraKeccak verification key. Build with [`VerificationKey::from_bytes`].
+#[derive(Clone)]
+pub struct VerificationKey {
+    pub circuit_size: u64,
+    pub log_circuit_size: u64,
+    pub public_inputs_size: u64,
+    pub pub_inputs_offset: u64,
+    pub(crate) qm: Bn254G1Affine,
+    pub(crate) qc: Bn254G1Affine,
+    pub(crate) ql: Bn254G1Affine,
+    pub(crate) qr: Bn254G1Affine,
+    pub(crate) qo: Bn254G1Affine,
+    pub(crate) q4: Bn254G1Affine,
+    pub(crate) q_lookup: Bn254G1Affine,
+    pub(crate) q_arith: Bn254G1Affine,
+    pub(crate) q_delta_range: Bn254G1Affine,
+    pub(crate) q_elliptic: Bn254G1Affine,
+    pub(crate) q_aux: Bn254G1Affine,
+    pub(crate) q_poseidon2_external: Bn254G1Affine,
+    pub(crate) q_poseidon2_internal: Bn254G1Affine,
+    pub(crate) s1: Bn254G1Affine,
+    pub(crate) s2: Bn254G1Affine,
+    pub(crate) s3: Bn254G1Affine,
+    pub(crate) s4: Bn254G1Affine,
+    pub(crate) id1: Bn254G1Affine,
+    pub(crate) id2: Bn254G1Affine,
+    pub(crate) id3: Bn254G1Affine,
+    pub(crate) id4: Bn254G1Affine,
+    pub(crate) t1: Bn254G1Affine,
+    pub(crate) t2: Bn254G1Affine,
+    pub(crate) t3: Bn254G1Affine,
+    pub(crate) t4: Bn254G1Affine,
+    pub(crate) lagrange_first: Bn254G1Affine,
+    pub(crate) lagrange_last: Bn254G1Affine,
+}
+
+/// Parsed UltraKeccak proof. Build with [`Proof::from_bytes`].
+#[derive(Clone)]
+pub struct Proof {
+    pub(crate) pairing_point_object: [Fr; PAIRING_POINTS_SIZE],
+    pub(crate) w1: Bn254G1Affine,
+    pub(crate) w2: Bn254G1Affine,
+    pub(crate) w3: Bn254G1Affine,
+    pub(crate) w4: Bn254G1Affine,
+    pub(crate) lookup_read_counts: Bn254G1Affine,
+    pub(crate) lookup_read_tags: Bn254G1Affine,
+    pub(crate) lookup_inverses: Bn254G1Affine,
+    pub(crate) z_perm: Bn254G1Affine,
+    pub(crate) sumcheck_univariates: [[Fr; BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N],
+    pub(crate) sumcheck_evaluations: [Fr; NUMBER_OF_ENTITIES],
+    pub(crate) gemini_fold_comms: [Bn254G1Affine; CONST_PROOF_SIZE_LOG_N - 1],
+    pub(crate) gemini_a_evaluations: [Fr; CONST_PROOF_SIZE_LOG_N],
+    pub(crate) shplonk_q: Bn254G1Affine,
+    pub(crate) kzg_quotient: Bn254G1Affine,
+}
+
+#[derive(Clone)]
+pub struct RelationParameters {
+    pub eta: Fr,
+    pub eta_two: Fr,
+    pub eta_three: Fr,
+    pub beta: Fr,
+    pub gamma: Fr,
+    pub public_inputs_delta: Fr,
+}
+
+#[derive(Clone)]
+pub struct Transcript {
+    pub rel_params: RelationParameters,
+    pub alphas: [Fr; NUMBER_OF_ALPHAS],
+    pub gate_challenges: [Fr; CONST_PROOF_SIZE_LOG_N],
+    pub sumcheck_u_challenges: [Fr; CONST_PROOF_SIZE_LOG_N],
+    pub rho: Fr,
+    pub gemini_r: Fr,
+    pub shplonk_nu: Fr,
+    pub shplonk_z: Fr,
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Sequential reader over a byte buffer whose length was already checked.
+struct Reader<'a> {
+    bytes: &'a Bytes,
+    pos: u32,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a Bytes) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn read<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        let end = self.pos + N as u32;
+        self.bytes.slice(self.pos..end).copy_into_slice(&mut out);
+        self.pos = end;
+        out
+    }
+
+    fn read_u64(&mut self) -> u64 {
+        u64::from_be_bytes(self.read::<8>())
+    }
+
+    fn read_fr(&mut self) -> Result<Fr, VerifierError> {
+        Fr::from_canonical_bytes(&self.read::<32>()).ok_or(VerifierError::InvalidScalarEncoding)
+    }
+
+    /// Reads a 64-byte `x || y` point, as stored in the verification key.
+    fn read_g1(&mut self, env: &Env) -> Result<Bn254G1Affine, VerifierError> {
+        let xy = self.read::<64>();
+        g1_from_xy(env, xy[..32].try_into().unwrap(), xy[32..].try_into().unwrap())
+    }
+
+    /// Reads a proof point, stored as four 32-byte limbs `x_lo, x_hi, y_lo, y_hi`
+    /// where `lo` holds the low 136 bits and `hi` the remaining 118 bits.
+    fn read_g1_limbs(&mut self, env: &Env) -> Result<Bn254G1Affine, VerifierError> {
+        let x = combine_limbs(&self.read::<32>(), &self.read::<32>())?;
+        let y = combine_limbs(&self.read::<32>(), &self.read::<32>())?;
+        g1_from_xy(env, &x, &y)
+    }
+}
+
+fn combine_limbs(lo: &[u8; 32], hi: &[u8; 32]) -> Result<[u8; 32], VerifierError> {
+    if lo[..15].iter().chain(hi[..17].iter()).any(|b| *b != 0) || hi[17] >= 0x40 {
+        return Err(VerifierError::InvalidPointEncoding);
+    }
+    let mut out = [0u8; 32];
+    out[..15].copy_from_slice(&hi[17..]);
+    out[15..].copy_from_slice(&lo[15..]);
+    Ok(out)
+}
+
+impl VerificationKey {
+    /// Parses a binary verification key as written by `bb write_vk`.
+    ///
+    /// The header must describe a power-of-two circuit of at most
+    /// `2^CONST_PROOF_SIZE_LOG_N` rows whose public inputs, pairing points
+    /// included, fit in the trace after `pub_inputs_offset`. Every commitment
+    /// must be a canonical point on the curve.
+    pub fn from_bytes(env: &Env, bytes: &Bytes) -> Result<Self, VerifierError> {
+        if bytes.len() as usize != VK_BYTES {
+            return Err(VerifierError::InvalidVkLength);
+        }
+        let mut r = Reader::new(bytes);
+        let circuit_size = r.read_u64();
+        let log_circuit_size = r.read_u64();
+        let public_inputs_size = r.read_u64();
+        let pub_inputs_offset = r.read_u64();
+
+        if log_circuit_size == 0
+            || log_circuit_size > CONST_PROOF_SIZE_LOG_N as u64
+            || circuit_size != 1 << log_circuit_size
+        {
+            return Err(VerifierError::InvalidCircuitSize);
+        }
+        if public_inputs_size < PAIRING_POINTS_SIZE as u64
+            || pub_inputs_offset
+                .checked_add(public_inputs_size)
+                .is_none_or(|end| end > circuit_size)
+        {
+            return Err(VerifierError::InvalidPublicInputLayout);
+        }
+
+        let mut pts: [Option<Bn254G1Affine>; 27] = Default::default();
+        for slot in pts.iter_mut() {
+            *slot = Some(r.read_g1(env)?);
+        }
+        let [qm, qc, ql, qr, qo, q4, q_lookup, q_arith, q_delta_range, q_elliptic, q_aux, q_poseidon2_external, q_poseidon2_internal, s1, s2, s3, s4, id1, id2, id3, id4, t1, t2, t3, t4, lagrange_first, lagrange_last] =
+            pts.map(Option::unwrap);
+        Ok(Self {
+            circuit_size,
+            log_circuit_size,
+            public_inputs_size,
+            pub_inputs_offset,
+            qm,
+            qc,
+            ql,
+            qr,
+            qo,
+            q4,
+            q_lookup,
+            q_arith,
+            q_delta_range,
+            q_elliptic,
+            q_aux,
+            q_poseidon2_external,
+            q_poseidon2_internal,
+            s1,
+            s2,
+            s3,
+            s4,
+            id1,
+            id2,
+            id3,
+            id4,
+            t1,
+            t2,
+            t3,
+            t4,
+            lagrange_first,
+            lagrange_last,
+        })
+    }
+
+    /// Number of circuit public inputs, excluding the pairing point object.
+    pub fn num_public_inputs(&self) -> u32 {
+        (self.public_inputs_size - PAIRING_POINTS_SIZE as u64) as u32
+    }
+}
+
+impl Proof {
+    /// Parses a proof as written by `bb prove --oracle_hash keccak`.
+    ///
+    /// Rejects proofs of the wrong length, non-canonical scalars or limbs,
+    /// and points that are not on the curve.
+    pub fn from_bytes(env: &Env, bytes: &Bytes) -> Result<Self, VerifierError> {
+        if bytes.len() as usize != PROOF_BYTES {
+            return Err(VerifierError::InvalidProofLength);
+        }
+        let mut r = Reader::new(bytes);
+
+        let mut pairing_point_object = [Fr::zero(); PAIRING_POINTS_SIZE];
+        for slot in pairing_point_object.iter_mut() {
+            *slot = r.read_fr()?;
+        }
+
+        let w1 = r.read_g1_limbs(env)?;
+        let w2 = r.read_g1_limbs(env)?;
+        let w3 = r.read_g1_limbs(env)?;
+        let lookup_read_counts = r.read_g1_limbs(env)?;
+        let lookup_read_tags = r.read_g1_limbs(env)?;
+        let w4 = r.read_g1_limbs(env)?;
+        let lookup_inverses = r.read_g1_limbs(env)?;
+        let z_perm = r.read_g1_limbs(env)?;
+
+        let mut sumcheck_univariates =
+            [[Fr::zero(); BATCHED_RELATION_PARTIAL_LENGTH]; CONST_PROOF_SIZE_LOG_N];
+        for row in sumcheck_univariates.iter_mut() {
+            for cell in row.iter_mut() {
+                *cell = r.read_fr()?;
+            }
+        }
+
+        let mut sumcheck_evaluations = [Fr::zero(); NUMBER_OF_ENTITIES];
+        for slot in sumcheck_evaluations.iter_mut() {
+            *slot = r.read_fr()?;
+        }
+
+        let mut gemini_fold_comms: [Option<Bn254G1Affine>; CONST_PROOF_SIZE_LOG_N - 1] =
+            Default::default();
+        for slot in gemini_fold_comms.iter_mut() {
+            *slot = Some(r.read_g1_limbs(env)?);
+        }
+
+        let mut gemini_a_evaluations = [Fr::zero(); CONST_PROOF_SIZE_LOG_N];
+        for slot in gemini_a_evaluations.iter_mut() {
+            *slot = r.read_fr()?;
+        }
+
+        let shplonk_q = r.read_g1_limbs(env)?;
+        let kzg_quotient = r.read_g1_limbs(env)?;
+
+        Ok(Self {
+            pairing_point_object,
+            w1,
+            w2,
+            w3,
+            w4,
+            lookup_read_counts,
+            lookup_read_tags,
+            lookup_inverses,
+            z_perm,
+            sumcheck_univariates,
+            sumcheck_evaluations,
+            gemini_fold_comms: gemini_fold_comms.map(Option::unwrap),
+            gemini_a_evaluations,
+            shplonk_q,
+            kzg_quotient,
+        })
+    }
+}
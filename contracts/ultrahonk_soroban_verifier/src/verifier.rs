@@ -0,0 +1,105 @@
+use crate::error::VerifierError;
+use crate::field::Fr;
+use crate::shplemini::verify_shplemini;
+use crate::sumcheck::verify_sumcheck;
+use crate::transcript::generate_transcript;
+use crate::types::{Proof, VerificationKey};
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+pub struct UltraHonkVerifier;
+
+impl UltraHonkVerifier {
+    /// Verifies an UltraHonk (keccak oracle) proof against a binary
+    /// verification key as written by `bb write_vk`.
+    ///
+    /// `public_inputs` are the circuit's public inputs in order, each a
+    /// canonical big-endian field element, without the pairing point object.
+    pub fn verify(
+        env: &Env,
+        proof: &Bytes,
+        public_inputs: &Vec<BytesN<32>>,
+        vk_bytes: &Bytes,
+    ) -> Result<(), VerifierError> {
+        let vk = VerificationKey::from_bytes(env, vk_bytes)?;
+        let proof = Proof::from_bytes(env, proof)?;
+        Self::verify_parsed(env, &proof, public_inputs, &vk)
+    }
+
+    /// Same as [`UltraHonkVerifier::verify`] for an already parsed key and proof.
+    pub fn verify_parsed(
+        env: &Env,
+        proof: &Proof,
+        public_inputs: &Vec<BytesN<32>>,
+        vk: &VerificationKey,
+    ) -> Result<(), VerifierError> {
+        let log_n = vk.log_circuit_size as usize;
+
+        // Gemini evaluations beyond log_n are padding and must be zero.
+        if proof.gemini_a_evaluations[log_n..]
+            .iter()
+            .any(|e| !e.is_zero())
+        {
+            return Err(VerifierError::InvalidGeminiPadding);
+        }
+
+        if public_inputs.len() != vk.num_public_inputs() {
+            return Err(VerifierError::PublicInputCountMismatch);
+        }
+        let mut pi_bytes = Bytes::new(env);
+        for pi in public_inputs.iter() {
+            let arr = pi.to_array();
+            Fr::from_canonical_bytes(&arr).ok_or(VerifierError::InvalidPublicInput)?;
+            pi_bytes.extend_from_slice(&arr);
+        }
+
+        let mut tp = generate_transcript(
+            env,
+            proof,
+            &pi_bytes,
+            vk.circuit_size,
+            vk.public_inputs_size,
+            vk.pub_inputs_offset,
+        );
+        tp.rel_params.public_inputs_delta = public_input_delta(
+            public_inputs,
+            &proof.pairing_point_object,
+            tp.rel_params.beta,
+            tp.rel_params.gamma,
+            vk.pub_inputs_offset,
+            vk.circuit_size,
+        )
+        .ok_or(VerifierError::SumcheckFailed)?;
+
+        verify_sumcheck(proof, &tp, log_n).map_err(|_| VerifierError::SumcheckFailed)?;
+        verify_shplemini(env, proof, vk, &tp, log_n).map_err(|_| VerifierError::PairingCheckFailed)
+    }
+}
+
+/// Public input contribution to the permutation grand product:
+/// `prod (gamma + x_i + beta * (n + offset + i)) / prod (gamma + x_i - beta * (offset + 1 + i))`,
+/// taken over the public inputs followed by the pairing point object.
+fn public_input_delta(
+    public_inputs: &Vec<BytesN<32>>,
+    pairing_point_object: &[Fr],
+    beta: Fr,
+    gamma: Fr,
+    offset: u64,
+    n: u64,
+) -> Option<Fr> {
+    let mut numerator = Fr::one();
+    let mut denominator = Fr::one();
+    let mut numerator_acc = gamma + beta * Fr::from_u64(n + offset);
+    let mut denominator_acc = gamma - beta * Fr::from_u64(offset + 1);
+
+    let inputs = public_inputs
+        .iter()
+        .map(|pi| Fr::from_bytes(&pi.to_array()))
+        .chain(pairing_point_object.iter().copied());
+    for x in inputs {
+        numerator = numerator * (numerator_acc + x);
+        denominator = denominator * (denominator_acc + x);
+        numerator_acc = numerator_acc + beta;
+        denominator_acc = denominator_acc - beta;
+    }
+    Some(numerator * denominator.inverse()?)
+}
@@ -1,50 +1,90 @@
 #![no_std]
 
+mod payout;
+mod poseidon2;
+
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, token,
-    Address, Bytes, BytesN, Env, Vec,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, token,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, Vec,
 };
+use game_hub_interface::{admin, GameAdmin, GameHubClient};
 
-const VK_HASH: [u8; 32] = [
-    0x16, 0xbf, 0x49, 0xee, 0x90, 0xd0, 0xff, 0x0b,
-    0x07, 0xb0, 0x7b, 0x49, 0x13, 0xac, 0x22, 0xd9,
-    0x29, 0xe7, 0xe1, 0x62, 0x6d, 0x21, 0x9e, 0x17,
-    0x8d, 0x37, 0x61, 0xa6, 0x15, 0x5b, 0xe8, 0xb7,
-];
-
+pub use game_hub_interface::GameOutcome;
 
+/// TTL of open rooms (persistent) and of nullifiers.
 pub const TTL_LEDGERS: u32 = 518_400;
 pub const INSTANCE_TTL: u32 = 518_400;
+/// How long a finished room stays readable after archival (~7 days).
+pub const ARCHIVE_TTL_LEDGERS: u32 = 120_960;
+
+/// Ledgers a lobby stays joinable (~1 day at 5s per ledger).
+pub const LOBBY_TIMEOUT_LEDGERS: u32 = 17_280;
+/// Ledgers every player has to commit once the room is full (~1 hour).
+pub const COMMIT_TIMEOUT_LEDGERS: u32 = 720;
+/// Ledgers to settle through `resolve` or `reveal` once all committed (~1 hour).
+pub const RESOLVE_TIMEOUT_LEDGERS: u32 = 720;
+/// Ledgers the other players always get to reveal after the first reveal
+/// of a round (~1 hour); a late first reveal extends the deadline.
+pub const REVEAL_TIMEOUT_LEDGERS: u32 = 720;
+
+
+/// Seat limits for a room. Two-seat rooms settle with the original circuit,
+/// larger ones with the `zk-porrinha-multi` circuit.
+pub const MIN_SEATS: u32 = 2;
+pub const MAX_SEATS: u32 = 6;
+/// Highest number of sticks a player may hold.
+pub const MAX_HAND: u32 = 3;
+/// Longest match a room can be created for (best of 9).
+pub const MAX_ROUNDS: u32 = 9;
+
+pub const BPS_DENOMINATOR: u32 = 10_000;
+/// Upper bound for the house fee (10%).
+pub const MAX_FEE_BPS: u32 = 1_000;
+/// Share of the post-fee pot paid to parity winners by default (80%).
+pub const DEFAULT_PARITY_BPS: u32 = 8_000;
+
+/// Most rooms a single `list_open_rooms` / `list_rooms_by_player` call returns.
+pub const MAX_PAGE: u32 = 50;
+/// Most indexed rooms a single `list_open_rooms` call reads, matching or
+/// not; with the index entries it keeps the call under the 100 ledger entry
+/// footprint limit.
+pub const MAX_SCAN: u32 = 80;
+/// Consecutive room ids sharing one room index entry.
+pub const ROOM_INDEX_BUCKET: u64 = 64;
+/// Most expired lobbies `create_room` drops from the open room index.
+pub const MAX_EXPIRED_PRUNE: u32 = 8;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
-    RoomNotFound     = 1,
-    NotPlayer        = 2,
-    InvalidPhase     = 3,
-    AlreadyCommitted = 4,
-    InvalidProof     = 5,
-    NullifierUsed    = 6,
-    InvalidBet       = 7,
-    XlmTokenNotSet   = 8,
-    VerifierNotSet   = 9,
-    GameHubNotSet    = 10,
-    SelfPlay         = 11,
-}
-
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
+    RoomNotFound       = 1,
+    NotPlayer          = 2,
+    InvalidPhase       = 3,
+    AlreadyCommitted   = 4,
+    InvalidProof       = 5,
+    NullifierUsed      = 6,
+    InvalidBet         = 7,
+    XlmTokenNotSet     = 8,
+    VerifierNotSet     = 9,
+    GameHubNotSet      = 10,
+    SelfPlay           = 11,
+    VkHashNotSet       = 12,
+    InvalidReveal      = 13,
+    AlreadyRevealed    = 14,
+    InvalidHand        = 15,
+    RoomExpired        = 16,
+    DeadlineNotReached = 17,
+    NothingToClaim     = 18,
+    InvalidSeats       = 19,
+    InvalidFeeTerms    = 20,
+    InsufficientFees   = 21,
+    TokenNotAllowed    = 22,
+    InvalidAccess      = 23,
+    NotInvited         = 24,
+    MissingInviteSig   = 25,
+    InvalidRounds      = 26,
+    InvalidGuess       = 27,
 }
 
 #[contractclient(name = "VerifierClient")]
@@ -54,7 +94,25 @@ pub trait VerifierInterface {
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum RoomStatus { Lobby, Commit, Settled }
+pub enum RoomStatus { Lobby, Commit, Settled, Cancelled, Refunded }
+
+/// Result of a two-seat room. `Draw` covers a shared prize (both or neither
+/// guessing right) and a refund; `Pending` until then.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome { Pending, P1Win, P2Win, Draw }
+
+/// Who may take the free seats of a room. `Invited` lists the only
+/// addresses allowed to join, one distinct address per free seat; `Key` holds an ed25519 public key, and a
+/// joiner passes its signature over `room_id ‖ player` to `join_room`: the
+/// id as 8 big-endian bytes, then the address as `ScVal` XDR.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoomAccess {
+    Public,
+    Invited(Vec<Address>),
+    Key(BytesN<32>),
+}
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -64,32 +122,168 @@ pub struct PlayerState {
     pub has_committed: bool,
     pub parity_guess: u32,    // 0 = Even, 1 = Odd
     pub exact_sum_guess: u32,
+    pub has_revealed: bool,
+    pub hand: u32,            // only meaningful once has_revealed
+    pub score: u32,           // rounds won so far in a match
+    pub exact_hits: u32,      // rounds with the exact sum so far in a match
+}
+
+/// Payout terms, in basis points. `fee_bps` of the pot goes to the treasury;
+/// `parity_bps` of the rest funds the parity pool and the remainder the
+/// jackpot.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTerms {
+    pub fee_bps: u32,
+    pub parity_bps: u32,
 }
 
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct Room {
-    pub player1: PlayerState,
-    pub player2: PlayerState,
-    pub has_player2: bool,
+    /// Seated players in join order; the first one created the room.
+    pub players: Vec<PlayerState>,
+    pub seats: u32,
+    /// Token escrowed by this room; bets, payouts, fees and the jackpot it
+    /// feeds are all in this asset.
+    pub token: Address,
     pub bet_amount: i128,
+    pub access: RoomAccess,
+    /// Best-of-`rounds` match; 1 for a single throw.
+    pub rounds: u32,
+    /// Index of the round being played, from 0.
+    pub round: u32,
+    /// Total sum of every finished round, in order.
+    pub round_sums: Vec<u32>,
     pub status: RoomStatus,
     pub session_id: u32,
+    /// The sole winner; `None` while open and when the prize is shared.
     pub winner: Option<Address>,
+    /// Exact-sum hitters if anyone hit, otherwise correct parity guessers;
+    /// in a match, the top scorers. On a timeout, the players who did not
+    /// stall.
+    pub winners: Vec<Address>,
+    /// Total of the latest finished round.
+    pub total_sum: Option<u32>,
+    /// Set when a two-seat room finishes; larger rooms stay `Pending` and
+    /// only use `winners`.
+    pub outcome: Outcome,
+    /// Terms in force when the room was created; later config changes do
+    /// not apply to it.
+    pub fee_terms: FeeTerms,
+    /// Ledger sequence ending the current phase: joining in `Lobby`,
+    /// committing in `Commit`, then resolving or revealing once everyone has
+    /// committed, at least `REVEAL_TIMEOUT_LEDGERS` after the first reveal.
+    pub deadline: u32,
+}
+
+/// A room together with its id, as returned by the listing queries.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RoomEntry {
+    pub room_id: u64,
+    pub room: Room,
+}
+
+/// A page of `list_open_rooms`. `next_cursor` is where the next call picks
+/// up, `None` once the whole index has been read.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RoomPage {
+    pub rooms: Vec<RoomEntry>,
+    pub next_cursor: Option<u64>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomCreated {
+    #[topic]
+    pub room_id: u64,
+    pub creator: Address,
+    pub token: Address,
+    pub bet_amount: i128,
+    pub seats: u32,
+    pub rounds: u32,
+    pub private: bool,
+}
+
+/// `session_id` is 0 until the last seat is taken.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomJoined {
+    #[topic]
+    pub room_id: u64,
+    pub player: Address,
+    pub seat: u32,
+    pub session_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Committed {
+    #[topic]
+    pub room_id: u64,
+    pub player: Address,
+    pub round: u32,
+}
+
+/// The pot was paid out. `payouts` is per seat; `total_sum` is `None` for a
+/// timeout claim.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Resolved {
+    #[topic]
+    pub room_id: u64,
     pub total_sum: Option<u32>,
+    pub payouts: Vec<i128>,
+    pub fee: i128,
+    pub jackpot_delta: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JackpotWon {
+    #[topic]
+    pub room_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub winners: Vec<Address>,
+}
+
+/// Every bet was returned; `status` is `Cancelled` or `Refunded`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoomCancelled {
+    #[topic]
+    pub room_id: u64,
+    pub status: RoomStatus,
 }
 
 #[contracttype]
 enum DataKey {
-    Admin,
     Verifier,
-    GameHub,
+    VkHash,
+    MultiVkHash,
     RoomCounter,
     Room(u64),
+    /// Token the contract was deployed with. Its jackpot used to live under
+    /// `GlobalJackpot`, which is migrated to `Jackpot(token)` on first write.
     XlmToken,
     GlobalJackpot,
+    FeeTerms,
+    AllowedTokens,
+    Jackpot(Address),
+    Treasury(Address),
     Nullifier(BytesN<32>),
     SessionCounter,
+    /// Buckets of `OpenRooms` holding at least one id, ascending.
+    OpenRoomBuckets,
+    /// Ids of joinable public rooms in one bucket, ascending.
+    OpenRooms(u64),
+    /// Buckets of `PlayerRooms` holding at least one id, ascending.
+    PlayerRoomBuckets(Address),
+    /// Ids of unfinished rooms a player took a seat in, per bucket, ascending.
+    PlayerRooms(Address, u64),
 }
 
 #[contract]
@@ -102,106 +296,312 @@ impl ZkPorrinhaContract {
         admin: Address,
         game_hub: Address,
         verifier: Address,
+        vk_hash: BytesN<32>,
         xlm_token: Address,
     ) {
         let s = env.storage().instance();
-        s.set(&DataKey::Admin, &admin);
-        s.set(&DataKey::GameHub, &game_hub);
+        admin::init(&env, &admin, &game_hub);
         s.set(&DataKey::Verifier, &verifier);
+        s.set(&DataKey::VkHash, &vk_hash);
         s.set(&DataKey::XlmToken, &xlm_token);
+        s.set(&DataKey::AllowedTokens, &Vec::from_array(&env, [xlm_token]));
         s.set(&DataKey::RoomCounter, &0u64);
         s.set(&DataKey::SessionCounter, &0u32);
-        s.set(&DataKey::GlobalJackpot, &0i128);
+        s.set(&DataKey::FeeTerms, &FeeTerms { fee_bps: 0, parity_bps: DEFAULT_PARITY_BPS });
     }
 
     pub fn set_verifier(env: Env, new_verifier: Address) {
         let s = env.storage().instance();
-        let admin: Address = s.get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Self::get_admin(&env).require_auth();
         s.set(&DataKey::Verifier, &new_verifier);
     }
 
-    pub fn create_room(env: Env, player: Address, bet_amount: i128) -> Result<u64, Error> {
+    /// Points resolution at a different circuit registered in the verifier.
+    pub fn set_vk_hash(env: Env, new_vk_hash: BytesN<32>) {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        s.set(&DataKey::VkHash, &new_vk_hash);
+    }
+
+    pub fn get_vk_hash(env: Env) -> Result<BytesN<32>, Error> {
+        Self::load_vk_hash(&env)
+    }
+
+    /// Sets the verification key hash of the N-seat circuit used to resolve
+    /// rooms with more than two seats. Until it is set those rooms can only
+    /// settle through `reveal`.
+    pub fn set_multi_vk_hash(env: Env, new_vk_hash: BytesN<32>) {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        s.set(&DataKey::MultiVkHash, &new_vk_hash);
+    }
+
+    pub fn get_multi_vk_hash(env: Env) -> Result<BytesN<32>, Error> {
+        Self::load_multi_vk_hash(&env)
+    }
+
+    /// Sets the terms for rooms created from now on. Rooms already open keep
+    /// the terms they were created with.
+    pub fn set_fee_terms(env: Env, fee_bps: u32, parity_bps: u32) -> Result<(), Error> {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        if fee_bps > MAX_FEE_BPS || parity_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidFeeTerms);
+        }
+        s.set(&DataKey::FeeTerms, &FeeTerms { fee_bps, parity_bps });
+        Ok(())
+    }
+
+    pub fn get_fee_terms(env: Env) -> FeeTerms {
+        Self::load_fee_terms(&env)
+    }
+
+    /// Fees collected in `token` and not yet withdrawn.
+    pub fn get_treasury(env: Env, token: Address) -> i128 {
+        env.storage().instance().get(&DataKey::Treasury(token)).unwrap_or(0)
+    }
+
+    pub fn withdraw_fees(env: Env, token: Address, to: Address, amount: i128) -> Result<(), Error> {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        Self::bump_instance(&env);
+
+        let key = DataKey::Treasury(token.clone());
+        let treasury: i128 = s.get(&key).unwrap_or(0);
+        if amount <= 0 || amount > treasury {
+            return Err(Error::InsufficientFees);
+        }
+        s.set(&key, &(treasury - amount));
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+        Ok(())
+    }
+
+    /// Allows new rooms to escrow `token`.
+    pub fn allow_token(env: Env, token: Address) {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        let mut tokens = Self::load_allowed_tokens(&env);
+        if !tokens.contains(&token) {
+            tokens.push_back(token);
+            s.set(&DataKey::AllowedTokens, &tokens);
+        }
+    }
+
+    /// Stops new rooms from using `token`. Open rooms, its jackpot and its
+    /// treasury are unaffected.
+    pub fn disallow_token(env: Env, token: Address) {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+        let mut tokens = Self::load_allowed_tokens(&env);
+        if let Some(i) = tokens.first_index_of(&token) {
+            tokens.remove(i);
+            s.set(&DataKey::AllowedTokens, &tokens);
+        }
+    }
+
+    pub fn get_allowed_tokens(env: Env) -> Vec<Address> {
+        Self::load_allowed_tokens(&env)
+    }
+
+    /// Moves nullifiers written to instance storage by earlier versions into
+    /// their own persistent entries. Instance storage cannot be enumerated,
+    /// so the admin supplies the used nullifiers (e.g. from past `resolve`
+    /// calls). Returns how many were moved; unknown ones are skipped.
+    pub fn migrate_nullifiers(env: Env, nullifiers: Vec<BytesN<32>>) -> u32 {
+        let s = env.storage().instance();
+        Self::get_admin(&env).require_auth();
+
+        let mut moved = 0u32;
+        for nullifier in nullifiers.iter() {
+            let key = DataKey::Nullifier(nullifier.clone());
+            if s.has(&key) {
+                s.remove(&key);
+                Self::mark_nullifier(&env, &nullifier);
+                moved += 1;
+            }
+        }
+        moved
+    }
+
+    /// Opens a room with `seats` places (`MIN_SEATS..=MAX_SEATS`) betting
+    /// `bet_amount` of an allowlisted `token`; the creator takes the first
+    /// seat. Private rooms (any `access` but `Public`) stay out of
+    /// `list_open_rooms`. `rounds` above 1 makes the room a best-of-`rounds`
+    /// match paid out when it ends.
+    pub fn create_room(
+        env: Env,
+        player: Address,
+        token: Address,
+        bet_amount: i128,
+        seats: u32,
+        access: RoomAccess,
+        rounds: u32,
+    ) -> Result<u64, Error> {
         player.require_auth();
         Self::bump_instance(&env);
         if bet_amount <= 0 {
             return Err(Error::InvalidBet);
         }
+        if !(MIN_SEATS..=MAX_SEATS).contains(&seats) {
+            return Err(Error::InvalidSeats);
+        }
+        if !(1..=MAX_ROUNDS).contains(&rounds) {
+            return Err(Error::InvalidRounds);
+        }
+        if !Self::load_allowed_tokens(&env).contains(&token) {
+            return Err(Error::TokenNotAllowed);
+        }
+        if let RoomAccess::Invited(invited) = &access {
+            // One distinct invitee per free seat, so every invitee can join.
+            if invited.len() != seats - 1 || invited.contains(&player) {
+                return Err(Error::InvalidAccess);
+            }
+            for (i, invitee) in invited.iter().enumerate() {
+                if invited.first_index_of(&invitee) != Some(i as u32) {
+                    return Err(Error::InvalidAccess);
+                }
+            }
+        }
 
-        let token = token::Client::new(&env, &Self::get_xlm_token(&env)?);
-        token.transfer(&player, &env.current_contract_address(), &bet_amount);
+        token::Client::new(&env, &token).transfer(&player, env.current_contract_address(), &bet_amount);
 
         let mut counter: u64 = env.storage().instance().get(&DataKey::RoomCounter).unwrap_or(0);
         counter += 1;
         env.storage().instance().set(&DataKey::RoomCounter, &counter);
 
-        let null_addr = env.current_contract_address();
+        Self::index_player_room(&env, &player, counter);
+        let mut players = Vec::new(&env);
+        players.push_back(Self::empty_seat(&env, player));
         let room = Room {
-            player1: PlayerState {
-                address: player,
-                commitment: BytesN::from_array(&env, &[0; 32]),
-                has_committed: false,
-                parity_guess: 0,
-                exact_sum_guess: 0,
-            },
-            player2: PlayerState {
-                address: null_addr,
-                commitment: BytesN::from_array(&env, &[0; 32]),
-                has_committed: false,
-                parity_guess: 0,
-                exact_sum_guess: 0,
-            },
-            has_player2: false,
+            players,
+            seats,
+            token,
             bet_amount,
+            access,
+            rounds,
+            round: 0,
+            round_sums: Vec::new(&env),
             status: RoomStatus::Lobby,
             session_id: 0,
             winner: None,
+            winners: Vec::new(&env),
             total_sum: None,
+            outcome: Outcome::Pending,
+            fee_terms: Self::load_fee_terms(&env),
+            deadline: env.ledger().sequence() + LOBBY_TIMEOUT_LEDGERS,
         };
 
         Self::save_room(&env, counter, &room);
+        if room.access == RoomAccess::Public {
+            Self::prune_expired_lobbies(&env);
+            Self::index_open_room(&env, counter);
+        }
+        RoomCreated {
+            room_id: counter,
+            creator: room.players.get_unchecked(0).address,
+            token: room.token,
+            bet_amount,
+            seats,
+            rounds,
+            private: room.access != RoomAccess::Public,
+        }
+        .publish(&env);
         Ok(counter)
     }
 
-    pub fn join_room(env: Env, room_id: u64, player: Address) -> Result<(), Error> {
+    /// Takes the next free seat. The session starts once every seat is taken.
+    /// `invite_sig` is the invite key's signature of a `Key` room over
+    /// `room_id ‖ player` (see `RoomAccess`) and is ignored otherwise. A
+    /// wrong signature traps.
+    pub fn join_room(
+        env: Env,
+        room_id: u64,
+        player: Address,
+        invite_sig: Option<BytesN<64>>,
+    ) -> Result<(), Error> {
         player.require_auth();
         Self::bump_instance(&env);
         let mut room = Self::load_room(&env, room_id)?;
 
-        if room.status != RoomStatus::Lobby || room.has_player2 {
+        if room.status != RoomStatus::Lobby || room.players.len() >= room.seats {
             return Err(Error::InvalidPhase);
         }
-        if room.player1.address == player {
+        if room.players.iter().any(|p| p.address == player) {
             return Err(Error::SelfPlay);
         }
+        if env.ledger().sequence() > room.deadline {
+            return Err(Error::RoomExpired);
+        }
+        match &room.access {
+            RoomAccess::Public => {}
+            RoomAccess::Invited(invited) => {
+                if !invited.contains(&player) {
+                    return Err(Error::NotInvited);
+                }
+            }
+            RoomAccess::Key(invite_key) => {
+                let sig = invite_sig.ok_or(Error::MissingInviteSig)?;
+                let message = Self::invite_message(&env, room_id, &player);
+                env.crypto().ed25519_verify(invite_key, &message, &sig);
+            }
+        }
+
+        let token = token::Client::new(&env, &room.token);
+        token.transfer(&player, env.current_contract_address(), &room.bet_amount);
 
-        let token = token::Client::new(&env, &Self::get_xlm_token(&env)?);
-        token.transfer(&player, &env.current_contract_address(), &room.bet_amount);
+        Self::index_player_room(&env, &player, room_id);
+        room.players.push_back(Self::empty_seat(&env, player.clone()));
+        let seat = room.players.len() - 1;
+        if room.players.len() < room.seats {
+            Self::save_room(&env, room_id, &room);
+            RoomJoined { room_id, player, seat, session_id: 0 }.publish(&env);
+            return Ok(());
+        }
+        Self::unindex_open_room(&env, room_id);
 
         let mut session_counter: u32 = env.storage().instance().get(&DataKey::SessionCounter).unwrap_or(0);
         session_counter += 1;
         env.storage().instance().set(&DataKey::SessionCounter, &session_counter);
         room.session_id = session_counter;
 
-        let game_hub = Self::get_game_hub(&env)?;
-        let game_hub_client = GameHubClient::new(&env, &game_hub);
-        game_hub_client.start_game(
-            &env.current_contract_address(),
-            &session_counter,
-            &room.player1.address,
-            &player,
-            &room.bet_amount,
-            &room.bet_amount,
-        );
+        let game_hub_client = GameHubClient::new(&env, &Self::get_game_hub(&env)?);
+        if room.seats == 2 {
+            game_hub_client.start_game(
+                &env.current_contract_address(),
+                &session_counter,
+                &room.players.get_unchecked(0).address,
+                &room.players.get_unchecked(1).address,
+                &room.bet_amount,
+                &room.bet_amount,
+            );
+        } else {
+            let mut addresses = Vec::new(&env);
+            let mut points = Vec::new(&env);
+            for p in room.players.iter() {
+                addresses.push_back(p.address);
+                points.push_back(room.bet_amount);
+            }
+            game_hub_client.start_multiplayer_game(
+                &env.current_contract_address(),
+                &session_counter,
+                &addresses,
+                &points,
+            );
+        }
 
-        room.player2.address = player;
-        room.has_player2 = true;
         room.status = RoomStatus::Commit;
+        room.deadline = env.ledger().sequence() + COMMIT_TIMEOUT_LEDGERS;
 
         Self::save_room(&env, room_id, &room);
+        RoomJoined { room_id, player, seat, session_id: session_counter }.publish(&env);
         Ok(())
     }
 
+    /// Seals the player's hand and guesses for the current round. `parity`
+    /// is 0 (even) or 1 (odd); `exact_guess` is at most the highest sum the
+    /// room's circuit accepts, `MAX_HAND * 2` for two seats and
+    /// `MAX_HAND * MAX_SEATS` otherwise.
     pub fn commit(
         env: Env,
         room_id: u64,
@@ -216,127 +616,231 @@ impl ZkPorrinhaContract {
         if room.status != RoomStatus::Commit {
             return Err(Error::InvalidPhase);
         }
+        if env.ledger().sequence() > room.deadline {
+            return Err(Error::RoomExpired);
+        }
+        let max_exact = if room.seats == 2 { MAX_HAND * 2 } else { MAX_HAND * MAX_SEATS };
+        if parity > 1 || exact_guess > max_exact {
+            return Err(Error::InvalidGuess);
+        }
 
-        let is_p1 = player == room.player1.address;
-        let is_p2 = room.has_player2 && player == room.player2.address;
-        if !is_p1 && !is_p2 {
-            return Err(Error::NotPlayer);
+        let seat = Self::seat_of(&room, &player)?;
+        let mut state = room.players.get_unchecked(seat);
+        if state.has_committed {
+            return Err(Error::AlreadyCommitted);
         }
+        state.commitment = commitment;
+        state.parity_guess = parity;
+        state.exact_sum_guess = exact_guess;
+        state.has_committed = true;
+        room.players.set(seat, state);
 
-        if is_p1 {
-            if room.player1.has_committed { return Err(Error::AlreadyCommitted); }
-            room.player1.commitment = commitment;
-            room.player1.parity_guess = parity;
-            room.player1.exact_sum_guess = exact_guess;
-            room.player1.has_committed = true;
-        } else {
-            if room.player2.has_committed { return Err(Error::AlreadyCommitted); }
-            room.player2.commitment = commitment;
-            room.player2.parity_guess = parity;
-            room.player2.exact_sum_guess = exact_guess;
-            room.player2.has_committed = true;
+        if Self::all_committed(&room) {
+            room.deadline = env.ledger().sequence() + RESOLVE_TIMEOUT_LEDGERS;
         }
 
         Self::save_room(&env, room_id, &room);
+        Committed { room_id, player, round: room.round }.publish(&env);
         Ok(())
     }
 
+    /// Verifies the proof and settles the current round. The nullifier is
+    /// not chosen by the caller: it is derived from the room id, session id,
+    /// round and all commitments (see `get_nullifier`), so a proof is spent
+    /// by exactly the round it was generated for.
     pub fn resolve(
         env: Env,
         room_id: u64,
         proof: Bytes,
         total_sum: u32,
-        nullifier: BytesN<32>,
     ) -> Result<(), Error> {
         Self::bump_instance(&env);
         let mut room = Self::load_room(&env, room_id)?;
 
-        if room.status != RoomStatus::Commit
-            || !room.player1.has_committed
-            || !room.player2.has_committed
-        {
+        if room.status != RoomStatus::Commit || !Self::all_committed(&room) {
             return Err(Error::InvalidPhase);
         }
+        if env.ledger().sequence() > room.deadline {
+            return Err(Error::RoomExpired);
+        }
 
-         let nullifier_key = DataKey::Nullifier(nullifier.clone());
-        if env.storage().instance().has(&nullifier_key) {
+        let nullifier = Self::room_nullifier(&env, room_id, &room);
+        if Self::is_nullifier_used(&env, &nullifier) {
             return Err(Error::NullifierUsed);
         }
 
         let verifier = VerifierClient::new(&env, &Self::get_verifier(&env)?);
-        let mut public_inputs: Vec<BytesN<32>> = Vec::new(&env);
-        public_inputs.push_back(room.player1.commitment.clone());
-        public_inputs.push_back(room.player2.commitment.clone());
-        public_inputs.push_back(Self::u32_to_bytes32(&env, room.player1.parity_guess));
-        public_inputs.push_back(Self::u32_to_bytes32(&env, room.player2.parity_guess));
-        public_inputs.push_back(Self::u32_to_bytes32(&env, room.player1.exact_sum_guess));
-        public_inputs.push_back(Self::u32_to_bytes32(&env, room.player2.exact_sum_guess));
-        public_inputs.push_back(Self::u32_to_bytes32(&env, total_sum));
-
-        let vk_hash_bytes = BytesN::from_array(&env, &VK_HASH);
-        if !verifier.verify(&proof, &public_inputs, &vk_hash_bytes) {
+        let vk_hash = if room.seats == 2 {
+            Self::load_vk_hash(&env)?
+        } else {
+            Self::load_multi_vk_hash(&env)?
+        };
+        let public_inputs = Self::public_inputs(&env, &room, total_sum);
+        if !verifier.verify(&proof, &public_inputs, &vk_hash) {
             return Err(Error::InvalidProof);
         }
 
-        env.storage().instance().set(&nullifier_key, &true);
+        Self::mark_nullifier(&env, &nullifier);
 
-        let total_pot = room.bet_amount * 2;
-        let parity_pool = (total_pot * 80) / 100;
-        let jackpot_contribution = total_pot - parity_pool; // 20%
+        Self::finish_round(&env, room_id, &mut room, total_sum)
+    }
 
-        let real_parity = total_sum % 2;
-        let p1_wins_parity = room.player1.parity_guess == real_parity;
-        let p2_wins_parity = room.player2.parity_guess == real_parity;
+    /// Non-ZK fallback: opens the caller's commitment on-chain by recomputing
+    /// `poseidon2([hand, parity_guess, exact_sum_guess, salt])`. The room
+    /// round settles once every player has revealed. The first reveal leaves
+    /// the others at least `REVEAL_TIMEOUT_LEDGERS` to follow before anyone
+    /// can claim a forfeit. `resolve` with a proof remains
+    /// the default path and keeps hands private.
+    pub fn reveal(
+        env: Env,
+        room_id: u64,
+        player: Address,
+        hand: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+        Self::bump_instance(&env);
+        let mut room = Self::load_room(&env, room_id)?;
 
-        let token = token::Client::new(&env, &Self::get_xlm_token(&env)?);
+        if room.status != RoomStatus::Commit || !Self::all_committed(&room) {
+            return Err(Error::InvalidPhase);
+        }
+        let now = env.ledger().sequence();
+        if now > room.deadline {
+            return Err(Error::RoomExpired);
+        }
 
-        if p1_wins_parity && !p2_wins_parity {
-            token.transfer(&env.current_contract_address(), &room.player1.address, &parity_pool);
-        } else if p2_wins_parity && !p1_wins_parity {
-            token.transfer(&env.current_contract_address(), &room.player2.address, &parity_pool);
-        } else {
-            let half = parity_pool / 2;
-            token.transfer(&env.current_contract_address(), &room.player1.address, &half);
-            token.transfer(&env.current_contract_address(), &room.player2.address, &half);
-        }
-
-        let mut jackpot: i128 = env.storage().instance().get(&DataKey::GlobalJackpot).unwrap_or(0);
-        let p1_hits_exact = room.player1.exact_sum_guess == total_sum;
-        let p2_hits_exact = room.player2.exact_sum_guess == total_sum;
-
-        let player1_won: bool;
-
-        if p1_hits_exact || p2_hits_exact {
-            let prize = jackpot + jackpot_contribution;
-            if p1_hits_exact && p2_hits_exact {
-                let half = prize / 2;
-                token.transfer(&env.current_contract_address(), &room.player1.address, &half);
-                token.transfer(&env.current_contract_address(), &room.player2.address, &half);
-                room.winner = Some(room.player1.address.clone());
-                player1_won = true;
-            } else {
-                let jackpot_winner = if p1_hits_exact { &room.player1.address } else { &room.player2.address };
-                token.transfer(&env.current_contract_address(), jackpot_winner, &prize);
-                room.winner = Some(jackpot_winner.clone());
-                player1_won = p1_hits_exact;
-            }
-            env.storage().instance().set(&DataKey::GlobalJackpot, &0i128);
-        } else {
-            jackpot += jackpot_contribution;
-            env.storage().instance().set(&DataKey::GlobalJackpot, &jackpot);
-            let parity_winner = if p1_wins_parity { &room.player1.address } else { &room.player2.address };
-            room.winner = Some(parity_winner.clone());
-            player1_won = p1_wins_parity;
+        let seat = Self::seat_of(&room, &player)?;
+        let mut state = room.players.get_unchecked(seat);
+        if state.has_revealed {
+            return Err(Error::AlreadyRevealed);
+        }
+        if hand > MAX_HAND {
+            return Err(Error::InvalidHand);
+        }
+        if !poseidon2::is_field_element(&salt) {
+            return Err(Error::InvalidReveal);
+        }
+        let opened = poseidon2::commitment(
+            &env,
+            hand,
+            state.parity_guess,
+            state.exact_sum_guess,
+            &salt,
+        );
+        if opened != state.commitment {
+            return Err(Error::InvalidReveal);
         }
+        if room.players.iter().all(|p| !p.has_revealed) {
+            room.deadline = room.deadline.max(now + REVEAL_TIMEOUT_LEDGERS);
+        }
+        state.hand = hand;
+        state.has_revealed = true;
+        room.players.set(seat, state);
 
-        room.total_sum = Some(total_sum);
+        if room.players.iter().all(|p| p.has_revealed) {
+            let total_sum = room.players.iter().map(|p| p.hand).sum();
+            return Self::finish_round(&env, room_id, &mut room, total_sum);
+        }
+
+        Self::save_room(&env, room_id, &room);
+        Ok(())
+    }
+
+    /// The creator closes a lobby that has not filled up; everyone seated
+    /// gets their bet back.
+    pub fn cancel_room(env: Env, room_id: u64) -> Result<(), Error> {
+        Self::bump_instance(&env);
+        let mut room = Self::load_room(&env, room_id)?;
+        room.players.get_unchecked(0).address.require_auth();
+
+        if room.status != RoomStatus::Lobby {
+            return Err(Error::InvalidPhase);
+        }
+
+        Self::refund_all(&env, &room);
+        Self::unindex_open_room(&env, room_id);
+
+        room.status = RoomStatus::Cancelled;
+        Self::archive_room(&env, room_id, &room);
+        RoomCancelled { room_id, status: room.status }.publish(&env);
+        Ok(())
+    }
+
+    /// Claims the pot, less the room's fee, after the current deadline when
+    /// others stalled: before everyone committed, the players who committed
+    /// split it; afterwards, the players who revealed do, once the reveal
+    /// window has closed. `player` must be one of them.
+    pub fn claim_timeout(env: Env, room_id: u64, player: Address) -> Result<(), Error> {
+        player.require_auth();
+        Self::bump_instance(&env);
+        let mut room = Self::load_room(&env, room_id)?;
+
+        if room.status != RoomStatus::Commit {
+            return Err(Error::InvalidPhase);
+        }
+        if env.ledger().sequence() <= room.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        let seat = Self::seat_of(&room, &player)?;
+        let active = Self::active_players(&env, &room);
+        if !Self::has_acted(&room, seat) || active.len() == room.players.len() {
+            return Err(Error::NothingToClaim);
+        }
+
+        let pot = room.bet_amount * room.players.len() as i128;
+        let jackpot = Self::load_jackpot(&env, &room.token);
+        let active_seats = (0..room.players.len())
+            .filter(|&seat| Self::has_acted(&room, seat))
+            .fold(0u32, |mask, seat| mask | (1 << seat));
+        let result = payout::forfeit(pot, jackpot, &room.fee_terms, active_seats);
+        Self::apply_payout(&env, room_id, &room, jackpot, &result, None);
+
+        let mut quitters = Vec::new(&env);
+        for p in room.players.iter() {
+            if !active.contains(&p.address) {
+                quitters.push_back(p.address);
+            }
+        }
         room.status = RoomStatus::Settled;
+        Self::record_result(&env, &mut room, active, GameOutcome::Forfeit(quitters))?;
 
-        let game_hub = Self::get_game_hub(&env)?;
-        let game_hub_client = GameHubClient::new(&env, &game_hub);
-        game_hub_client.end_game(&room.session_id, &player1_won);
+        Self::archive_room(&env, room_id, &room);
+        Ok(())
+    }
 
-        Self::save_room(&env, room_id, &room);
+    /// Returns every bet once the current deadline passed without a result
+    /// and nobody can claim a forfeit: the lobby never filled, nobody
+    /// committed, or everyone committed and no proof or reveal arrived.
+    /// Anyone may call.
+    pub fn refund_room(env: Env, room_id: u64) -> Result<(), Error> {
+        Self::bump_instance(&env);
+        let mut room = Self::load_room(&env, room_id)?;
+
+        if room.status != RoomStatus::Lobby && room.status != RoomStatus::Commit {
+            return Err(Error::InvalidPhase);
+        }
+        if env.ledger().sequence() <= room.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        let started = room.status == RoomStatus::Commit;
+        if started && !Self::active_players(&env, &room).is_empty() {
+            return Err(Error::NothingToClaim);
+        }
+
+        Self::refund_all(&env, &room);
+
+        room.status = RoomStatus::Refunded;
+
+        if started {
+            Self::record_result(&env, &mut room, Vec::new(&env), GameOutcome::Cancelled)?;
+        } else {
+            Self::unindex_open_room(&env, room_id);
+        }
+
+        Self::archive_room(&env, room_id, &room);
+        RoomCancelled { room_id, status: room.status }.publish(&env);
         Ok(())
     }
 
@@ -344,19 +848,382 @@ impl ZkPorrinhaContract {
         Self::load_room(&env, room_id)
     }
 
+    /// The nullifier `resolve` burns for the current round. Only defined once
+    /// every player has committed.
+    pub fn get_nullifier(env: Env, room_id: u64) -> Result<BytesN<32>, Error> {
+        let room = Self::load_room(&env, room_id)?;
+        if room.status == RoomStatus::Lobby || !Self::all_committed(&room) {
+            return Err(Error::InvalidPhase);
+        }
+        Ok(Self::room_nullifier(&env, room_id, &room))
+    }
+
+    /// Joinable rooms with an id above `cursor`, oldest first, at most
+    /// `limit` (capped at `MAX_PAGE`). Expired lobbies are skipped;
+    /// `token` and `bet_amount` narrow the results when given. A call reads
+    /// at most `MAX_SCAN` rooms, so a page may come back short or empty
+    /// before the index is exhausted: keep passing `next_cursor` until it
+    /// is `None`.
+    pub fn list_open_rooms(
+        env: Env,
+        cursor: u64,
+        limit: u32,
+        token: Option<Address>,
+        bet_amount: Option<i128>,
+    ) -> RoomPage {
+        let limit = limit.min(MAX_PAGE);
+        let now = env.ledger().sequence();
+        let mut rooms = Vec::new(&env);
+        if limit == 0 {
+            return RoomPage { rooms, next_cursor: Some(cursor) };
+        }
+        let mut scanned = 0;
+        let mut last = cursor;
+        let stopped = Self::scan_room_index(&env, &DataKey::OpenRoomBuckets, DataKey::OpenRooms, cursor, |room_id| {
+            scanned += 1;
+            last = room_id;
+            if let Ok(room) = Self::load_room(&env, room_id) {
+                if now <= room.deadline
+                    && token.as_ref().is_none_or(|t| *t == room.token)
+                    && bet_amount.is_none_or(|b| b == room.bet_amount)
+                {
+                    rooms.push_back(RoomEntry { room_id, room });
+                }
+            }
+            rooms.len() < limit && scanned < MAX_SCAN
+        });
+        RoomPage { rooms, next_cursor: stopped.then_some(last) }
+    }
+
+    /// Unfinished rooms `player` took a seat in with an id above `cursor`,
+    /// oldest first, at most `limit` (capped at `MAX_PAGE`). Rooms leave the
+    /// list once settled, cancelled or refunded.
+    pub fn list_rooms_by_player(env: Env, player: Address, cursor: u64, limit: u32) -> Vec<RoomEntry> {
+        let limit = limit.min(MAX_PAGE);
+        let mut page = Vec::new(&env);
+        if limit == 0 {
+            return page;
+        }
+        let buckets_key = DataKey::PlayerRoomBuckets(player.clone());
+        Self::scan_room_index(&env, &buckets_key, |b| DataKey::PlayerRooms(player.clone(), b), cursor, |room_id| {
+            if let Ok(room) = Self::load_room(&env, room_id) {
+                page.push_back(RoomEntry { room_id, room });
+            }
+            page.len() < limit
+        });
+        page
+    }
+
     pub fn get_room_count(env: Env) -> u64 {
         env.storage().instance().get(&DataKey::RoomCounter).unwrap_or(0)
     }
 
-    pub fn get_jackpot(env: Env) -> i128 {
+    pub fn get_jackpot(env: Env, token: Address) -> i128 {
         Self::bump_instance(&env);
-        env.storage().instance().get(&DataKey::GlobalJackpot).unwrap_or(0)
+        Self::load_jackpot(&env, &token)
     }
 
     pub fn get_room_pot(env: Env, room_id: u64) -> Result<i128, Error> {
         let room = Self::load_room(&env, room_id)?;
-        let players: i128 = if room.has_player2 { 2 } else { 1 };
-        Ok(room.bet_amount * players)
+        Ok(room.bet_amount * room.players.len() as i128)
+    }
+
+    /// Scores a round that totalled `total_sum`. A single throw settles on
+    /// it; a match starts the next round until it is decided, then settles
+    /// on the scores.
+    fn finish_round(env: &Env, room_id: u64, room: &mut Room, total_sum: u32) -> Result<(), Error> {
+        let real_parity = total_sum % 2;
+        let mut parity_winners = Vec::new(env);
+        let mut exact_hitters = Vec::new(env);
+        let (mut parity_hits, mut exact_hits) = (0u32, 0u32);
+        for (seat, p) in room.players.iter().enumerate() {
+            if p.parity_guess == real_parity {
+                parity_winners.push_back(p.address.clone());
+                parity_hits |= 1 << seat;
+            }
+            if p.exact_sum_guess == total_sum {
+                exact_hitters.push_back(p.address);
+                exact_hits |= 1 << seat;
+            }
+        }
+        let (winners, round_winners) = if exact_hitters.is_empty() {
+            (parity_winners, parity_hits)
+        } else {
+            (exact_hitters, exact_hits)
+        };
+
+        room.total_sum = Some(total_sum);
+        room.round_sums.push_back(total_sum);
+        if room.rounds == 1 {
+            return Self::settle(env, room_id, room, parity_hits, exact_hits, winners);
+        }
+
+        for seat in 0..room.players.len() {
+            let mut state = room.players.get_unchecked(seat);
+            state.score += (round_winners >> seat) & 1;
+            state.exact_hits += (exact_hits >> seat) & 1;
+            room.players.set(seat, state);
+        }
+        room.round += 1;
+
+        if !Self::match_decided(room) {
+            for seat in 0..room.players.len() {
+                let state = room.players.get_unchecked(seat);
+                let mut next = Self::empty_seat(env, state.address);
+                next.score = state.score;
+                next.exact_hits = state.exact_hits;
+                room.players.set(seat, next);
+            }
+            room.deadline = env.ledger().sequence() + COMMIT_TIMEOUT_LEDGERS;
+            Self::save_room(env, room_id, room);
+            return Ok(());
+        }
+
+        // The top scorers take the parity pool; anyone who hit an exact sum
+        // during the match shares the jackpot.
+        let top = room.players.iter().map(|p| p.score).max().unwrap_or(0);
+        let mut leaders = Vec::new(env);
+        let (mut leader_seats, mut exact_seats) = (0u32, 0u32);
+        for (seat, p) in room.players.iter().enumerate() {
+            if p.score == top {
+                leader_seats |= 1 << seat;
+                if top > 0 {
+                    leaders.push_back(p.address.clone());
+                }
+            }
+            if p.exact_hits > 0 {
+                exact_seats |= 1 << seat;
+            }
+        }
+        Self::settle(env, room_id, room, leader_seats, exact_seats, leaders)
+    }
+
+    /// A match ends after its last round, or earlier once a single leader
+    /// cannot be caught in the rounds left.
+    fn match_decided(room: &Room) -> bool {
+        let left = room.rounds - room.round;
+        if left == 0 {
+            return true;
+        }
+        let (mut first, mut second) = (0u32, 0u32);
+        for p in room.players.iter() {
+            if p.score > first {
+                second = first;
+                first = p.score;
+            } else if p.score > second {
+                second = p.score;
+            }
+        }
+        first > second + left
+    }
+
+    /// Distributes the pot per `payout::settle`: fee to the treasury, parity
+    /// pool to `parity_hits` (everyone if empty), the rest to the jackpot,
+    /// which `exact_hits` then split.
+    fn settle(
+        env: &Env,
+        room_id: u64,
+        room: &mut Room,
+        parity_hits: u32,
+        exact_hits: u32,
+        winners: Vec<Address>,
+    ) -> Result<(), Error> {
+        let total_pot = room.bet_amount * room.players.len() as i128;
+        let jackpot = Self::load_jackpot(env, &room.token);
+        let result = payout::settle(
+            total_pot,
+            jackpot,
+            &room.fee_terms,
+            room.players.len(),
+            parity_hits,
+            exact_hits,
+        );
+        Self::apply_payout(env, room_id, room, jackpot, &result, room.total_sum);
+        if result.jackpot_won > 0 {
+            let mut hitters = Vec::new(env);
+            for (seat, p) in room.players.iter().enumerate() {
+                if exact_hits & (1 << seat) != 0 {
+                    hitters.push_back(p.address);
+                }
+            }
+            JackpotWon {
+                room_id,
+                token: room.token.clone(),
+                amount: result.jackpot_won,
+                winners: hitters,
+            }
+            .publish(env);
+        }
+
+        // A prize shared by everyone, or by nobody, is a draw for the hub
+        let outcome = if winners.is_empty() || winners.len() == room.players.len() {
+            GameOutcome::Draw
+        } else {
+            GameOutcome::Win(winners.clone())
+        };
+        room.status = RoomStatus::Settled;
+        Self::record_result(env, room, winners, outcome)?;
+
+        Self::archive_room(env, room_id, room);
+        Ok(())
+    }
+
+    /// Credits the fee to the treasury, stores the new jackpot, transfers
+    /// each seat's share and publishes `Resolved`. `jackpot` is the jackpot
+    /// before this payout.
+    fn apply_payout(
+        env: &Env,
+        room_id: u64,
+        room: &Room,
+        jackpot: i128,
+        result: &payout::Payout,
+        total_sum: Option<u32>,
+    ) {
+        if result.fee > 0 {
+            let key = DataKey::Treasury(room.token.clone());
+            let treasury: i128 = env.storage().instance().get(&key).unwrap_or(0);
+            env.storage().instance().set(&key, &(treasury + result.fee));
+        }
+        Self::store_jackpot(env, &room.token, result.jackpot);
+
+        let token = token::Client::new(env, &room.token);
+        let mut payouts = Vec::new(env);
+        for (p, share) in room.players.iter().zip(result.shares) {
+            if share > 0 {
+                token.transfer(&env.current_contract_address(), &p.address, &share);
+            }
+            payouts.push_back(share);
+        }
+
+        Resolved {
+            room_id,
+            total_sum,
+            payouts,
+            fee: result.fee,
+            jackpot_delta: result.jackpot - jackpot,
+        }
+        .publish(env);
+    }
+
+    fn refund_all(env: &Env, room: &Room) {
+        let token = token::Client::new(env, &room.token);
+        for p in room.players.iter() {
+            token.transfer(&env.current_contract_address(), &p.address, &room.bet_amount);
+        }
+    }
+
+    fn load_jackpot(env: &Env, token: &Address) -> i128 {
+        let s = env.storage().instance();
+        if let Some(jackpot) = s.get(&DataKey::Jackpot(token.clone())) {
+            return jackpot;
+        }
+        if s.get::<_, Address>(&DataKey::XlmToken).as_ref() == Some(token) {
+            return s.get(&DataKey::GlobalJackpot).unwrap_or(0);
+        }
+        0
+    }
+
+    fn store_jackpot(env: &Env, token: &Address, jackpot: i128) {
+        let s = env.storage().instance();
+        s.set(&DataKey::Jackpot(token.clone()), &jackpot);
+        if s.get::<_, Address>(&DataKey::XlmToken).as_ref() == Some(token) {
+            s.remove(&DataKey::GlobalJackpot);
+        }
+    }
+
+    /// Stores `winners` on the room and reports `outcome` to the hub. In a
+    /// two-seat room a single winner is a win for that seat; none or both is
+    /// a draw.
+    fn record_result(
+        env: &Env,
+        room: &mut Room,
+        winners: Vec<Address>,
+        outcome: GameOutcome,
+    ) -> Result<(), Error> {
+        room.winner = if winners.len() == 1 { winners.first() } else { None };
+        room.winners = winners;
+
+        let game_hub_client = GameHubClient::new(env, &Self::get_game_hub(env)?);
+        game_hub_client.end_game_with_outcome(&env.current_contract_address(), &room.session_id, &outcome);
+
+        if room.seats == 2 {
+            room.outcome = match &room.winner {
+                Some(w) if *w == room.players.get_unchecked(0).address => Outcome::P1Win,
+                Some(_) => Outcome::P2Win,
+                None => Outcome::Draw,
+            };
+        }
+        Ok(())
+    }
+
+    /// Whether seat `seat` has done its part of the current phase:
+    /// committing until everyone has, revealing afterwards.
+    fn has_acted(room: &Room, seat: u32) -> bool {
+        let state = room.players.get_unchecked(seat);
+        if Self::all_committed(room) { state.has_revealed } else { state.has_committed }
+    }
+
+    fn active_players(env: &Env, room: &Room) -> Vec<Address> {
+        let mut active = Vec::new(env);
+        for (seat, p) in room.players.iter().enumerate() {
+            if Self::has_acted(room, seat as u32) {
+                active.push_back(p.address);
+            }
+        }
+        active
+    }
+
+    fn all_committed(room: &Room) -> bool {
+        room.players.iter().all(|p| p.has_committed)
+    }
+
+    fn seat_of(room: &Room, player: &Address) -> Result<u32, Error> {
+        room.players
+            .iter()
+            .position(|p| p.address == *player)
+            .map(|i| i as u32)
+            .ok_or(Error::NotPlayer)
+    }
+
+    fn empty_seat(env: &Env, address: Address) -> PlayerState {
+        PlayerState {
+            address,
+            commitment: BytesN::from_array(env, &[0; 32]),
+            has_committed: false,
+            parity_guess: 0,
+            exact_sum_guess: 0,
+            has_revealed: false,
+            hand: 0,
+            score: 0,
+            exact_hits: 0,
+        }
+    }
+
+    /// Two-seat rooms: `[h1, h2, parity1, parity2, exact1, exact2, total_sum]`.
+    /// Larger rooms follow the multi circuit: `[seats, h[6], parity[6],
+    /// exact[6], total_sum]` with empty seats zeroed.
+    fn public_inputs(env: &Env, room: &Room, total_sum: u32) -> Vec<BytesN<32>> {
+        let mut inputs: Vec<BytesN<32>> = Vec::new(env);
+        let width = if room.seats == 2 {
+            2
+        } else {
+            inputs.push_back(Self::u32_to_bytes32(env, room.seats));
+            MAX_SEATS
+        };
+        let zero = BytesN::from_array(env, &[0; 32]);
+        for i in 0..width {
+            inputs.push_back(room.players.get(i).map(|p| p.commitment).unwrap_or(zero.clone()));
+        }
+        for i in 0..width {
+            let parity = room.players.get(i).map(|p| p.parity_guess).unwrap_or(0);
+            inputs.push_back(Self::u32_to_bytes32(env, parity));
+        }
+        for i in 0..width {
+            let exact = room.players.get(i).map(|p| p.exact_sum_guess).unwrap_or(0);
+            inputs.push_back(Self::u32_to_bytes32(env, exact));
+        }
+        inputs.push_back(Self::u32_to_bytes32(env, total_sum));
+        inputs
     }
 
     fn u32_to_bytes32(env: &Env, val: u32) -> BytesN<32> {
@@ -366,29 +1233,220 @@ impl ZkPorrinhaContract {
         BytesN::from_array(env, &b)
     }
 
+    /// Open rooms live in persistent storage; archived rooms (and rooms
+    /// created before the move to persistent storage) in temporary storage.
     fn load_room(env: &Env, room_id: u64) -> Result<Room, Error> {
+        let key = DataKey::Room(room_id);
         env.storage()
-            .temporary()
-            .get(&DataKey::Room(room_id))
+            .persistent()
+            .get(&key)
+            .or_else(|| env.storage().temporary().get(&key))
             .ok_or(Error::RoomNotFound)
     }
 
     fn save_room(env: &Env, room_id: u64, room: &Room) {
         let key = DataKey::Room(room_id);
+        env.storage().persistent().set(&key, room);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_LEDGERS, TTL_LEDGERS);
+        env.storage().temporary().remove(&key);
+    }
+
+    /// Room indexes are persistent lists of ids, renewed on every write. Ids
+    /// are split into buckets of `ROOM_INDEX_BUCKET`, one entry each, next to
+    /// a list of the buckets in use; emptied entries are removed.
+    fn load_room_index(env: &Env, key: &DataKey) -> Vec<u64> {
+        env.storage().persistent().get(key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn save_room_index(env: &Env, key: &DataKey, ids: &Vec<u64>) {
+        if ids.is_empty() {
+            env.storage().persistent().remove(key);
+            return;
+        }
+        env.storage().persistent().set(key, ids);
+        env.storage().persistent().extend_ttl(key, TTL_LEDGERS, TTL_LEDGERS);
+    }
+
+    fn index_room(env: &Env, buckets_key: &DataKey, bucket_key: impl Fn(u64) -> DataKey, room_id: u64) {
+        let bucket = room_id / ROOM_INDEX_BUCKET;
+        let key = bucket_key(bucket);
+        let mut ids = Self::load_room_index(env, &key);
+        let Err(i) = ids.binary_search(room_id) else { return };
+        ids.insert(i, room_id);
+        Self::save_room_index(env, &key, &ids);
+
+        let mut buckets = Self::load_room_index(env, buckets_key);
+        if let Err(i) = buckets.binary_search(bucket) {
+            buckets.insert(i, bucket);
+            Self::save_room_index(env, buckets_key, &buckets);
+        }
+    }
+
+    fn unindex_room(env: &Env, buckets_key: &DataKey, bucket_key: impl Fn(u64) -> DataKey, room_id: u64) {
+        let bucket = room_id / ROOM_INDEX_BUCKET;
+        let key = bucket_key(bucket);
+        let mut ids = Self::load_room_index(env, &key);
+        let Ok(i) = ids.binary_search(room_id) else { return };
+        ids.remove(i);
+        Self::save_room_index(env, &key, &ids);
+
+        if ids.is_empty() {
+            let mut buckets = Self::load_room_index(env, buckets_key);
+            if let Ok(i) = buckets.binary_search(bucket) {
+                buckets.remove(i);
+                Self::save_room_index(env, buckets_key, &buckets);
+            }
+        }
+    }
+
+    /// Feeds the indexed ids above `cursor` to `visit` in ascending order
+    /// until it returns false, and tells whether it did. Only buckets that
+    /// can hold such ids are read.
+    fn scan_room_index(
+        env: &Env,
+        buckets_key: &DataKey,
+        bucket_key: impl Fn(u64) -> DataKey,
+        cursor: u64,
+        mut visit: impl FnMut(u64) -> bool,
+    ) -> bool {
+        let first = cursor / ROOM_INDEX_BUCKET;
+        for bucket in Self::load_room_index(env, buckets_key).iter() {
+            if bucket < first {
+                continue;
+            }
+            for room_id in Self::load_room_index(env, &bucket_key(bucket)).iter() {
+                if room_id > cursor && !visit(room_id) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn index_open_room(env: &Env, room_id: u64) {
+        Self::index_room(env, &DataKey::OpenRoomBuckets, DataKey::OpenRooms, room_id);
+    }
+
+    fn unindex_open_room(env: &Env, room_id: u64) {
+        Self::unindex_room(env, &DataKey::OpenRoomBuckets, DataKey::OpenRooms, room_id);
+    }
+
+    fn index_player_room(env: &Env, player: &Address, room_id: u64) {
+        let buckets_key = DataKey::PlayerRoomBuckets(player.clone());
+        Self::index_room(env, &buckets_key, |b| DataKey::PlayerRooms(player.clone(), b), room_id);
+    }
+
+    fn unindex_player_room(env: &Env, player: &Address, room_id: u64) {
+        let buckets_key = DataKey::PlayerRoomBuckets(player.clone());
+        Self::unindex_room(env, &buckets_key, |b| DataKey::PlayerRooms(player.clone(), b), room_id);
+    }
+
+    /// Drops up to `MAX_EXPIRED_PRUNE` expired lobbies from the open room
+    /// index. Lobby deadlines grow with the room id, so the expired ones are
+    /// at its front. They stay refundable through `refund_room`.
+    fn prune_expired_lobbies(env: &Env) {
+        let now = env.ledger().sequence();
+        let mut expired = Vec::new(env);
+        Self::scan_room_index(env, &DataKey::OpenRoomBuckets, DataKey::OpenRooms, 0, |room_id| {
+            if Self::load_room(env, room_id).is_ok_and(|room| now <= room.deadline) {
+                return false;
+            }
+            expired.push_back(room_id);
+            expired.len() < MAX_EXPIRED_PRUNE
+        });
+        for room_id in expired.iter() {
+            Self::unindex_open_room(env, room_id);
+        }
+    }
+
+    /// What a `Key` room's invite key signs to admit `player`.
+    fn invite_message(env: &Env, room_id: u64, player: &Address) -> Bytes {
+        let mut message = Bytes::from_array(env, &room_id.to_be_bytes());
+        message.append(&player.clone().to_xdr(env));
+        message
+    }
+
+    /// `sha256(room_id ‖ session_id ‖ round ‖ commitment_1 ‖ … ‖
+    /// commitment_n)` in seat order, integers big-endian.
+    fn room_nullifier(env: &Env, room_id: u64, room: &Room) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.extend_from_array(&room_id.to_be_bytes());
+        data.extend_from_array(&room.session_id.to_be_bytes());
+        data.extend_from_array(&room.round.to_be_bytes());
+        for p in room.players.iter() {
+            data.append(&p.commitment.into());
+        }
+        env.crypto().sha256(&data).into()
+    }
+
+    /// Moves a finished room out of persistent storage and out of its
+    /// players' indexes. It stays readable through `get_room` for
+    /// `ARCHIVE_TTL_LEDGERS` and is then dropped.
+    fn archive_room(env: &Env, room_id: u64, room: &Room) {
+        for p in room.players.iter() {
+            Self::unindex_player_room(env, &p.address, room_id);
+        }
+        let key = DataKey::Room(room_id);
+        env.storage().persistent().remove(&key);
         env.storage().temporary().set(&key, room);
         env.storage()
             .temporary()
+            .extend_ttl(&key, ARCHIVE_TTL_LEDGERS, ARCHIVE_TTL_LEDGERS);
+    }
+
+    /// Nullifiers are persistent entries, one per key. Their TTL is renewed
+    /// whenever they are checked; once archived by the network, reading one
+    /// fails the transaction until restored, so an archived nullifier can
+    /// never be reused either.
+    fn is_nullifier_used(env: &Env, nullifier: &BytesN<32>) -> bool {
+        let key = DataKey::Nullifier(nullifier.clone());
+        if env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_LEDGERS, TTL_LEDGERS);
+            return true;
+        }
+        env.storage().instance().has(&key)
+    }
+
+    fn mark_nullifier(env: &Env, nullifier: &BytesN<32>) {
+        let key = DataKey::Nullifier(nullifier.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
             .extend_ttl(&key, TTL_LEDGERS, TTL_LEDGERS);
     }
 
-    fn get_xlm_token(env: &Env) -> Result<Address, Error> {
-        env.storage().instance().get(&DataKey::XlmToken).ok_or(Error::XlmTokenNotSet)
+    /// Deployments from before the allowlist only know `XlmToken`.
+    fn load_allowed_tokens(env: &Env) -> Vec<Address> {
+        let s = env.storage().instance();
+        s.get(&DataKey::AllowedTokens).unwrap_or_else(|| {
+            let mut tokens = Vec::new(env);
+            if let Some(xlm) = s.get::<_, Address>(&DataKey::XlmToken) {
+                tokens.push_back(xlm);
+            }
+            tokens
+        })
     }
     fn get_verifier(env: &Env) -> Result<Address, Error> {
         env.storage().instance().get(&DataKey::Verifier).ok_or(Error::VerifierNotSet)
     }
+    fn load_vk_hash(env: &Env) -> Result<BytesN<32>, Error> {
+        env.storage().instance().get(&DataKey::VkHash).ok_or(Error::VkHashNotSet)
+    }
+    fn load_fee_terms(env: &Env) -> FeeTerms {
+        env.storage()
+            .instance()
+            .get(&DataKey::FeeTerms)
+            .unwrap_or(FeeTerms { fee_bps: 0, parity_bps: DEFAULT_PARITY_BPS })
+    }
+    fn load_multi_vk_hash(env: &Env) -> Result<BytesN<32>, Error> {
+        env.storage().instance().get(&DataKey::MultiVkHash).ok_or(Error::VkHashNotSet)
+    }
     fn get_game_hub(env: &Env) -> Result<Address, Error> {
-        env.storage().instance().get(&DataKey::GameHub).ok_or(Error::GameHubNotSet)
+        admin::hub_address(env).ok_or(Error::GameHubNotSet)
     }
 
     fn bump_instance(env: &Env) {
@@ -398,5 +1456,9 @@ impl ZkPorrinhaContract {
     }
 }
 
+/// `get_admin`, `set_admin`, `get_hub`, `set_hub` and `upgrade`.
+#[contractimpl(contracttrait)]
+impl GameAdmin for ZkPorrinhaContract {}
+
 #[cfg(test)]
 mod test;
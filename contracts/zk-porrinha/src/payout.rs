@@ -0,0 +1,86 @@
+//! Pot distribution for a room's escrow.
+//!
+//! All amounts are integer stroops of the room token. Every result conserves
+//! funds exactly: `sum(shares) + (jackpot_after - jackpot_before) + fee == pot`.
+//! When an amount does not divide evenly, the remainder goes to the first
+//! recipient in seat order.
+//!
+//! Players are addressed by seat index; sets of players are bitmasks where
+//! bit `i` is seat `i`.
+
+use crate::{FeeTerms, BPS_DENOMINATOR, MAX_SEATS};
+
+pub const SEATS: usize = MAX_SEATS as usize;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payout {
+    /// Credited to the token's treasury.
+    pub fee: i128,
+    /// Paid to each seat.
+    pub shares: [i128; SEATS],
+    /// The token's jackpot after this payout.
+    pub jackpot: i128,
+    /// Part of `shares` taken out of the jackpot by exact-sum hitters.
+    pub jackpot_won: i128,
+}
+
+pub fn bps(amount: i128, bps: u32) -> i128 {
+    amount * bps as i128 / BPS_DENOMINATOR as i128
+}
+
+/// Every seat of a `seats`-seat room.
+pub fn everyone(seats: u32) -> u32 {
+    (1u32 << seats) - 1
+}
+
+/// Result of a settled round. The fee comes off the top; `parity_bps` of the
+/// rest is split among `parity_hits` (every seat if empty) and the remainder
+/// joins the jackpot, which `exact_hits` then split and empty.
+pub fn settle(
+    pot: i128,
+    jackpot: i128,
+    terms: &FeeTerms,
+    seats: u32,
+    parity_hits: u32,
+    exact_hits: u32,
+) -> Payout {
+    let fee = bps(pot, terms.fee_bps);
+    let net = pot - fee;
+    let parity_pool = bps(net, terms.parity_bps);
+    let mut jackpot = jackpot + (net - parity_pool);
+
+    let mut shares = [0i128; SEATS];
+    let parity_recipients = if parity_hits == 0 { everyone(seats) } else { parity_hits };
+    split(&mut shares, parity_pool, parity_recipients);
+    let mut jackpot_won = 0;
+    if exact_hits != 0 {
+        split(&mut shares, jackpot, exact_hits);
+        jackpot_won = jackpot;
+        jackpot = 0;
+    }
+
+    Payout { fee, shares, jackpot, jackpot_won }
+}
+
+/// Result of a timeout claim: the pot less the fee is split among `active`.
+/// The jackpot is untouched.
+pub fn forfeit(pot: i128, jackpot: i128, terms: &FeeTerms, active: u32) -> Payout {
+    let fee = bps(pot, terms.fee_bps);
+    let mut shares = [0i128; SEATS];
+    split(&mut shares, pot - fee, active);
+    Payout { fee, shares, jackpot, jackpot_won: 0 }
+}
+
+/// Adds an even split of `amount` over `recipients` to `shares`, the first
+/// recipient also taking the remainder. `recipients` must not be empty.
+fn split(shares: &mut [i128; SEATS], amount: i128, recipients: u32) {
+    let count = recipients.count_ones() as i128;
+    let share = amount / count;
+    let mut remainder = amount - share * count;
+    for (seat, slot) in shares.iter_mut().enumerate() {
+        if recipients & (1 << seat) != 0 {
+            *slot += share + remainder;
+            remainder = 0;
+        }
+    }
+}
@@ -1,8 +1,15 @@
 #![cfg(test)]
 
-use crate::{ZkPorrinhaContract, ZkPorrinhaContractClient, RoomStatus, TTL_LEDGERS};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use crate::{
+    payout, Committed, DataKey, Error, FeeTerms, GameOutcome, JackpotWon, Outcome, Resolved, RoomAccess,
+    RoomCancelled, RoomCreated, RoomJoined, RoomStatus, ZkPorrinhaContract, ZkPorrinhaContractClient,
+    COMMIT_TIMEOUT_LEDGERS, LOBBY_TIMEOUT_LEDGERS, MAX_SCAN, MAX_SEATS, MIN_SEATS, RESOLVE_TIMEOUT_LEDGERS,
+    REVEAL_TIMEOUT_LEDGERS, ROOM_INDEX_BUCKET, TTL_LEDGERS,
+};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use ed25519_dalek::{Signer as _, SigningKey};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Event as _, Vec};
 
 // ── Mock contracts ────────────────────────────────────────────────────────────
 
@@ -11,19 +18,73 @@ pub struct MockGameHub;
 #[contractimpl]
 impl MockGameHub {
     pub fn start_game(
-        _env: Env, _game_id: Address, _session_id: u32,
-        _player1: Address, _player2: Address, _p1: i128, _p2: i128,
-    ) {}
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
+        env: Env, _game_id: Address, session_id: u32,
+        player1: Address, player2: Address, _p1: i128, _p2: i128,
+    ) {
+        env.storage().instance().set(&(session_id, 0u32), &Vec::from_array(&env, [player1, player2]));
+    }
+    pub fn start_multiplayer_game(
+        env: Env, _game_id: Address, session_id: u32, players: Vec<Address>, _points: Vec<i128>,
+    ) {
+        env.storage().instance().set(&(session_id, 0u32), &players);
+    }
+    pub fn end_game_with_outcome(env: Env, _game_id: Address, session_id: u32, outcome: GameOutcome) {
+        env.storage().instance().set(&(session_id, 1u32), &outcome);
+    }
+    pub fn outcome(env: Env, session_id: u32) -> GameOutcome {
+        env.storage().instance().get(&(session_id, 1u32)).unwrap()
+    }
+    pub fn ended(env: Env, session_id: u32) -> bool {
+        env.storage().instance().has(&(session_id, 1u32))
+    }
+    pub fn players(env: Env, session_id: u32) -> Option<Vec<Address>> {
+        env.storage().instance().get(&(session_id, 0u32))
+    }
+    /// Players who won an ended session: the listed winners, or everyone
+    /// but the forfeiters; empty for a draw or cancellation.
+    pub fn winners(env: Env, session_id: u32) -> Option<Vec<Address>> {
+        if !Self::ended(env.clone(), session_id) {
+            return None;
+        }
+        let winners = match Self::outcome(env.clone(), session_id) {
+            GameOutcome::Win(winners) => winners,
+            GameOutcome::Forfeit(quitters) => {
+                let mut winners = Vec::new(&env);
+                for p in Self::players(env.clone(), session_id).unwrap().iter() {
+                    if !quitters.contains(&p) {
+                        winners.push_back(p);
+                    }
+                }
+                winners
+            }
+            GameOutcome::Draw | GameOutcome::Cancelled => Vec::new(&env),
+        };
+        Some(winners)
+    }
+    /// Whether player1 won a two-player session; `None` without a sole winner.
+    pub fn result(env: Env, session_id: u32) -> Option<bool> {
+        let winners = Self::winners(env.clone(), session_id)?;
+        if winners.len() != 1 {
+            return None;
+        }
+        let players = Self::players(env, session_id)?;
+        Some(winners.get_unchecked(0) == players.get_unchecked(0))
+    }
+    pub fn drawn(env: Env, session_id: u32) -> bool {
+        Self::ended(env.clone(), session_id) && Self::outcome(env, session_id) == GameOutcome::Draw
+    }
 }
 
-/// Accepts any non-empty proof with >= 2 valid 32-byte public inputs.
+const VK_HASH: [u8; 32] = [0x5au8; 32];
+
+/// Accepts any non-empty proof with >= 2 valid 32-byte public inputs,
+/// as long as it is checked against `VK_HASH`.
 #[contract]
 pub struct MockVerifier;
 #[contractimpl]
 impl MockVerifier {
-    pub fn verify(_env: Env, proof: Bytes, public_inputs: Vec<BytesN<32>>, _vk_hash: BytesN<32>) -> bool {
-        proof.len() > 0 && public_inputs.len() >= 2
+    pub fn verify(env: Env, proof: Bytes, public_inputs: Vec<BytesN<32>>, vk_hash: BytesN<32>) -> bool {
+        !proof.is_empty() && public_inputs.len() >= 2 && vk_hash == BytesN::from_array(&env, &VK_HASH)
     }
 }
 
@@ -35,12 +96,19 @@ impl MockVerifierReject {
     pub fn verify(_env: Env, _proof: Bytes, _pi: Vec<BytesN<32>>, _vk_hash: BytesN<32>) -> bool { false }
 }
 
-/// Silently succeeds token transfers.
+/// Succeeds every transfer and tracks net balances (which may go negative).
 #[contract]
 pub struct MockToken;
 #[contractimpl]
 impl MockToken {
-    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let s = env.storage().instance();
+        s.set(&from, &(s.get::<_, i128>(&from).unwrap_or(0) - amount));
+        s.set(&to, &(s.get::<_, i128>(&to).unwrap_or(0) + amount));
+    }
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().instance().get(&id).unwrap_or(0)
+    }
 }
 
 /// Always panics on transfer.
@@ -77,7 +145,8 @@ fn setup() -> (Env, ZkPorrinhaContractClient<'static>, Address, Address) {
     let verifier = env.register(MockVerifier, ());
     let token    = env.register(MockToken, ());
     let admin    = Address::generate(&env);
-    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &token));
+    let vk_hash  = BytesN::from_array(&env, &VK_HASH);
+    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &vk_hash, &token));
     let client = ZkPorrinhaContractClient::new(&env, &cid);
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
@@ -92,7 +161,8 @@ fn setup_reject() -> (Env, ZkPorrinhaContractClient<'static>, Address, Address)
     let verifier = env.register(MockVerifierReject, ());
     let token    = env.register(MockToken, ());
     let admin    = Address::generate(&env);
-    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &token));
+    let vk_hash  = BytesN::from_array(&env, &VK_HASH);
+    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &vk_hash, &token));
     let client = ZkPorrinhaContractClient::new(&env, &cid);
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
@@ -107,13 +177,20 @@ fn setup_token_fail() -> (Env, ZkPorrinhaContractClient<'static>, Address, Addre
     let verifier = env.register(MockVerifier, ());
     let token    = env.register(MockTokenFail, ());
     let admin    = Address::generate(&env);
-    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &token));
+    let vk_hash  = BytesN::from_array(&env, &VK_HASH);
+    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &vk_hash, &token));
     let client = ZkPorrinhaContractClient::new(&env, &cid);
     let p1 = Address::generate(&env);
     let p2 = Address::generate(&env);
     (env, client, p1, p2)
 }
 
+/// The token every setup deploys with, allowlisted by the constructor.
+fn xlm(client: &ZkPorrinhaContractClient) -> Address {
+    client.get_allowed_tokens().get_unchecked(0)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn do_full_game(
     env: &Env,
     client: &ZkPorrinhaContractClient,
@@ -125,17 +202,15 @@ fn do_full_game(
     p2_parity: u32,
     p2_exact: u32,
     total_sum: u32,
-    nullifier_byte: u8,
 ) -> crate::Room {
-    let id = client.create_room(p1, &bet);
-    client.join_room(&id, p2);
+    let id = client.create_room(p1, &xlm(client), &bet, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, p2, &None);
     let c1 = BytesN::from_array(env, &[0x11u8; 32]);
     let c2 = BytesN::from_array(env, &[0x22u8; 32]);
     client.commit(&id, p1, &c1, &p1_parity, &p1_exact);
     client.commit(&id, p2, &c2, &p2_parity, &p2_exact);
-    let proof     = Bytes::from_array(env, &[0xAAu8; 200]);
-    let nullifier = BytesN::from_array(env, &[nullifier_byte; 32]);
-    client.resolve(&id, &proof, &total_sum, &nullifier);
+    let proof = Bytes::from_array(env, &[0xAAu8; 200]);
+    client.resolve(&id, &proof, &total_sum);
     client.get_room(&id)
 }
 
@@ -144,25 +219,26 @@ fn do_full_game(
 #[test]
 fn test_initial_jackpot_is_zero() {
     let (_, client, _, _) = setup();
-    assert_eq!(client.get_jackpot(), 0i128);
+    assert_eq!(client.get_jackpot(&xlm(&client)), 0i128);
 }
 
 #[test]
 fn test_create_room_counter() {
     let (_, client, p1, p2) = setup();
     assert_eq!(client.get_room_count(), 0u64);
-    assert_eq!(client.create_room(&p1, &1_000i128), 1u64);
-    assert_eq!(client.create_room(&p2, &1_000i128), 2u64);
+    assert_eq!(client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1), 1u64);
+    assert_eq!(client.create_room(&p2, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1), 2u64);
     assert_eq!(client.get_room_count(), 2u64);
 }
 
 #[test]
 fn test_create_room_state() {
     let (_, client, p1, _) = setup();
-    let id = client.create_room(&p1, &500i128);
+    let id = client.create_room(&p1, &xlm(&client), &500i128, &2u32, &RoomAccess::Public, &1);
     let room = client.get_room(&id);
     assert_eq!(room.status, RoomStatus::Lobby);
-    assert!(!room.has_player2);
+    assert_eq!(room.players.len(), 1);
+    assert_eq!(room.seats, 2);
     assert_eq!(room.bet_amount, 500i128);
     assert!(room.winner.is_none());
 }
@@ -170,61 +246,64 @@ fn test_create_room_state() {
 #[test]
 fn test_join_transitions_to_commit() {
     let (_, client, p1, p2) = setup();
-    let id = client.create_room(&p1, &1_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     let room = client.get_room(&id);
-    assert!(room.has_player2);
+    assert_eq!(room.players.len(), 2);
     assert_eq!(room.status, RoomStatus::Commit);
-    assert_eq!(room.player2.address, p2);
+    assert_eq!(room.players.get_unchecked(1).address, p2);
 }
 
 #[test]
 fn test_room_pot_one_player() {
     let (_, client, p1, _) = setup();
-    let id = client.create_room(&p1, &2_000i128);
+    let id = client.create_room(&p1, &xlm(&client), &2_000i128, &2u32, &RoomAccess::Public, &1);
     assert_eq!(client.get_room_pot(&id), 2_000i128);
 }
 
 #[test]
 fn test_room_pot_two_players() {
     let (_, client, p1, p2) = setup();
-    let id = client.create_room(&p1, &2_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &2_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     assert_eq!(client.get_room_pot(&id), 4_000i128);
 }
 
 #[test]
-fn test_vk_hash_file_consistency() {
-    use sha2::{Digest, Sha256};
-    let bytes: &[u8] = include_bytes!("../../../circuits/zk-porrinha/target/vk");
-    let mut hasher = Sha256::new();
-    hasher.update(bytes);
-    let result = hasher.finalize();
-    let mut arr = [0u8; 32];
-    arr.copy_from_slice(&result);
-    assert_eq!(arr, crate::VK_HASH, "VK file hash does not match contract constant");
+fn test_vk_hash_from_config() {
+    let (env, client, _, _) = setup();
+    assert_eq!(client.get_vk_hash(), BytesN::from_array(&env, &VK_HASH));
+}
+
+#[test]
+#[should_panic]
+fn test_resolve_uses_configured_vk_hash() {
+    let (env, client, p1, p2) = setup();
+    client.set_vk_hash(&BytesN::from_array(&env, &[0x01u8; 32]));
+    do_full_game(&env, &client, &p1, &p2, 1_000, 0, 0, 0, 0, 2);
 }
 
 #[test]
 fn test_commit_records_values() {
     let (env, client, p1, p2) = setup();
-    let id = client.create_room(&p1, &1_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     let c = BytesN::from_array(&env, &[0xABu8; 32]);
     client.commit(&id, &p1, &c, &1u32, &3u32);
     let room = client.get_room(&id);
-    assert!(room.player1.has_committed);
-    assert_eq!(room.player1.commitment, c);
-    assert_eq!(room.player1.parity_guess, 1u32);
-    assert_eq!(room.player1.exact_sum_guess, 3u32);
-    assert!(!room.player2.has_committed);
+    let state = room.players.get_unchecked(0);
+    assert!(state.has_committed);
+    assert_eq!(state.commitment, c);
+    assert_eq!(state.parity_guess, 1u32);
+    assert_eq!(state.exact_sum_guess, 3u32);
+    assert!(!room.players.get_unchecked(1).has_committed);
 }
 
 #[test]
 fn test_full_game_p1_wins_parity() {
     // total_sum=3 odd; p1 guesses odd, p2 guesses even
     let (env, client, p1, p2) = setup();
-    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 0, 3, 0xBB);
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 0, 3);
     assert_eq!(room.status, RoomStatus::Settled);
     assert_eq!(room.winner, Some(p1));
     assert_eq!(room.total_sum, Some(3u32));
@@ -234,7 +313,7 @@ fn test_full_game_p1_wins_parity() {
 fn test_full_game_p2_wins_parity() {
     // total_sum=4 even; p2 guesses even
     let (env, client, p1, p2) = setup();
-    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 5, 4, 0xCC);
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 5, 4);
     assert_eq!(room.winner, Some(p2));
 }
 
@@ -242,26 +321,26 @@ fn test_full_game_p2_wins_parity() {
 fn test_exact_hit_p1_wins_jackpot() {
     // total_sum=3; p1 guesses exact=3
     let (env, client, p1, p2) = setup();
-    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 3, 0, 0, 3, 0xDD);
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 3, 0, 0, 3);
     assert_eq!(room.winner, Some(p1));
-    assert_eq!(client.get_jackpot(), 0i128); // paid out
+    assert_eq!(client.get_jackpot(&xlm(&client)), 0i128); // paid out
 }
 
 #[test]
 fn test_exact_hit_p2_wins_jackpot() {
     let (env, client, p1, p2) = setup();
-    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 4, 4, 0xEE);
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 0, 0, 4, 4);
     assert_eq!(room.winner, Some(p2));
-    assert_eq!(client.get_jackpot(), 0i128);
+    assert_eq!(client.get_jackpot(&xlm(&client)), 0i128);
 }
 
 #[test]
 fn test_no_exact_jackpot_accumulates() {
     let (env, client, p1, p2) = setup();
     let bet = 1_000i128;
-    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3, 0xAA);
+    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3);
     let expected = (bet * 2 * 20) / 100;
-    assert_eq!(client.get_jackpot(), expected);
+    assert_eq!(client.get_jackpot(&xlm(&client)), expected);
 }
 
 #[test]
@@ -269,10 +348,10 @@ fn test_jackpot_grows_two_rounds() {
     let (env, client, p1, p2) = setup();
     let bet = 1_000i128;
     let contribution = (bet * 2 * 20) / 100;
-    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3, 0x01);
-    assert_eq!(client.get_jackpot(), contribution);
-    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3, 0x02);
-    assert_eq!(client.get_jackpot(), contribution * 2);
+    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3);
+    assert_eq!(client.get_jackpot(&xlm(&client)), contribution);
+    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3);
+    assert_eq!(client.get_jackpot(&xlm(&client)), contribution * 2);
 }
 
 #[test]
@@ -281,17 +360,17 @@ fn test_jackpot_resets_after_exact_hit() {
     let bet = 1_000i128;
     let contribution = (bet * 2 * 20) / 100;
     // Round 1 accumulates
-    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3, 0x10);
-    assert_eq!(client.get_jackpot(), contribution);
+    do_full_game(&env, &client, &p1, &p2, bet, 1, 0, 0, 6, 3);
+    assert_eq!(client.get_jackpot(&xlm(&client)), contribution);
     // Round 2: p1 hits exact
-    do_full_game(&env, &client, &p1, &p2, bet, 1, 5, 0, 0, 5, 0x20);
-    assert_eq!(client.get_jackpot(), 0i128);
+    do_full_game(&env, &client, &p1, &p2, bet, 1, 5, 0, 0, 5);
+    assert_eq!(client.get_jackpot(&xlm(&client)), 0i128);
 }
 
 #[test]
 fn test_winner_is_set_after_settle() {
     let (env, client, p1, p2) = setup();
-    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 3, 0, 0, 3, 0xFF);
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 3, 0, 0, 3);
     assert!(room.winner.is_some());
 }
 
@@ -301,15 +380,15 @@ fn test_winner_is_set_after_settle() {
 #[should_panic]
 fn test_zero_bet_fails() {
     let (_, client, p1, _) = setup();
-    client.create_room(&p1, &0i128);
+    client.create_room(&p1, &xlm(&client), &0i128, &2u32, &RoomAccess::Public, &1);
 }
 
 #[test]
 #[should_panic]
 fn test_self_play_fails() {
     let (_, client, p1, _) = setup();
-    let id = client.create_room(&p1, &500i128);
-    client.join_room(&id, &p1);
+    let id = client.create_room(&p1, &xlm(&client), &500i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p1, &None);
 }
 
 #[test]
@@ -317,17 +396,17 @@ fn test_self_play_fails() {
 fn test_join_full_room_fails() {
     let (env, client, p1, p2) = setup();
     let p3 = Address::generate(&env);
-    let id = client.create_room(&p1, &500i128);
-    client.join_room(&id, &p2);
-    client.join_room(&id, &p3);
+    let id = client.create_room(&p1, &xlm(&client), &500i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    client.join_room(&id, &p3, &None);
 }
 
 #[test]
 #[should_panic]
 fn test_commit_twice_fails() {
     let (env, client, p1, p2) = setup();
-    let id = client.create_room(&p1, &1_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     let c = BytesN::from_array(&env, &[0x11u8; 32]);
     client.commit(&id, &p1, &c, &0u32, &0u32);
     client.commit(&id, &p1, &c, &0u32, &0u32);
@@ -337,7 +416,7 @@ fn test_commit_twice_fails() {
 #[should_panic]
 fn test_commit_in_lobby_fails() {
     let (env, client, p1, _) = setup();
-    let id = client.create_room(&p1, &500i128);
+    let id = client.create_room(&p1, &xlm(&client), &500i128, &2u32, &RoomAccess::Public, &1);
     let c = BytesN::from_array(&env, &[0x11u8; 32]);
     client.commit(&id, &p1, &c, &0u32, &0u32);
 }
@@ -346,52 +425,79 @@ fn test_commit_in_lobby_fails() {
 #[should_panic]
 fn test_bad_proof_rejected() {
     let (env, client, p1, p2) = setup_reject();
-    let id = client.create_room(&p1, &1_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     let c1 = BytesN::from_array(&env, &[0x11u8; 32]);
     let c2 = BytesN::from_array(&env, &[0x22u8; 32]);
     client.commit(&id, &p1, &c1, &0u32, &0u32);
     client.commit(&id, &p2, &c2, &0u32, &0u32);
-    let proof     = Bytes::from_array(&env, &[0xAAu8; 200]);
-    let nullifier = BytesN::from_array(&env, &[0xBBu8; 32]);
-    client.resolve(&id, &proof, &2u32, &nullifier);
+    let proof = Bytes::from_array(&env, &[0xAAu8; 200]);
+    client.resolve(&id, &proof, &2u32);
 }
 
 #[test]
-#[should_panic]
 fn test_nullifier_replay_fails() {
     let (env, client, p1, p2) = setup();
-    // Game 1
-    let id1 = client.create_room(&p1, &1_000i128);
-    client.join_room(&id1, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    client.commit(&id, &p1, &BytesN::from_array(&env, &[0x11u8; 32]), &0u32, &0u32);
+    client.commit(&id, &p2, &BytesN::from_array(&env, &[0x22u8; 32]), &0u32, &0u32);
+    let key = DataKey::Nullifier(client.get_nullifier(&id));
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(&key, &true);
+    });
+    let proof = Bytes::from_array(&env, &[0xAAu8; 200]);
+    assert_eq!(
+        client.try_resolve(&id, &proof, &2u32),
+        Err(Ok(Error::NullifierUsed))
+    );
+}
+
+#[test]
+fn test_nullifier_is_bound_to_room() {
+    let (env, client, p1, p2) = setup();
     let c1 = BytesN::from_array(&env, &[0x11u8; 32]);
     let c2 = BytesN::from_array(&env, &[0x22u8; 32]);
-    client.commit(&id1, &p1, &c1, &0u32, &0u32);
-    client.commit(&id1, &p2, &c2, &0u32, &0u32);
-    let proof     = Bytes::from_array(&env, &[0xAAu8; 200]);
-    let nullifier = BytesN::from_array(&env, &[0xBBu8; 32]);
-    client.resolve(&id1, &proof, &2u32, &nullifier);
-    // Game 2 with same nullifier
-    let id2 = client.create_room(&p1, &1_000i128);
-    client.join_room(&id2, &p2);
-    let c3 = BytesN::from_array(&env, &[0x33u8; 32]);
-    let c4 = BytesN::from_array(&env, &[0x44u8; 32]);
-    client.commit(&id2, &p1, &c3, &0u32, &0u32);
-    client.commit(&id2, &p2, &c4, &0u32, &0u32);
-    client.resolve(&id2, &proof, &2u32, &nullifier);
+    let mut ids = [0u64; 2];
+    for id in ids.iter_mut() {
+        *id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+        client.join_room(id, &p2, &None);
+        client.commit(id, &p1, &c1, &0u32, &0u32);
+        client.commit(id, &p2, &c2, &0u32, &0u32);
+    }
+    let n1 = client.get_nullifier(&ids[0]);
+    assert_eq!(n1, client.get_nullifier(&ids[0]));
+    assert_ne!(n1, client.get_nullifier(&ids[1]));
+
+    let mut data = Bytes::new(&env);
+    data.extend_from_array(&ids[0].to_be_bytes());
+    data.extend_from_array(&client.get_room(&ids[0]).session_id.to_be_bytes());
+    data.extend_from_array(&0u32.to_be_bytes());
+    data.append(&c1.clone().into());
+    data.append(&c2.clone().into());
+    let expected: BytesN<32> = env.crypto().sha256(&data).into();
+    assert_eq!(n1, expected);
+}
+
+#[test]
+#[should_panic]
+fn test_get_nullifier_before_commit_fails() {
+    let (_, client, p1, p2) = setup();
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    client.get_nullifier(&id);
 }
 
 #[test]
 #[should_panic]
 fn test_resolve_before_both_commit_fails() {
     let (env, client, p1, p2) = setup();
-    let id = client.create_room(&p1, &1_000i128);
-    client.join_room(&id, &p2);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
     let c1 = BytesN::from_array(&env, &[0x11u8; 32]);
     client.commit(&id, &p1, &c1, &0u32, &0u32);
-    let proof     = Bytes::from_array(&env, &[0xAAu8; 200]);
-    let nullifier = BytesN::from_array(&env, &[0xBBu8; 32]);
-    client.resolve(&id, &proof, &1u32, &nullifier);
+    let proof = Bytes::from_array(&env, &[0xAAu8; 200]);
+    client.resolve(&id, &proof, &1u32);
 }
 
 #[test]
@@ -405,5 +511,1496 @@ fn test_get_nonexistent_room_fails() {
 #[should_panic]
 fn test_token_failure_panics() {
     let (_, client, p1, _) = setup_token_fail();
-    client.create_room(&p1, &500i128);
+    client.create_room(&p1, &xlm(&client), &500i128, &2u32, &RoomAccess::Public, &1);
+}
+
+// ── Reveal mode ───────────────────────────────────────────────────────────────
+
+fn salt(env: &Env, v: u16) -> BytesN<32> {
+    let mut b = [0u8; 32];
+    b[30..].copy_from_slice(&v.to_be_bytes());
+    BytesN::from_array(env, &b)
+}
+
+fn hex32(env: &Env, hex: &str) -> BytesN<32> {
+    let mut b = [0u8; 32];
+    for (i, byte) in b.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    BytesN::from_array(env, &b)
+}
+
+/// Creates a room where p1 commits to (3, odd, 4, 0x111) and p2 to
+/// (1, even, 2, 0x222), the inputs of `test_valid_round_with_guesses` in
+/// the circuit.
+fn committed_room(env: &Env, client: &ZkPorrinhaContractClient, p1: &Address, p2: &Address) -> u64 {
+    let id = client.create_room(p1, &xlm(client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, p2, &None);
+    let c1 = crate::poseidon2::commitment(env, 3, 1, 4, &salt(env, 0x111));
+    let c2 = crate::poseidon2::commitment(env, 1, 0, 2, &salt(env, 0x222));
+    client.commit(&id, p1, &c1, &1u32, &4u32);
+    client.commit(&id, p2, &c2, &0u32, &2u32);
+    id
+}
+
+#[test]
+fn test_poseidon2_reference_vectors() {
+    let env = Env::default();
+    let u = |v: u32| soroban_sdk::U256::from_u32(&env, v);
+    assert_eq!(
+        crate::poseidon2::permute(&env, [u(0), u(0), u(0), u(0)]),
+        hex32(&env, "18dfb8dc9b82229cff974efefc8df78b1ce96d9d844236b496785c698bc6732e"),
+    );
+    assert_eq!(
+        crate::poseidon2::permute(&env, [u(0), u(1), u(2), u(3)]),
+        hex32(&env, "01bd538c2ee014ed5141b29e9ae240bf8db3fe5b9a38629a9647cf8d76c01737"),
+    );
+}
+
+#[test]
+fn test_commitment_matches_hasher_circuit() {
+    let env = Env::default();
+    assert_eq!(
+        crate::poseidon2::commitment(&env, 3, 1, 4, &salt(&env, 0x111)),
+        hex32(&env, "069c5d7f35da7708a5926c046190baece1dd3a04ec57019038c5d41f9cc8e87f"),
+    );
+    assert_eq!(
+        crate::poseidon2::commitment(&env, 1, 0, 2, &salt(&env, 0x222)),
+        hex32(&env, "12ee736167055ac9ef45257d8bbe0d92fefdb0b2f5b43d1d20bd9bf1e9e154bb"),
+    );
+}
+
+#[test]
+fn test_reveal_settles_room() {
+    let (env, client, p1, p2) = setup();
+    let id = committed_room(&env, &client, &p1, &p2);
+
+    client.reveal(&id, &p1, &3u32, &salt(&env, 0x111));
+    let room = client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Commit);
+    assert!(room.players.get_unchecked(0).has_revealed);
+
+    client.reveal(&id, &p2, &1u32, &salt(&env, 0x222));
+    let room = client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    assert_eq!(room.total_sum, Some(4));
+    // Sum 4 is even so p2 wins parity, but p1 hit the exact sum and takes the jackpot.
+    assert_eq!(room.winner, Some(p1));
+    assert_eq!(client.get_jackpot(&xlm(&client)), 0i128);
+}
+
+#[test]
+#[should_panic]
+fn test_reveal_wrong_salt_fails() {
+    let (env, client, p1, p2) = setup();
+    let id = committed_room(&env, &client, &p1, &p2);
+    client.reveal(&id, &p1, &3u32, &salt(&env, 0x112));
+}
+
+#[test]
+#[should_panic]
+fn test_reveal_wrong_hand_fails() {
+    let (env, client, p1, p2) = setup();
+    let id = committed_room(&env, &client, &p1, &p2);
+    client.reveal(&id, &p1, &2u32, &salt(&env, 0x111));
+}
+
+#[test]
+#[should_panic]
+fn test_reveal_twice_fails() {
+    let (env, client, p1, p2) = setup();
+    let id = committed_room(&env, &client, &p1, &p2);
+    client.reveal(&id, &p1, &3u32, &salt(&env, 0x111));
+    client.reveal(&id, &p1, &3u32, &salt(&env, 0x111));
+}
+
+#[test]
+#[should_panic]
+fn test_reveal_hand_out_of_range_fails() {
+    let (env, client, p1, p2) = setup();
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    let c1 = crate::poseidon2::commitment(&env, 5, 0, 0, &salt(&env, 0x111));
+    let c2 = crate::poseidon2::commitment(&env, 1, 0, 0, &salt(&env, 0x222));
+    client.commit(&id, &p1, &c1, &0u32, &0u32);
+    client.commit(&id, &p2, &c2, &0u32, &0u32);
+    client.reveal(&id, &p1, &5u32, &salt(&env, 0x111));
+}
+
+#[test]
+#[should_panic]
+fn test_reveal_before_both_commit_fails() {
+    let (env, client, p1, p2) = setup();
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    let c1 = crate::poseidon2::commitment(&env, 3, 1, 4, &salt(&env, 0x111));
+    client.commit(&id, &p1, &c1, &1u32, &4u32);
+    client.reveal(&id, &p1, &3u32, &salt(&env, 0x111));
+}
+
+// ── Timeouts ──────────────────────────────────────────────────────────────────
+
+struct Tracked {
+    env: Env,
+    client: ZkPorrinhaContractClient<'static>,
+    hub: MockGameHubClient<'static>,
+    token: MockTokenClient<'static>,
+    p1: Address,
+    p2: Address,
+}
+
+fn setup_tracked() -> Tracked {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set(ledger_info_default());
+    let hub      = env.register(MockGameHub, ());
+    let verifier = env.register(MockVerifier, ());
+    let token    = env.register(MockToken, ());
+    let admin    = Address::generate(&env);
+    let vk_hash  = BytesN::from_array(&env, &VK_HASH);
+    let cid = env.register(ZkPorrinhaContract, (&admin, &hub, &verifier, &vk_hash, &token));
+    Tracked {
+        client: ZkPorrinhaContractClient::new(&env, &cid),
+        hub: MockGameHubClient::new(&env, &hub),
+        token: MockTokenClient::new(&env, &token),
+        p1: Address::generate(&env),
+        p2: Address::generate(&env),
+        env,
+    }
+}
+
+fn advance(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|l| l.sequence_number += ledgers);
+}
+
+#[test]
+fn test_cancel_room_refunds_player1() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    assert_eq!(t.token.balance(&t.p1), -1_000);
+    t.client.cancel_room(&id);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Cancelled);
+}
+
+#[test]
+#[should_panic]
+fn test_cancel_joined_room_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    t.client.cancel_room(&id);
+}
+
+#[test]
+#[should_panic]
+fn test_join_expired_lobby_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    advance(&t.env, LOBBY_TIMEOUT_LEDGERS + 1);
+    t.client.join_room(&id, &t.p2, &None);
+}
+
+#[test]
+#[should_panic]
+fn test_commit_after_deadline_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &t.p1, &c, &0u32, &0u32);
+}
+
+#[test]
+fn test_claim_timeout_opponent_never_committed() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let c = BytesN::from_array(&t.env, &[0x22u8; 32]);
+    t.client.commit(&id, &t.p2, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+
+    t.client.claim_timeout(&id, &t.p2);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    assert_eq!(room.winner, Some(t.p2.clone()));
+    assert_eq!(t.token.balance(&t.p2), 1_000);
+    assert_eq!(t.token.balance(&t.p1), -1_000);
+    assert_eq!(t.hub.result(&room.session_id), Some(false));
+    assert_eq!(
+        t.hub.outcome(&room.session_id),
+        GameOutcome::Forfeit(Vec::from_array(&t.env, [t.p1.clone()]))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_claim_timeout_before_deadline_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &t.p1, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS);
+    t.client.claim_timeout(&id, &t.p1);
+}
+
+#[test]
+#[should_panic]
+fn test_claim_timeout_by_uncommitted_player_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &t.p1, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p2);
+}
+
+#[test]
+fn test_claim_timeout_opponent_never_revealed() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    t.client.reveal(&id, &t.p1, &3u32, &salt(&t.env, 0x111));
+    advance(&t.env, REVEAL_TIMEOUT_LEDGERS + 1);
+
+    t.client.claim_timeout(&id, &t.p1);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.winner, Some(t.p1.clone()));
+    assert_eq!(t.token.balance(&t.p1), 1_000);
+    assert_eq!(t.hub.result(&room.session_id), Some(true));
+}
+
+#[test]
+fn test_late_first_reveal_opens_reveal_window() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    advance(&t.env, RESOLVE_TIMEOUT_LEDGERS);
+    t.client.reveal(&id, &t.p1, &3u32, &salt(&t.env, 0x111));
+
+    // The resolve deadline has passed, but p2 still has the reveal window
+    advance(&t.env, REVEAL_TIMEOUT_LEDGERS);
+    assert_eq!(t.client.try_claim_timeout(&id, &t.p1), Err(Ok(Error::DeadlineNotReached)));
+    t.client.reveal(&id, &t.p2, &1u32, &salt(&t.env, 0x222));
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Settled);
+}
+
+#[test]
+fn test_late_reveal_and_resolve_fail_and_room_refunds() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    advance(&t.env, RESOLVE_TIMEOUT_LEDGERS + 1);
+
+    // Nobody acted in time: a reveal cannot make the caller the sole winner
+    assert_eq!(
+        t.client.try_reveal(&id, &t.p1, &3u32, &salt(&t.env, 0x111)),
+        Err(Ok(Error::RoomExpired))
+    );
+    let proof = Bytes::from_array(&t.env, &[0xAAu8; 200]);
+    assert_eq!(t.client.try_resolve(&id, &proof, &4u32), Err(Ok(Error::RoomExpired)));
+    assert_eq!(t.client.try_claim_timeout(&id, &t.p1), Err(Ok(Error::NothingToClaim)));
+
+    t.client.refund_room(&id);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Refunded);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.token.balance(&t.p2), 0);
+}
+
+#[test]
+fn test_refund_when_no_proof_arrives() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    advance(&t.env, RESOLVE_TIMEOUT_LEDGERS + 1);
+
+    t.client.refund_room(&id);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Refunded);
+    assert_eq!(room.winner, None);
+    assert_eq!(room.outcome, Outcome::Draw);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.token.balance(&t.p2), 0);
+    assert_eq!(t.hub.outcome(&room.session_id), GameOutcome::Cancelled);
+    assert_eq!(t.hub.result(&room.session_id), None);
+}
+
+#[test]
+#[should_panic]
+fn test_refund_before_deadline_fails() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    t.client.refund_room(&id);
+}
+
+#[test]
+#[should_panic]
+fn test_refund_with_pending_forfeit_fails() {
+    let t = setup_tracked();
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    t.client.reveal(&id, &t.p1, &3u32, &salt(&t.env, 0x111));
+    advance(&t.env, RESOLVE_TIMEOUT_LEDGERS + 1);
+    t.client.refund_room(&id);
+}
+
+// ── Storage ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_open_room_is_persistent() {
+    let (env, client, p1, _) = setup();
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&DataKey::Room(id)));
+        assert!(!env.storage().temporary().has(&DataKey::Room(id)));
+    });
+}
+
+#[test]
+fn test_settled_room_is_archived() {
+    let (env, client, p1, p2) = setup();
+    let room = do_full_game(&env, &client, &p1, &p2, 1_000, 1, 5, 0, 3, 5);
+    assert_eq!(room.status, RoomStatus::Settled);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().persistent().has(&DataKey::Room(1)));
+        assert!(env.storage().temporary().has(&DataKey::Room(1)));
+    });
+}
+
+#[test]
+fn test_nullifier_is_persistent() {
+    let (env, client, p1, p2) = setup();
+    do_full_game(&env, &client, &p1, &p2, 1_000, 1, 5, 0, 3, 5);
+    let key = DataKey::Nullifier(client.get_nullifier(&1u64));
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&key));
+        assert!(!env.storage().instance().has(&key));
+    });
+}
+
+#[test]
+fn test_migrate_nullifiers_moves_instance_entries() {
+    let (env, client, p1, p2) = setup();
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    client.join_room(&id, &p2, &None);
+    client.commit(&id, &p1, &BytesN::from_array(&env, &[0x11u8; 32]), &1u32, &5u32);
+    client.commit(&id, &p2, &BytesN::from_array(&env, &[0x22u8; 32]), &0u32, &3u32);
+    let legacy = client.get_nullifier(&id);
+    let unknown = BytesN::from_array(&env, &[0x44u8; 32]);
+    let key = DataKey::Nullifier(legacy.clone());
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&key, &true);
+    });
+
+    let list = Vec::from_array(&env, [legacy, unknown]);
+    assert_eq!(client.migrate_nullifiers(&list), 1);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&key));
+        assert!(env.storage().persistent().has(&key));
+    });
+
+    // A migrated nullifier still cannot be replayed.
+    let proof = Bytes::from_array(&env, &[0xAAu8; 200]);
+    assert_eq!(
+        client.try_resolve(&id, &proof, &5u32),
+        Err(Ok(Error::NullifierUsed))
+    );
+}
+
+// ── N-seat rooms ──────────────────────────────────────────────────────────────
+
+/// Fills a `seats`-seat room: `t.p1` creates it, `t.p2` and fresh addresses join.
+fn full_room(t: &Tracked, seats: u32, bet: i128) -> (u64, Vec<Address>) {
+    let id = t.client.create_room(&t.p1, &t.token.address, &bet, &seats, &RoomAccess::Public, &1);
+    let mut players = Vec::from_array(&t.env, [t.p1.clone(), t.p2.clone()]);
+    while players.len() < seats {
+        players.push_back(Address::generate(&t.env));
+    }
+    for p in players.iter().skip(1) {
+        t.client.join_room(&id, &p, &None);
+    }
+    (id, players)
+}
+
+/// Commits `(hand, parity, exact)` for every seat, salted with `0x100 + seat`.
+fn commit_all(t: &Tracked, id: u64, players: &Vec<Address>, plays: &[(u32, u32, u32)]) {
+    for (i, &(hand, parity, exact)) in plays.iter().enumerate() {
+        let c = crate::poseidon2::commitment(&t.env, hand, parity, exact, &salt(&t.env, 0x100 + i as u16));
+        t.client.commit(&id, &players.get_unchecked(i as u32), &c, &parity, &exact);
+    }
+}
+
+fn reveal_all(t: &Tracked, id: u64, players: &Vec<Address>, plays: &[(u32, u32, u32)]) {
+    for (i, &(hand, _, _)) in plays.iter().enumerate() {
+        t.client.reveal(&id, &players.get_unchecked(i as u32), &hand, &salt(&t.env, 0x100 + i as u16));
+    }
+}
+
+#[test]
+fn test_invalid_seat_counts_fail() {
+    let (_, client, p1, _) = setup();
+    assert_eq!(
+        client.try_create_room(&p1, &xlm(&client), &1_000i128, &1u32, &RoomAccess::Public, &1),
+        Err(Ok(Error::InvalidSeats))
+    );
+    assert_eq!(
+        client.try_create_room(&p1, &xlm(&client), &1_000i128, &7u32, &RoomAccess::Public, &1),
+        Err(Ok(Error::InvalidSeats))
+    );
+}
+
+#[test]
+fn test_room_starts_when_all_seats_taken() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Lobby);
+    assert_eq!(t.client.get_room_pot(&id), 2_000);
+
+    let p3 = Address::generate(&t.env);
+    t.client.join_room(&id, &p3, &None);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Commit);
+    assert_eq!(
+        t.hub.players(&room.session_id),
+        Some(Vec::from_array(&t.env, [t.p1.clone(), t.p2.clone(), p3]))
+    );
+}
+
+#[test]
+fn test_join_same_player_twice_fails() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(t.client.try_join_room(&id, &t.p2, &None), Err(Ok(Error::SelfPlay)));
+}
+
+#[test]
+fn test_commit_rejects_guesses_outside_the_circuit_range() {
+    let t = setup_tracked();
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    let (two, _) = full_room(&t, 2, 100);
+    assert_eq!(t.client.try_commit(&two, &t.p1, &c, &2u32, &0u32), Err(Ok(Error::InvalidGuess)));
+    assert_eq!(t.client.try_commit(&two, &t.p1, &c, &0u32, &7u32), Err(Ok(Error::InvalidGuess)));
+    t.client.commit(&two, &t.p1, &c, &1u32, &6u32);
+
+    let (three, _) = full_room(&t, 3, 100);
+    assert_eq!(t.client.try_commit(&three, &t.p1, &c, &0u32, &19u32), Err(Ok(Error::InvalidGuess)));
+    t.client.commit(&three, &t.p1, &c, &0u32, &18u32);
+}
+
+#[test]
+fn test_three_seat_reveal_splits_pools() {
+    let t = setup_tracked();
+    let (id, players) = full_room(&t, 3, 1_000);
+    // Sum 6 is even: seats 0 and 1 share the parity pool, seats 0 and 2 hit the exact sum.
+    let plays = [(3, 0, 6), (2, 0, 5), (1, 1, 6)];
+    commit_all(&t, id, &players, &plays);
+    reveal_all(&t, id, &players, &plays);
+
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    assert_eq!(room.total_sum, Some(6));
+    let hitters = Vec::from_array(&t.env, [players.get_unchecked(0), players.get_unchecked(2)]);
+    assert_eq!(room.winners, hitters);
+    assert_eq!(room.winner, None);
+    assert_eq!(t.hub.winners(&room.session_id), Some(hitters));
+
+    assert_eq!(t.token.balance(&players.get_unchecked(0)), 500);
+    assert_eq!(t.token.balance(&players.get_unchecked(1)), 200);
+    assert_eq!(t.token.balance(&players.get_unchecked(2)), -700);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 0);
+}
+
+#[test]
+fn test_six_seat_no_exact_hit_feeds_jackpot() {
+    let t = setup_tracked();
+    let (id, players) = full_room(&t, 6, 1_000);
+    // Sum 9 is odd and nobody guessed 9; only seat 5 guessed odd.
+    let plays = [(3, 0, 1), (3, 0, 2), (1, 0, 3), (1, 0, 4), (0, 0, 5), (1, 1, 6)];
+    commit_all(&t, id, &players, &plays);
+    reveal_all(&t, id, &players, &plays);
+
+    let room = t.client.get_room(&id);
+    assert_eq!(room.total_sum, Some(9));
+    assert_eq!(room.winners, Vec::from_array(&t.env, [players.get_unchecked(5)]));
+    assert_eq!(t.token.balance(&players.get_unchecked(5)), 4_800 - 1_000);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 1_200);
+}
+
+#[test]
+fn test_multi_seat_resolve_needs_multi_vk_hash() {
+    let t = setup_tracked();
+    let (id, players) = full_room(&t, 4, 1_000);
+    commit_all(&t, id, &players, &[(1, 0, 0), (1, 0, 0), (1, 0, 0), (1, 0, 4)]);
+    let proof = Bytes::from_array(&t.env, &[0xAAu8; 200]);
+    assert_eq!(t.client.try_resolve(&id, &proof, &4u32), Err(Ok(Error::VkHashNotSet)));
+
+    t.client.set_multi_vk_hash(&BytesN::from_array(&t.env, &VK_HASH));
+    t.client.resolve(&id, &proof, &4u32);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.winners, Vec::from_array(&t.env, [players.get_unchecked(3)]));
+}
+
+#[test]
+fn test_multi_seat_claim_timeout_splits_among_committed() {
+    let t = setup_tracked();
+    let (id, players) = full_room(&t, 3, 1_001);
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &players.get_unchecked(0), &c, &0u32, &0u32);
+    t.client.commit(&id, &players.get_unchecked(1), &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+
+    assert_eq!(
+        t.client.try_claim_timeout(&id, &players.get_unchecked(2)),
+        Err(Ok(Error::NothingToClaim))
+    );
+    t.client.claim_timeout(&id, &players.get_unchecked(1));
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    // 3003 split two ways; the odd unit goes to the first claimant by seat.
+    assert_eq!(t.token.balance(&players.get_unchecked(0)), 501);
+    assert_eq!(t.token.balance(&players.get_unchecked(1)), 500);
+    assert_eq!(t.token.balance(&players.get_unchecked(2)), -1_001);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 0);
+    assert_eq!(t.hub.winners(&room.session_id).unwrap().len(), 2);
+}
+
+#[test]
+fn test_unfilled_lobby_refunds_after_deadline() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &4u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(t.client.try_refund_room(&id), Err(Ok(Error::DeadlineNotReached)));
+    advance(&t.env, LOBBY_TIMEOUT_LEDGERS + 1);
+
+    t.client.refund_room(&id);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Refunded);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.token.balance(&t.p2), 0);
+}
+
+#[test]
+fn test_cancel_partial_lobby_refunds_everyone() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    t.client.cancel_room(&id);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Cancelled);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.token.balance(&t.p2), 0);
+}
+
+// ── Fees ──────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_default_fee_terms() {
+    let (_, client, p1, _) = setup();
+    let terms = FeeTerms { fee_bps: 0, parity_bps: 8_000 };
+    assert_eq!(client.get_fee_terms(), terms);
+    let id = client.create_room(&p1, &xlm(&client), &1_000i128, &2u32, &RoomAccess::Public, &1);
+    assert_eq!(client.get_room(&id).fee_terms, terms);
+    assert_eq!(client.get_treasury(&xlm(&client)), 0);
+}
+
+#[test]
+fn test_set_fee_terms_out_of_range_fails() {
+    let (_, client, _, _) = setup();
+    assert_eq!(client.try_set_fee_terms(&1_001u32, &8_000u32), Err(Ok(Error::InvalidFeeTerms)));
+    assert_eq!(client.try_set_fee_terms(&500u32, &10_001u32), Err(Ok(Error::InvalidFeeTerms)));
+}
+
+#[test]
+fn test_fee_goes_to_treasury_on_settle() {
+    let t = setup_tracked();
+    // 5% fee, 50/50 parity/jackpot split.
+    t.client.set_fee_terms(&500u32, &5_000u32);
+    let id = committed_room(&t.env, &t.client, &t.p1, &t.p2);
+    t.client.reveal(&id, &t.p1, &3u32, &salt(&t.env, 0x111));
+    t.client.reveal(&id, &t.p2, &1u32, &salt(&t.env, 0x222));
+
+    // Pot 2000: fee 100, parity pool 950 to p2 (sum 4 is even), jackpot 950 to p1 (exact hit).
+    assert_eq!(t.client.get_treasury(&t.token.address), 100);
+    assert_eq!(t.token.balance(&t.p1), -50);
+    assert_eq!(t.token.balance(&t.p2), -50);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 0);
+}
+
+#[test]
+fn test_room_keeps_fee_terms_from_creation() {
+    let t = setup_tracked();
+    let (id, _) = full_room(&t, 2, 1_000);
+    t.client.set_fee_terms(&1_000u32, &8_000u32);
+    assert_eq!(t.client.get_room(&id).fee_terms, FeeTerms { fee_bps: 0, parity_bps: 8_000 });
+
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &t.p1, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p1);
+    assert_eq!(t.client.get_treasury(&t.token.address), 0);
+    assert_eq!(t.token.balance(&t.p1), 1_000);
+}
+
+#[test]
+fn test_claim_timeout_pays_fee() {
+    let t = setup_tracked();
+    t.client.set_fee_terms(&250u32, &8_000u32);
+    let (id, _) = full_room(&t, 2, 1_000);
+    let c = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    t.client.commit(&id, &t.p1, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p1);
+    assert_eq!(t.client.get_treasury(&t.token.address), 50);
+    assert_eq!(t.token.balance(&t.p1), 950);
+}
+
+#[test]
+fn test_withdraw_fees() {
+    let t = setup_tracked();
+    t.client.set_fee_terms(&1_000u32, &8_000u32);
+    let (id, _) = full_room(&t, 2, 1_000);
+    t.client.commit(&id, &t.p1, &BytesN::from_array(&t.env, &[0x11u8; 32]), &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p1);
+    assert_eq!(t.client.get_treasury(&t.token.address), 200);
+
+    let operator = Address::generate(&t.env);
+    assert_eq!(
+        t.client.try_withdraw_fees(&t.token.address, &operator, &201i128),
+        Err(Ok(Error::InsufficientFees))
+    );
+    t.client.withdraw_fees(&t.token.address, &operator, &150i128);
+    assert_eq!(t.client.get_treasury(&t.token.address), 50);
+    assert_eq!(t.token.balance(&operator), 150);
+}
+
+#[test]
+#[should_panic]
+fn test_withdraw_fees_requires_admin() {
+    let t = setup_tracked();
+    t.env.set_auths(&[]);
+    t.client.withdraw_fees(&t.token.address, &t.p1, &1i128);
+}
+
+// ── Tokens ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_create_room_with_unlisted_token_fails() {
+    let t = setup_tracked();
+    let usdc = t.env.register(MockToken, ());
+    assert_eq!(
+        t.client.try_create_room(&t.p1, &usdc, &1_000i128, &2u32, &RoomAccess::Public, &1),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+}
+
+#[test]
+fn test_each_token_has_its_own_jackpot() {
+    let t = setup_tracked();
+    let usdc = MockTokenClient::new(&t.env, &t.env.register(MockToken, ()));
+    t.client.allow_token(&usdc.address);
+    assert_eq!(
+        t.client.get_allowed_tokens(),
+        Vec::from_array(&t.env, [t.token.address.clone(), usdc.address.clone()])
+    );
+
+    let id = t.client.create_room(&t.p1, &usdc.address, &500i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let plays = [(1, 1, 0), (2, 0, 0)];
+    let players = Vec::from_array(&t.env, [t.p1.clone(), t.p2.clone()]);
+    commit_all(&t, id, &players, &plays);
+    reveal_all(&t, id, &players, &plays);
+
+    // Sum 3 is odd: p1 takes the 800 parity pool in USDC; 200 feeds the USDC jackpot.
+    assert_eq!(usdc.balance(&t.p1), 300);
+    assert_eq!(usdc.balance(&t.p2), -500);
+    assert_eq!(t.client.get_jackpot(&usdc.address), 200);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 0);
+    assert_eq!(t.token.balance(&t.p1), 0);
+}
+
+#[test]
+fn test_disallowed_token_keeps_open_rooms() {
+    let t = setup_tracked();
+    let (id, _) = full_room(&t, 2, 1_000);
+    t.client.disallow_token(&t.token.address);
+    assert!(t.client.get_allowed_tokens().is_empty());
+    assert_eq!(
+        t.client.try_create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+
+    t.client.commit(&id, &t.p1, &BytesN::from_array(&t.env, &[0x11u8; 32]), &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p1);
+    assert_eq!(t.token.balance(&t.p1), 1_000);
+}
+
+#[test]
+fn test_legacy_global_jackpot_belongs_to_xlm() {
+    let t = setup_tracked();
+    t.env.as_contract(&t.client.address, || {
+        t.env.storage().instance().set(&DataKey::GlobalJackpot, &700i128);
+    });
+    assert_eq!(t.client.get_jackpot(&t.token.address), 700);
+
+    do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 1, 0, 0, 6, 3);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 700 + 400);
+    t.env.as_contract(&t.client.address, || {
+        assert!(!t.env.storage().instance().has(&DataKey::GlobalJackpot));
+    });
+}
+
+// ── Payout math ───────────────────────────────────────────────────────────────
+
+const POTS: [i128; 6] = [2, 7, 1_001, 3_003, 123_456_789, i64::MAX as i128];
+const JACKPOTS: [i128; 4] = [0, 1, 13, 10_000_001];
+const TERMS: [(u32, u32); 6] = [(0, 8_000), (250, 8_000), (1_000, 0), (0, 10_000), (333, 6_667), (1_000, 10_000)];
+
+fn terms(&(fee_bps, parity_bps): &(u32, u32)) -> FeeTerms {
+    FeeTerms { fee_bps, parity_bps }
+}
+
+fn paid(result: &payout::Payout) -> i128 {
+    result.shares.iter().sum()
+}
+
+#[test]
+fn test_settle_conserves_funds_for_every_outcome() {
+    for seats in MIN_SEATS..=MAX_SEATS {
+        let all = payout::everyone(seats);
+        for parity_hits in 0..=all {
+            for exact_hits in 0..=all {
+                for pot in POTS {
+                    for jackpot in JACKPOTS {
+                        for t in TERMS.iter().map(terms) {
+                            let r = payout::settle(pot, jackpot, &t, seats, parity_hits, exact_hits);
+                            assert_eq!(paid(&r) + (r.jackpot - jackpot) + r.fee, pot);
+                            assert!(r.fee >= 0 && r.jackpot >= 0);
+                            if exact_hits != 0 {
+                                assert_eq!(r.jackpot, 0);
+                                assert!(r.jackpot_won >= jackpot);
+                            } else {
+                                assert!(r.jackpot >= jackpot);
+                                assert_eq!(r.jackpot_won, 0);
+                            }
+
+                            let recipients = if parity_hits == 0 { all } else { parity_hits } | exact_hits;
+                            for (seat, &share) in r.shares.iter().enumerate() {
+                                assert!(share >= 0);
+                                if recipients & (1 << seat) == 0 {
+                                    assert_eq!(share, 0);
+                                }
+                            }
+                            assert_eq!(r, payout::settle(pot, jackpot, &t, seats, parity_hits, exact_hits));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_forfeit_conserves_funds_for_every_claimant_set() {
+    for seats in MIN_SEATS..=MAX_SEATS {
+        for active in 1..=payout::everyone(seats) {
+            for pot in POTS {
+                for t in TERMS.iter().map(terms) {
+                    let r = payout::forfeit(pot, 13, &t, active);
+                    assert_eq!(paid(&r) + r.fee, pot);
+                    assert_eq!(r.jackpot, 13);
+                    for (seat, &share) in r.shares.iter().enumerate() {
+                        assert!(share >= 0);
+                        if active & (1 << seat) == 0 {
+                            assert_eq!(share, 0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_remainder_goes_to_first_recipient() {
+    let t = FeeTerms { fee_bps: 0, parity_bps: 10_000 };
+    // 1001 over seats 1, 2 and 4: 333 each, seat 1 takes the 2 left over.
+    let r = payout::settle(1_001, 0, &t, 5, 0b10110, 0);
+    assert_eq!(r.shares, [0, 335, 333, 0, 333, 0]);
+
+    // A forfeit of 7 claimed by seats 0 and 3.
+    let r = payout::forfeit(7, 0, &t, 0b1001);
+    assert_eq!(r.shares, [4, 0, 0, 3, 0, 0]);
+}
+
+#[test]
+fn test_settled_room_leaves_no_dust_in_contract() {
+    let t = setup_tracked();
+    t.client.set_fee_terms(&333u32, &6_667u32);
+    let (id, players) = full_room(&t, 5, 1_001);
+    // Sum 7 is odd: seats 1, 2 and 4 guessed odd; seats 0 and 3 hit the exact sum.
+    let plays = [(3, 0, 7), (1, 1, 2), (0, 1, 3), (2, 0, 7), (1, 1, 9)];
+    commit_all(&t, id, &players, &plays);
+    reveal_all(&t, id, &players, &plays);
+
+    let balances: i128 = players.iter().map(|p| t.token.balance(&p)).sum();
+    let held = t.client.get_jackpot(&t.token.address) + t.client.get_treasury(&t.token.address);
+    assert_eq!(balances + held, 0);
+    assert_eq!(t.token.balance(&t.client.address), held);
+}
+
+// ── Outcomes ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_single_winner_sets_outcome() {
+    let t = setup_tracked();
+    let room = do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 1, 0, 0, 0, 3);
+    assert_eq!(room.outcome, Outcome::P1Win);
+    assert_eq!(t.hub.result(&room.session_id), Some(true));
+
+    let room = do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 1, 0, 0, 4, 4);
+    assert_eq!(room.outcome, Outcome::P2Win);
+    assert_eq!(room.winner, Some(t.p2.clone()));
+    assert_eq!(t.hub.result(&room.session_id), Some(false));
+}
+
+#[test]
+fn test_both_exact_hits_is_a_draw() {
+    let t = setup_tracked();
+    let room = do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 1, 3, 1, 3, 3);
+    assert_eq!(room.outcome, Outcome::Draw);
+    assert_eq!(room.winner, None);
+    assert_eq!(room.winners.len(), 2);
+    assert!(t.hub.drawn(&room.session_id));
+    assert_eq!(t.hub.result(&room.session_id), None);
+    assert_eq!(t.token.balance(&t.p1), 0);
+    assert_eq!(t.token.balance(&t.p2), 0);
+}
+
+#[test]
+fn test_both_right_parity_is_a_draw() {
+    let t = setup_tracked();
+    let room = do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 0, 0, 0, 6, 4);
+    assert_eq!(room.outcome, Outcome::Draw);
+    assert_eq!(room.winner, None);
+    assert!(t.hub.drawn(&room.session_id));
+}
+
+#[test]
+fn test_nobody_right_is_a_draw() {
+    let t = setup_tracked();
+    let room = do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 1, 0, 1, 6, 4);
+    assert_eq!(room.outcome, Outcome::Draw);
+    assert!(room.winners.is_empty());
+}
+
+#[test]
+fn test_multi_seat_rooms_have_no_outcome() {
+    let t = setup_tracked();
+    let (id, players) = full_room(&t, 3, 1_000);
+    let plays = [(3, 0, 6), (2, 0, 5), (1, 1, 6)];
+    commit_all(&t, id, &players, &plays);
+    reveal_all(&t, id, &players, &plays);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.outcome, Outcome::Pending);
+    assert_eq!(room.winner, None);
+    assert_eq!(room.winners.len(), 2);
+}
+
+// ── Lobby index ───────────────────────────────────────────────────────────────
+
+fn entry_ids(entries: &Vec<crate::RoomEntry>) -> Vec<u64> {
+    let mut ids = Vec::new(entries.env());
+    for e in entries.iter() {
+        ids.push_back(e.room_id);
+    }
+    ids
+}
+
+#[test]
+fn test_open_rooms_lists_lobbies_only() {
+    let t = setup_tracked();
+    let a = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    let b = t.client.create_room(&t.p1, &t.token.address, &200i128, &3u32, &RoomAccess::Public, &1);
+    let c = t.client.create_room(&t.p2, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    assert_eq!(entry_ids(&t.client.list_open_rooms(&0, &10, &None, &None).rooms), Vec::from_slice(&t.env, &[a, b, c]));
+
+    // Filled, cancelled and partly filled rooms.
+    t.client.join_room(&a, &t.p2, &None);
+    t.client.cancel_room(&c);
+    t.client.join_room(&b, &t.p2, &None);
+    let open = t.client.list_open_rooms(&0, &10, &None, &None).rooms;
+    assert_eq!(entry_ids(&open), Vec::from_slice(&t.env, &[b]));
+    assert_eq!(open.get_unchecked(0).room.players.len(), 2);
+}
+
+#[test]
+fn test_open_rooms_filters_by_token_and_bet() {
+    let t = setup_tracked();
+    let usdc = MockTokenClient::new(&t.env, &t.env.register(MockToken, ()));
+    t.client.allow_token(&usdc.address);
+    let a = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    let b = t.client.create_room(&t.p1, &usdc.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    let c = t.client.create_room(&t.p1, &usdc.address, &500i128, &2u32, &RoomAccess::Public, &1);
+
+    let by_token = t.client.list_open_rooms(&0, &10, &Some(usdc.address.clone()), &None).rooms;
+    assert_eq!(entry_ids(&by_token), Vec::from_slice(&t.env, &[b, c]));
+    assert_eq!(entry_ids(&t.client.list_open_rooms(&0, &10, &None, &Some(100)).rooms), Vec::from_slice(&t.env, &[a, b]));
+    assert_eq!(
+        entry_ids(&t.client.list_open_rooms(&0, &10, &Some(usdc.address.clone()), &Some(500)).rooms),
+        Vec::from_slice(&t.env, &[c])
+    );
+    assert!(t.client.list_open_rooms(&0, &10, &None, &Some(1)).rooms.is_empty());
+}
+
+#[test]
+fn test_open_rooms_paginates() {
+    let t = setup_tracked();
+    let mut ids = [0u64; 5];
+    for id in ids.iter_mut() {
+        *id = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    }
+
+    let first = t.client.list_open_rooms(&0, &2, &None, &None);
+    assert_eq!(entry_ids(&first.rooms), Vec::from_slice(&t.env, &ids[..2]));
+    assert_eq!(first.next_cursor, Some(ids[1]));
+    let cursor = first.rooms.get_unchecked(1).room_id;
+    assert_eq!(entry_ids(&t.client.list_open_rooms(&cursor, &2, &None, &None).rooms), Vec::from_slice(&t.env, &ids[2..4]));
+    let last = t.client.list_open_rooms(&ids[3], &2, &None, &None);
+    assert_eq!(entry_ids(&last.rooms), Vec::from_slice(&t.env, &ids[4..]));
+    assert_eq!(last.next_cursor, None);
+    assert!(t.client.list_open_rooms(&ids[4], &2, &None, &None).rooms.is_empty());
+}
+
+#[test]
+fn test_open_rooms_caps_rooms_scanned() {
+    let t = setup_tracked();
+    for _ in 0..MAX_SCAN {
+        t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    }
+    let wanted = t.client.create_room(&t.p1, &t.token.address, &500i128, &2u32, &RoomAccess::Public, &1);
+
+    // The only match sits past the scan budget: the first page is empty but
+    // hands back a cursor to resume from.
+    let first = t.client.list_open_rooms(&0, &10, &None, &Some(500));
+    assert!(first.rooms.is_empty());
+    assert_eq!(first.next_cursor, Some(MAX_SCAN as u64));
+    let rest = t.client.list_open_rooms(&first.next_cursor.unwrap(), &10, &None, &Some(500));
+    assert_eq!(entry_ids(&rest.rooms), Vec::from_array(&t.env, [wanted]));
+    assert_eq!(rest.next_cursor, None);
+}
+
+#[test]
+fn test_open_rooms_caps_page_size() {
+    let t = setup_tracked();
+    for _ in 0..crate::MAX_PAGE + 1 {
+        t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    }
+    assert_eq!(t.client.list_open_rooms(&0, &u32::MAX, &None, &None).rooms.len(), crate::MAX_PAGE);
+}
+
+#[test]
+fn test_open_rooms_skips_expired_and_drops_refunded() {
+    let t = setup_tracked();
+    let old = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    advance(&t.env, LOBBY_TIMEOUT_LEDGERS + 1);
+    let fresh = t.client.create_room(&t.p2, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    assert_eq!(entry_ids(&t.client.list_open_rooms(&0, &10, &None, &None).rooms), Vec::from_slice(&t.env, &[fresh]));
+
+    t.client.refund_room(&old);
+    assert_eq!(open_index(&t, 0), Vec::from_array(&t.env, [fresh]));
+}
+
+fn open_index(t: &Tracked, bucket: u64) -> Vec<u64> {
+    t.env.as_contract(&t.client.address, || {
+        t.env.storage().persistent().get(&DataKey::OpenRooms(bucket)).unwrap_or_else(|| Vec::new(&t.env))
+    })
+}
+
+#[test]
+fn test_open_rooms_page_across_buckets() {
+    let t = setup_tracked();
+    let count = ROOM_INDEX_BUCKET + 2;
+    for _ in 0..count {
+        t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    }
+    // Fill every room of the first bucket but the last.
+    for id in 1..ROOM_INDEX_BUCKET - 1 {
+        t.client.join_room(&id, &t.p2, &None);
+    }
+    assert_eq!(open_index(&t, 0), Vec::from_array(&t.env, [ROOM_INDEX_BUCKET - 1]));
+    assert_eq!(open_index(&t, 1).len(), 3);
+
+    let first = t.client.list_open_rooms(&0, &2, &None, &None).rooms;
+    assert_eq!(entry_ids(&first), Vec::from_array(&t.env, [ROOM_INDEX_BUCKET - 1, ROOM_INDEX_BUCKET]));
+    let rest = t.client.list_open_rooms(&first.get_unchecked(1).room_id, &10, &None, &None).rooms;
+    assert_eq!(entry_ids(&rest), Vec::from_array(&t.env, [ROOM_INDEX_BUCKET + 1, count]));
+
+    t.client.join_room(&(ROOM_INDEX_BUCKET - 1), &t.p2, &None);
+    assert!(open_index(&t, 0).is_empty());
+    let buckets: Vec<u64> = t.env.as_contract(&t.client.address, || {
+        t.env.storage().persistent().get(&DataKey::OpenRoomBuckets).unwrap()
+    });
+    assert_eq!(buckets, Vec::from_array(&t.env, [1]));
+}
+
+#[test]
+fn test_create_room_prunes_expired_lobbies() {
+    let t = setup_tracked();
+    let mut stale = Vec::new(&t.env);
+    for _ in 0..crate::MAX_EXPIRED_PRUNE + 2 {
+        stale.push_back(t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1));
+    }
+    advance(&t.env, LOBBY_TIMEOUT_LEDGERS + 1);
+
+    let fresh = t.client.create_room(&t.p2, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    let mut expected = stale.slice(crate::MAX_EXPIRED_PRUNE..);
+    expected.push_back(fresh);
+    assert_eq!(open_index(&t, 0), expected);
+
+    let next = t.client.create_room(&t.p2, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    assert_eq!(open_index(&t, 0), Vec::from_array(&t.env, [fresh, next]));
+
+    // Pruned lobbies still refund.
+    t.client.refund_room(&stale.get_unchecked(0));
+    assert_eq!(t.client.get_room(&stale.get_unchecked(0)).status, RoomStatus::Refunded);
+}
+
+#[test]
+fn test_rooms_by_player() {
+    let t = setup_tracked();
+    let (a, _) = full_room(&t, 2, 100);
+    let b = t.client.create_room(&t.p2, &t.token.address, &100i128, &3u32, &RoomAccess::Public, &1);
+    let c = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    let d = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    t.client.cancel_room(&c);
+
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p1, &0, &10)), Vec::from_slice(&t.env, &[a, d]));
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p2, &0, &10)), Vec::from_slice(&t.env, &[a, b]));
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p1, &a, &10)), Vec::from_slice(&t.env, &[d]));
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p2, &0, &1)), Vec::from_slice(&t.env, &[a]));
+    assert!(t.client.list_rooms_by_player(&Address::generate(&t.env), &0, &10).is_empty());
+}
+
+#[test]
+fn test_finished_rooms_leave_player_index() {
+    let t = setup_tracked();
+    let (a, players) = full_room(&t, 2, 100);
+    let b = t.client.create_room(&t.p2, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    // Joining an older room keeps the index sorted.
+    t.client.join_room(&b, &t.p1, &None);
+    let c = t.client.create_room(&t.p1, &t.token.address, &100i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&c, &t.p2, &None);
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p1, &0, &10)), Vec::from_slice(&t.env, &[a, b, c]));
+
+    play_round(&t, a, &players, &[(1, 1, 2), (0, 0, 2)]);
+    assert_eq!(entry_ids(&t.client.list_rooms_by_player(&t.p2, &0, &10)), Vec::from_slice(&t.env, &[b, c]));
+
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.refund_room(&b);
+    t.client.refund_room(&c);
+    for p in players.iter() {
+        assert!(t.client.list_rooms_by_player(&p, &0, &10).is_empty());
+        let buckets: Option<Vec<u64>> = t.env.as_contract(&t.client.address, || {
+            t.env.storage().persistent().get(&DataKey::PlayerRoomBuckets(p.clone()))
+        });
+        assert!(buckets.is_none());
+    }
+}
+
+// ── Private rooms ─────────────────────────────────────────────────────────────
+
+fn invite_key(env: &Env, seed: u8) -> (SigningKey, RoomAccess) {
+    let key = SigningKey::from_bytes(&[seed; 32]);
+    let access = RoomAccess::Key(BytesN::from_array(env, &key.verifying_key().to_bytes()));
+    (key, access)
+}
+
+fn invite_sig(env: &Env, key: &SigningKey, room_id: u64, player: &Address) -> Option<BytesN<64>> {
+    let mut message = Bytes::from_array(env, &room_id.to_be_bytes());
+    message.append(&player.clone().to_xdr(env));
+    let mut buf = [0u8; 128];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+    Some(BytesN::from_array(env, &key.sign(&buf[..len]).to_bytes()))
+}
+
+#[test]
+fn test_invited_room_only_admits_invitees() {
+    let t = setup_tracked();
+    let access = RoomAccess::Invited(Vec::from_array(&t.env, [t.p2.clone()]));
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &access, &1);
+
+    let stranger = Address::generate(&t.env);
+    assert_eq!(t.client.try_join_room(&id, &stranger, &None), Err(Ok(Error::NotInvited)));
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Commit);
+}
+
+#[test]
+fn test_invalid_invites_are_rejected() {
+    let t = setup_tracked();
+    let cases = [
+        (Vec::new(&t.env), 2u32),
+        (Vec::from_array(&t.env, [t.p1.clone()]), 2),
+        (Vec::from_array(&t.env, [t.p2.clone(), Address::generate(&t.env)]), 2),
+    ];
+    for (invited, seats) in cases {
+        assert_eq!(
+            t.client.try_create_room(&t.p1, &t.token.address, &1_000i128, &seats, &RoomAccess::Invited(invited), &1),
+            Err(Ok(Error::InvalidAccess))
+        );
+    }
+}
+
+#[test]
+fn test_invite_list_must_fill_every_free_seat() {
+    let t = setup_tracked();
+    let p3 = Address::generate(&t.env);
+    let short = Vec::from_array(&t.env, [t.p2.clone()]);
+    let long = Vec::from_array(&t.env, [t.p2.clone(), p3.clone(), Address::generate(&t.env)]);
+    for invited in [short, long] {
+        assert_eq!(
+            t.client.try_create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Invited(invited), &1),
+            Err(Ok(Error::InvalidAccess))
+        );
+    }
+}
+
+#[test]
+fn test_duplicate_invitees_are_rejected() {
+    let t = setup_tracked();
+    let invited = Vec::from_array(&t.env, [t.p2.clone(), t.p2.clone()]);
+    assert_eq!(
+        t.client.try_create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Invited(invited), &1),
+        Err(Ok(Error::InvalidAccess))
+    );
+}
+
+#[test]
+fn test_key_room_requires_an_invite_signature() {
+    let t = setup_tracked();
+    let (key, access) = invite_key(&t.env, 7);
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &access, &1);
+
+    assert_eq!(t.client.try_join_room(&id, &t.p2, &None), Err(Ok(Error::MissingInviteSig)));
+    t.client.join_room(&id, &t.p2, &invite_sig(&t.env, &key, id, &t.p2));
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Commit);
+}
+
+#[test]
+fn test_invite_signature_is_bound_to_the_joiner() {
+    let t = setup_tracked();
+    let (key, access) = invite_key(&t.env, 7);
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &access, &1);
+    let other = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &access, &1);
+
+    // A signature seen in the mempool is useless to anyone else, or for
+    // another room with the same key.
+    let sig = invite_sig(&t.env, &key, id, &t.p2);
+    let thief = Address::generate(&t.env);
+    assert!(t.client.try_join_room(&id, &thief, &sig).is_err());
+    assert!(t.client.try_join_room(&other, &t.p2, &sig).is_err());
+    let (wrong_key, _) = invite_key(&t.env, 8);
+    assert!(t.client.try_join_room(&id, &t.p2, &invite_sig(&t.env, &wrong_key, id, &t.p2)).is_err());
+
+    t.client.join_room(&id, &t.p2, &sig);
+    assert_eq!(t.client.get_room(&id).players.get_unchecked(1).address, t.p2);
+}
+
+#[test]
+fn test_private_rooms_are_not_listed() {
+    let t = setup_tracked();
+    let public = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    let invited = RoomAccess::Invited(Vec::from_array(&t.env, [t.p2.clone()]));
+    let private = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &invited, &1);
+    let (_, keyed) = invite_key(&t.env, 7);
+    t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &keyed, &1);
+
+    let open = t.client.list_open_rooms(&0, &10, &None, &None).rooms;
+    assert_eq!(entry_ids(&open), Vec::from_slice(&t.env, &[public]));
+    // The creator still finds them, and cancelling one keeps the index intact.
+    assert_eq!(t.client.list_rooms_by_player(&t.p1, &0, &10).len(), 3);
+    t.client.cancel_room(&private);
+    assert_eq!(entry_ids(&t.client.list_open_rooms(&0, &10, &None, &None).rooms), Vec::from_slice(&t.env, &[public]));
+}
+
+#[test]
+fn test_invited_multi_seat_room_fills_with_invitees() {
+    let t = setup_tracked();
+    let p3 = Address::generate(&t.env);
+    let access = RoomAccess::Invited(Vec::from_array(&t.env, [t.p2.clone(), p3.clone()]));
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &access, &1);
+    t.client.join_room(&id, &p3, &None);
+    assert_eq!(
+        t.client.try_join_room(&id, &Address::generate(&t.env), &None),
+        Err(Ok(Error::NotInvited))
+    );
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Commit);
+}
+
+// ── Matches ───────────────────────────────────────────────────────────────────
+
+fn match_room(t: &Tracked, rounds: u32) -> (u64, Vec<Address>) {
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &rounds);
+    t.client.join_room(&id, &t.p2, &None);
+    (id, Vec::from_array(&t.env, [t.p1.clone(), t.p2.clone()]))
+}
+
+fn play_round(t: &Tracked, id: u64, players: &Vec<Address>, plays: &[(u32, u32, u32)]) {
+    commit_all(t, id, players, plays);
+    reveal_all(t, id, players, plays);
+}
+
+#[test]
+fn test_invalid_round_counts_fail() {
+    let t = setup_tracked();
+    for rounds in [0, crate::MAX_ROUNDS + 1] {
+        assert_eq!(
+            t.client.try_create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &rounds),
+            Err(Ok(Error::InvalidRounds))
+        );
+    }
+}
+
+#[test]
+fn test_match_pays_out_only_when_decided() {
+    let t = setup_tracked();
+    let (id, players) = match_room(&t, 3);
+    // Sum 3 is odd: p1's parity guess wins the round.
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 0)]);
+
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Commit);
+    assert_eq!(room.round, 1);
+    assert_eq!(room.round_sums, Vec::from_array(&t.env, [3]));
+    assert_eq!(room.players.get_unchecked(0).score, 1);
+    assert!(!room.players.get_unchecked(0).has_committed);
+    assert_eq!(t.token.balance(&t.p1), -1_000);
+    assert_eq!(t.hub.result(&room.session_id), None);
+
+    // 2-0 in a best of 3 cannot be caught.
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 0)]);
+    let done = t.client.get_room(&id);
+    assert_eq!(done.status, RoomStatus::Settled);
+    assert_eq!(done.session_id, room.session_id);
+    assert_eq!(done.round_sums.len(), 2);
+    assert_eq!(done.outcome, Outcome::P1Win);
+    assert_eq!(t.hub.result(&done.session_id), Some(true));
+    assert_eq!(t.token.balance(&t.p1), 600);
+    assert_eq!(t.token.balance(&t.p2), -1_000);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 400);
+}
+
+#[test]
+fn test_tied_match_is_a_draw() {
+    let t = setup_tracked();
+    let (id, players) = match_room(&t, 2);
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 0)]);
+    // Sum 2 is even: p2's parity guess wins.
+    play_round(&t, id, &players, &[(1, 1, 0), (1, 0, 0)]);
+
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    assert_eq!(room.outcome, Outcome::Draw);
+    assert!(t.hub.drawn(&room.session_id));
+    assert_eq!(t.token.balance(&t.p1), -200);
+    assert_eq!(t.token.balance(&t.p2), -200);
+}
+
+#[test]
+fn test_exact_hit_in_any_round_shares_match_jackpot() {
+    let t = setup_tracked();
+    let (id, players) = match_room(&t, 2);
+    // p2 hits the exact sum, which outranks p1's parity.
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 3)]);
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 0)]);
+
+    let room = t.client.get_room(&id);
+    assert_eq!(room.players.get_unchecked(1).exact_hits, 1);
+    assert_eq!(room.outcome, Outcome::Draw);
+    // 1-1: the 1_600 pool is split; p2 alone takes the 400 jackpot.
+    assert_eq!(t.token.balance(&t.p1), -200);
+    assert_eq!(t.token.balance(&t.p2), 200);
+    assert_eq!(t.client.get_jackpot(&t.token.address), 0);
+}
+
+#[test]
+fn test_each_round_has_its_own_nullifier() {
+    let t = setup_tracked();
+    let (id, _) = match_room(&t, 3);
+    let c1 = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    let c2 = BytesN::from_array(&t.env, &[0x22u8; 32]);
+    let proof = Bytes::from_array(&t.env, &[0xAAu8; 200]);
+
+    t.client.commit(&id, &t.p1, &c1, &1u32, &0u32);
+    t.client.commit(&id, &t.p2, &c2, &0u32, &0u32);
+    let first = t.client.get_nullifier(&id);
+    t.client.resolve(&id, &proof, &3u32);
+
+    // Same commitments again, yet a fresh nullifier.
+    t.client.commit(&id, &t.p1, &c1, &1u32, &0u32);
+    t.client.commit(&id, &t.p2, &c2, &0u32, &0u32);
+    assert_ne!(t.client.get_nullifier(&id), first);
+    t.client.resolve(&id, &proof, &3u32);
+    assert_eq!(t.client.get_room(&id).status, RoomStatus::Settled);
+}
+
+#[test]
+fn test_stalling_mid_match_forfeits() {
+    let t = setup_tracked();
+    let (id, players) = match_room(&t, 3);
+    play_round(&t, id, &players, &[(1, 1, 0), (2, 0, 0)]);
+    let c = BytesN::from_array(&t.env, &[0x22u8; 32]);
+    t.client.commit(&id, &t.p2, &c, &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+
+    t.client.claim_timeout(&id, &t.p2);
+    let room = t.client.get_room(&id);
+    assert_eq!(room.status, RoomStatus::Settled);
+    assert_eq!(room.outcome, Outcome::P2Win);
+    assert_eq!(t.token.balance(&t.p2), 1_000);
+}
+
+// ── Events ────────────────────────────────────────────────────────────────────
+
+fn porrinha_events(t: &Tracked) -> soroban_sdk::testutils::ContractEvents {
+    t.env.events().all().filter_by_contract(&t.client.address)
+}
+
+#[test]
+fn test_lobby_events() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &3u32, &RoomAccess::Public, &1);
+    assert_eq!(
+        porrinha_events(&t),
+        [RoomCreated {
+            room_id: id,
+            creator: t.p1.clone(),
+            token: t.token.address.clone(),
+            bet_amount: 1_000,
+            seats: 3,
+            rounds: 1,
+            private: false,
+        }
+        .to_xdr(&t.env, &t.client.address)]
+    );
+
+    t.client.join_room(&id, &t.p2, &None);
+    assert_eq!(
+        porrinha_events(&t),
+        [RoomJoined { room_id: id, player: t.p2.clone(), seat: 1, session_id: 0 }
+            .to_xdr(&t.env, &t.client.address)]
+    );
+
+    t.client.cancel_room(&id);
+    assert_eq!(
+        porrinha_events(&t),
+        [RoomCancelled { room_id: id, status: RoomStatus::Cancelled }.to_xdr(&t.env, &t.client.address)]
+    );
+}
+
+#[test]
+fn test_full_room_join_event_carries_session() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let events = porrinha_events(&t);
+    let session_id = t.client.get_room(&id).session_id;
+    assert_ne!(session_id, 0);
+    assert_eq!(
+        events,
+        [RoomJoined { room_id: id, player: t.p2.clone(), seat: 1, session_id }
+            .to_xdr(&t.env, &t.client.address)]
+    );
+}
+
+#[test]
+fn test_commit_and_resolve_events() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    let c1 = BytesN::from_array(&t.env, &[0x11u8; 32]);
+    let c2 = BytesN::from_array(&t.env, &[0x22u8; 32]);
+    t.client.commit(&id, &t.p1, &c1, &1u32, &0u32);
+    assert_eq!(
+        porrinha_events(&t),
+        [Committed { room_id: id, player: t.p1.clone(), round: 0 }.to_xdr(&t.env, &t.client.address)]
+    );
+    t.client.commit(&id, &t.p2, &c2, &0u32, &0u32);
+
+    t.client.resolve(&id, &Bytes::from_array(&t.env, &[0xAAu8; 200]), &3u32);
+    assert_eq!(
+        porrinha_events(&t),
+        [Resolved {
+            room_id: id,
+            total_sum: Some(3),
+            payouts: Vec::from_array(&t.env, [1_600, 0]),
+            fee: 0,
+            jackpot_delta: 400,
+        }
+        .to_xdr(&t.env, &t.client.address)]
+    );
+}
+
+#[test]
+fn test_jackpot_won_event() {
+    let t = setup_tracked();
+    do_full_game(&t.env, &t.client, &t.p1, &t.p2, 1_000, 0, 0, 0, 0, 3);
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    t.client.commit(&id, &t.p1, &BytesN::from_array(&t.env, &[0x11u8; 32]), &0u32, &0u32);
+    t.client.commit(&id, &t.p2, &BytesN::from_array(&t.env, &[0x22u8; 32]), &1u32, &4u32);
+    t.client.resolve(&id, &Bytes::from_array(&t.env, &[0xAAu8; 200]), &4u32);
+    // p1 takes the parity pool; p2 hits 4 and takes the 400 carried over
+    // plus this round's 400.
+    assert_eq!(
+        porrinha_events(&t),
+        [
+            Resolved {
+                room_id: id,
+                total_sum: Some(4),
+                payouts: Vec::from_array(&t.env, [1_600, 800]),
+                fee: 0,
+                jackpot_delta: -400,
+            }
+            .to_xdr(&t.env, &t.client.address),
+            JackpotWon {
+                room_id: id,
+                token: t.token.address.clone(),
+                amount: 800,
+                winners: Vec::from_array(&t.env, [t.p2.clone()]),
+            }
+            .to_xdr(&t.env, &t.client.address),
+        ]
+    );
+}
+
+#[test]
+fn test_timeout_events() {
+    let t = setup_tracked();
+    let id = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    t.client.join_room(&id, &t.p2, &None);
+    t.client.commit(&id, &t.p2, &BytesN::from_array(&t.env, &[0x22u8; 32]), &0u32, &0u32);
+    advance(&t.env, COMMIT_TIMEOUT_LEDGERS + 1);
+    t.client.claim_timeout(&id, &t.p2);
+    assert_eq!(
+        porrinha_events(&t),
+        [Resolved {
+            room_id: id,
+            total_sum: None,
+            payouts: Vec::from_array(&t.env, [0, 2_000]),
+            fee: 0,
+            jackpot_delta: 0,
+        }
+        .to_xdr(&t.env, &t.client.address)]
+    );
+
+    let stale = t.client.create_room(&t.p1, &t.token.address, &1_000i128, &2u32, &RoomAccess::Public, &1);
+    advance(&t.env, LOBBY_TIMEOUT_LEDGERS + 1);
+    t.client.refund_room(&stale);
+    assert_eq!(
+        porrinha_events(&t),
+        [RoomCancelled { room_id: stale, status: RoomStatus::Refunded }.to_xdr(&t.env, &t.client.address)]
+    );
 }
@@ -277,7 +277,7 @@ if (shouldEnsureMock) {
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -311,12 +311,27 @@ for (const contract of contracts) {
         process.exit(1);
       }
       console.log(`  Using noir-verifier: ${verifierContractId}`);
-      
+
+      // The verifier hashes its bundled VK on-chain; zk-porrinha is configured with that hash
+      const vkHashResult =
+        await $`stellar contract invoke --id ${verifierContractId} --source-account ${adminSecret} --network ${NETWORK} -- vk_hash`.text();
+      const vkHash = vkHashResult.trim().replace(/"/g, '');
+      console.log(`  Using VK hash: ${vkHash}`);
+
       // Use native XLM token address (Stellar Asset Contract for XLM)
       const xlmToken = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
       
       deployResult =
-        await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --verifier ${verifierContractId} --game-hub ${mockGameHubId} --xlm-token ${xlmToken}`.text();
+        await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --verifier ${verifierContractId} --vk-hash ${vkHash} --game-hub ${mockGameHubId} --xlm-token ${xlmToken}`.text();
+    } else if (contract.packageName === 'noir-verifier') {
+      // The verifier registers the zk-porrinha key written by `bb write_vk`
+      const vkPath = "circuits/zk-porrinha/target/vk";
+      if (!await Bun.file(vkPath).exists()) {
+        console.error(`❌ ${vkPath} not found. Generate it with bb write_vk (see circuits/zk-porrinha/README.md)`);
+        process.exit(1);
+      }
+      deployResult =
+        await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --vk-file-path ${vkPath}`.text();
     } else {
       deployResult =
         await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
@@ -324,7 +339,18 @@ for (const contract of contracts) {
     
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
-    console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
+    console.log(`✅ ${contract.packageName} deployed: ${contractId}`);
+
+    // The Game Hub only opens sessions for games its admin has registered
+    if (mockGameHubId && contract.packageName !== 'noir-verifier') {
+      try {
+        await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- add_game --game_address ${contractId}`.text();
+        console.log(`  Registered with Game Hub ${mockGameHubId}`);
+      } catch (error) {
+        console.warn(`⚠️  Could not register ${contract.packageName} with the Game Hub (not its admin?):`, error);
+      }
+    }
+    console.log("");
   } catch (error) {
     console.error(`❌ Failed to deploy ${contract.packageName}:`, error);
     process.exit(1);
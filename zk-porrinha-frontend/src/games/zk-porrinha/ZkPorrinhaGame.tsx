@@ -6,6 +6,8 @@ import {
   getRoomAction,
   determineRoomPhase,
   formatXLM as formatXLMService,
+  formatBps,
+  splitPot,
   runAction as runActionService,
   logMessage as logMessageService,
   handleCreateRoomAction,
@@ -299,7 +301,7 @@ export function ZkPorrinhaGame({
           }));
           if (res.autoResolved && res.winner) {
             setGameWinner(res.winner);
-            if (currentRoom?.bet_amount) setGameWinAmount(BigInt(currentRoom.bet_amount) * 2n);
+            if (currentRoom?.bet_amount) setGameWinAmount(BigInt(currentRoom.bet_amount) * BigInt(currentRoom.players.length));
             if (res.resolveTxHash) setLastTxHash(res.resolveTxHash);
             stopZkLog(`> Game auto-resolved! TX: ${res.resolveTxHash?.slice(0, 12) ?? ''}... 🏆`);
             setSuccess('🏆 Game auto-resolved!');
@@ -396,10 +398,10 @@ export function ZkPorrinhaGame({
     });
   };
 
-  const isPlayer1 = currentRoom?.player1.address === userAddress;
-  const isPlayer2 = currentRoom?.player2?.address === userAddress;
+  const isPlayer1 = currentRoom?.players[0]?.address === userAddress;
+  const isPlayer2 = currentRoom?.players[1]?.address === userAddress;
   const isPlayer = isPlayer1 || isPlayer2;
-  const hasCommitted = isPlayer1 ? currentRoom?.player1.has_committed : currentRoom?.player2?.has_committed;
+  const hasCommitted = isPlayer1 ? currentRoom?.players[0]?.has_committed : currentRoom?.players[1]?.has_committed;
 
   // ── Intro gate ──────────────────────────────────────────────────────────────
   if (showIntro) {
@@ -525,14 +527,14 @@ export function ZkPorrinhaGame({
               {searchResult && (() => {
                 const tag = (searchResult.room.status as any).tag;
                 const r = searchResult.room;
-                const isYours = r.player1.address === userAddress || r.player2?.address === userAddress;
-                const canJoin = tag === 'Lobby' && !r.has_player2 && r.player1.address !== userAddress;
+                const isYours = r.players.some((p) => p.address === userAddress);
+                const canJoin = tag === 'Lobby' && r.players.length < r.seats && !r.players.some((p) => p.address === userAddress);
                 return (
                   <div className="mt-3 bg-[#0d1117] border-2 border-[#3b82f6] p-3">
                     <div className="text-[#3b82f6] text-[13px] tracking-widest mb-1">TABLE #{searchResult.id.toString()}</div>
                     <div className="text-[#9ca3af] text-[13px]">Status: <span className="text-white">{tag}</span></div>
                     <div className="text-[#9ca3af] text-[13px]">Bet: <span className="text-[#fbbf24]">{formatXLM(r.bet_amount)} XLM</span></div>
-                    <div className="text-[#9ca3af] text-[13px]">Host: {r.player1.address.slice(0,8)}...</div>
+                    <div className="text-[#9ca3af] text-[13px]">Host: {r.players[0].address.slice(0,8)}...</div>
                     <div className="flex gap-2 mt-2">
                       {canJoin && (
                         <button onClick={() => handleJoinRoom(searchResult.id)} disabled={loading}
@@ -586,9 +588,9 @@ export function ZkPorrinhaGame({
                     </div>
                     <div className="border-t border-[#374151] pt-2 mt-2">
                       <div className="text-[#6b7280] text-[12px] mb-1">PLAYERS:</div>
-                      <div className="text-[#9ca3af] text-[12px]">P1: {r.player1.address.slice(0,10)}... · {r.player1.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.player1.exact_sum_guess}</div>
-                      {r.has_player2 && (
-                        <div className="text-[#9ca3af] text-[12px]">P2: {r.player2.address.slice(0,10)}... · {r.player2.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.player2.exact_sum_guess}</div>
+                      <div className="text-[#9ca3af] text-[12px]">P1: {r.players[0].address.slice(0,10)}... · {r.players[0].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.players[0].exact_sum_guess}</div>
+                      {r.players.length > 1 && (
+                        <div className="text-[#9ca3af] text-[12px]">P2: {r.players[1].address.slice(0,10)}... · {r.players[1].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.players[1].exact_sum_guess}</div>
                       )}
                     </div>
                   </div>
@@ -615,7 +617,7 @@ export function ZkPorrinhaGame({
             <div className="mt-2">
               <div className="text-[#fde68a] text-[13px] tracking-widest mb-2 flex items-center justify-between">
                 <span>🏓 ACTIVE TABLES</span>
-                <span className="text-[#9ca3af]">{lobbyRooms.filter(r => r.room?.status && (r.room.status as any).tag === 'Lobby' && !r.room.has_player2).length} open</span>
+                <span className="text-[#9ca3af]">{lobbyRooms.filter(r => r.room?.status && (r.room.status as any).tag === 'Lobby' && r.room.players.length < r.room.seats).length} open</span>
               </div>
 
               {lobbyLoading && (
@@ -629,29 +631,29 @@ export function ZkPorrinhaGame({
                 </div>
               )}
 
-              {/* Open rooms (Lobby + no player2) */}
-              {lobbyRooms.filter(r => r.room && (r.room.status as any).tag === 'Lobby' && !r.room.has_player2).map(({ id, room }) => (
+              {/* Open rooms (Lobby + free seat) */}
+              {lobbyRooms.filter(r => r.room && (r.room.status as any).tag === 'Lobby' && r.room.players.length < r.room.seats).map(({ id, room }) => (
                 <div key={id.toString()} className="bg-[#1a2e1a] border-2 border-[#22c55e] p-3 mb-2 flex items-center justify-between">
                   <div>
                     <div className="text-[#22c55e] text-[14px] tracking-widest">TABLE #{id.toString()}</div>
                     <div className="text-[#9ca3af] text-[13px] mt-0.5">
-                      Host: {room!.player1.address.slice(0,6)}... · Bet: {formatXLM(room!.bet_amount)} XLM
+                      Host: {room!.players[0].address.slice(0,6)}... · Bet: {formatXLM(room!.bet_amount)} XLM
                     </div>
                     <div className="text-[#fbbf24] text-[13px]">⏳ Waiting for challenger...</div>
                   </div>
                   <button
                     onClick={() => handleJoinRoom(id)}
-                    disabled={loading || room!.player1.address === userAddress}
+                    disabled={loading || room!.players[0].address === userAddress}
                     className="bg-[#22c55e] border-2 border-black text-black font-bold px-3 py-2 text-[13px] tracking-widest shadow-[3px_3px_0_#000] disabled:opacity-40"
                   >
-                    {room!.player1.address === userAddress ? 'YOURS' : 'JOIN'}
+                    {room!.players[0].address === userAddress ? 'YOURS' : 'JOIN'}
                   </button>
                 </div>
               ))}
 
               {/* Commit-phase rooms */}
               {lobbyRooms.filter(r => r.room && (r.room.status as any).tag === 'Commit').map(({ id, room }) => {
-                const isMyRoom = room!.player1.address === userAddress || room!.player2.address === userAddress;
+                const isMyRoom = room!.players.some((p) => p.address === userAddress);
                 return (
                   <div key={id.toString()} className="bg-[#1a1a2e] border-2 border-[#3b82f6] p-3 mb-2 flex items-center justify-between">
                     <div>
@@ -718,8 +720,8 @@ export function ZkPorrinhaGame({
                                   <span className="text-[#fbbf24]">{r.total_sum} ({r.total_sum % 2 === 0 ? 'EVEN' : 'ODD'})</span>
                                 </div>
                               )}
-                              <div className="text-[#6b7280] text-[12px] pt-1">P1: {r.player1.address.slice(0,10)}... · {r.player1.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.player1.exact_sum_guess}</div>
-                              {r.has_player2 && <div className="text-[#6b7280] text-[12px]">P2: {r.player2.address.slice(0,10)}... · {r.player2.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.player2.exact_sum_guess}</div>}
+                              <div className="text-[#6b7280] text-[12px] pt-1">P1: {r.players[0].address.slice(0,10)}... · {r.players[0].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.players[0].exact_sum_guess}</div>
+                              {r.players.length > 1 && <div className="text-[#6b7280] text-[12px]">P2: {r.players[1].address.slice(0,10)}... · {r.players[1].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={r.players[1].exact_sum_guess}</div>}
                             </div>
                           );
                         })()}
@@ -733,7 +735,7 @@ export function ZkPorrinhaGame({
         )}
 
         {/* ── LOBBY: waiting for player 2 ── */}
-        {roomPhase === 'lobby' && currentRoom && !currentRoom.has_player2 && (
+        {roomPhase === 'lobby' && currentRoom && currentRoom.players.length < currentRoom.seats && (
           <div className="mt-4 text-center">
             <div className="text-5xl mb-3">🌊</div>
             <div className="text-[#fde68a] text-[13px] tracking-widest mb-1">TABLE OPEN!</div>
@@ -877,7 +879,7 @@ export function ZkPorrinhaGame({
         )}
 
         {/* ── BOTH COMMITTED: reveal/resolve ── */}
-        {roomPhase === 'commit' && currentRoom && currentRoom.player1.has_committed && currentRoom.player2?.has_committed && (
+        {roomPhase === 'commit' && currentRoom && currentRoom.players.length === currentRoom.seats && currentRoom.players.every((p) => p.has_committed) && (
           <div className="mt-4">
             <RevealForm
               roomId={currentRoomId}
@@ -895,14 +897,14 @@ export function ZkPorrinhaGame({
           const isDraw = !winner;
           const winnerShort = winner ? `${winner.slice(0, 8)}...${winner.slice(-4)}` : null;
           const totalSumVal = currentRoom.total_sum;
-          const totalPot = BigInt(currentRoom.bet_amount) * 2n;
-          // 80% goes to parity winner, 20% accumulates in jackpot
-          const parityPrize = (totalPot * 80n) / 100n;
-          const jackpotContribution = totalPot - parityPrize; // 20%
+          const totalPot = BigInt(currentRoom.bet_amount) * BigInt(currentRoom.players.length);
+          // Split with the terms the room was created under
+          const terms = currentRoom.fee_terms;
+          const { fee, parityPrize, jackpotContribution } = splitPot(totalPot, terms);
 
           // Jackpot check: did either player guess the exact sum correctly?
-          const p1HitJackpot = totalSumVal !== undefined && totalSumVal !== null && currentRoom.player1.exact_sum_guess === totalSumVal;
-          const p2HitJackpot = totalSumVal !== undefined && totalSumVal !== null && currentRoom.player2?.exact_sum_guess === totalSumVal;
+          const p1HitJackpot = totalSumVal !== undefined && totalSumVal !== null && currentRoom.players[0]?.exact_sum_guess === totalSumVal;
+          const p2HitJackpot = totalSumVal !== undefined && totalSumVal !== null && currentRoom.players[1]?.exact_sum_guess === totalSumVal;
           const jackpotHit = p1HitJackpot || p2HitJackpot;
           const iHitJackpot = (isPlayer1 && p1HitJackpot) || (isPlayer2 && p2HitJackpot);
 
@@ -997,11 +999,15 @@ export function ZkPorrinhaGame({
                   <span className="text-[#fbbf24]">{formatXLM(totalPot)} XLM</span>
                 </div>
                 <div className="text-white flex justify-between">
-                  <span>PARITY PRIZE (80%):</span>
+                  <span>HOUSE FEE ({formatBps(terms.fee_bps)}):</span>
+                  <span className="text-[#9ca3af]">{formatXLM(fee)} XLM</span>
+                </div>
+                <div className="text-white flex justify-between">
+                  <span>PARITY PRIZE ({formatBps(terms.parity_bps)} AFTER FEE):</span>
                   <span className="text-[#22c55e]">{formatXLM(parityPrize)} XLM</span>
                 </div>
                 <div className="text-white flex justify-between">
-                  <span>→ JACKPOT (20%):</span>
+                  <span>→ JACKPOT ({formatBps(10_000 - terms.parity_bps)} AFTER FEE):</span>
                   <span className="text-[#ef4444]">+{formatXLM(jackpotContribution)} XLM</span>
                 </div>
                 <div className="text-white flex justify-between">
@@ -1014,16 +1020,16 @@ export function ZkPorrinhaGame({
               <div className="bg-[#0d1117] border-2 border-[#374151] p-3 mb-3 text-[13px] text-left">
                 <div className="text-[#6b7280] tracking-widest mb-2">PLAYER GUESSES</div>
                 <div className="flex justify-between mb-1">
-                  <span className="text-[#9ca3af]">Player 1 ({currentRoom.player1.address.slice(0,6)}...):</span>
+                  <span className="text-[#9ca3af]">Player 1 ({currentRoom.players[0].address.slice(0,6)}...):</span>
                   <span className={`text-white ${p1HitJackpot ? 'text-[#fbbf24]' : ''}`}>
-                    {currentRoom.player1.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={currentRoom.player1.exact_sum_guess}{p1HitJackpot ? ' 🎰' : ''}
+                    {currentRoom.players[0].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={currentRoom.players[0].exact_sum_guess}{p1HitJackpot ? ' 🎰' : ''}
                   </span>
                 </div>
-                {currentRoom.has_player2 && (
+                {currentRoom.players.length > 1 && (
                   <div className="flex justify-between">
-                    <span className="text-[#9ca3af]">Player 2 ({currentRoom.player2.address.slice(0,6)}...):</span>
+                    <span className="text-[#9ca3af]">Player 2 ({currentRoom.players[1].address.slice(0,6)}...):</span>
                     <span className={`text-white ${p2HitJackpot ? 'text-[#fbbf24]' : ''}`}>
-                      {currentRoom.player2.parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={currentRoom.player2.exact_sum_guess}{p2HitJackpot ? ' 🎰' : ''}
+                      {currentRoom.players[1].parity_guess === 1 ? 'ODD' : 'EVEN'} · exact={currentRoom.players[1].exact_sum_guess}{p2HitJackpot ? ' 🎰' : ''}
                     </span>
                   </div>
                 )}
@@ -49,58 +49,328 @@ export const Errors = {
   8: {message:"XlmTokenNotSet"},
   9: {message:"VerifierNotSet"},
   10: {message:"GameHubNotSet"},
-  11: {message:"SelfPlay"}
+  11: {message:"SelfPlay"},
+  12: {message:"VkHashNotSet"},
+  13: {message:"InvalidReveal"},
+  14: {message:"AlreadyRevealed"},
+  15: {message:"InvalidHand"},
+  16: {message:"RoomExpired"},
+  17: {message:"DeadlineNotReached"},
+  18: {message:"NothingToClaim"},
+  19: {message:"InvalidSeats"},
+  20: {message:"InvalidFeeTerms"},
+  21: {message:"InsufficientFees"},
+  22: {message:"TokenNotAllowed"},
+  23: {message:"InvalidAccess"},
+  24: {message:"NotInvited"},
+  25: {message:"MissingInviteSig"},
+  26: {message:"InvalidRounds"},
+  27: {message:"InvalidGuess"}
 }
 
-export type RoomStatus = {tag: "Lobby", values: void} | {tag: "Commit", values: void} | {tag: "Settled", values: void};
+export type RoomStatus = {tag: "Lobby", values: void} | {tag: "Commit", values: void} | {tag: "Settled", values: void} | {tag: "Cancelled", values: void} | {tag: "Refunded", values: void};
+
+/**
+ * Result of a two-seat room. `Draw` covers a shared prize (both or neither
+ * guessing right) and a refund; `Pending` until then.
+ */
+export type Outcome = {tag: "Pending", values: void} | {tag: "P1Win", values: void} | {tag: "P2Win", values: void} | {tag: "Draw", values: void};
+
+/**
+ * Who may take the free seats of a room. `Invited` lists the only
+ * addresses allowed to join, one distinct address per free seat; `Key` holds an ed25519 public key, and a
+ * joiner passes its signature over `room_id ‖ player` to `join_room`: the
+ * id as 8 big-endian bytes, then the address as `ScVal` XDR.
+ */
+export type RoomAccess = {tag: "Public", values: void} | {tag: "Invited", values: readonly [Array<string>]} | {tag: "Key", values: readonly [Buffer]};
 
 
 export interface PlayerState {
   address: string;
   commitment: Buffer;
+  exact_hits: u32;
   exact_sum_guess: u32;
+  hand: u32;
   has_committed: boolean;
+  has_revealed: boolean;
   parity_guess: u32;
+  score: u32;
+}
+
+
+/**
+ * Payout terms, in basis points. `fee_bps` of the pot goes to the treasury;
+ * `parity_bps` of the rest funds the parity pool and the remainder the
+ * jackpot.
+ */
+export interface FeeTerms {
+  fee_bps: u32;
+  parity_bps: u32;
 }
 
 
 export interface Room {
+  access: RoomAccess;
   bet_amount: i128;
-  has_player2: boolean;
-  player1: PlayerState;
-  player2: PlayerState;
+  /**
+   * Ledger sequence ending the current phase: joining in `Lobby`,
+   * committing in `Commit`, then resolving or revealing once everyone has
+   * committed, at least `REVEAL_TIMEOUT_LEDGERS` after the first reveal.
+   */
+  deadline: u32;
+  /**
+   * Terms in force when the room was created; later config changes do
+   * not apply to it.
+   */
+  fee_terms: FeeTerms;
+  /**
+   * Set when a two-seat room finishes; larger rooms stay `Pending` and
+   * only use `winners`.
+   */
+  outcome: Outcome;
+  /**
+   * Seated players in join order; the first one created the room.
+   */
+  players: Array<PlayerState>;
+  /**
+   * Index of the round being played, from 0.
+   */
+  round: u32;
+  /**
+   * Total sum of every finished round, in order.
+   */
+  round_sums: Array<u32>;
+  /**
+   * Best-of-`rounds` match; 1 for a single throw.
+   */
+  rounds: u32;
+  seats: u32;
   session_id: u32;
   status: RoomStatus;
+  /**
+   * Token escrowed by this room; bets, payouts, fees and the jackpot it
+   * feeds are all in this asset.
+   */
+  token: string;
+  /**
+   * Total of the latest finished round.
+   */
   total_sum: Option<u32>;
+  /**
+   * The sole winner; `None` while open and when the prize is shared.
+   */
   winner: Option<string>;
+  /**
+   * Exact-sum hitters if anyone hit, otherwise correct parity guessers;
+   * in a match, the top scorers. On a timeout, the players who did not
+   * stall.
+   */
+  winners: Array<string>;
+}
+
+
+/**
+ * A room together with its id, as returned by the listing queries.
+ */
+export interface RoomEntry {
+  room: Room;
+  room_id: u64;
+}
+
+
+/**
+ * A page of `list_open_rooms`. `next_cursor` is where the next call picks
+ * up, `None` once the whole index has been read.
+ */
+export interface RoomPage {
+  next_cursor: Option<u64>;
+  rooms: Array<RoomEntry>;
 }
 
 export interface Client {
+  /**
+   * Construct and simulate a set_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_vk_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Points resolution at a different circuit registered in the verifier.
+   */
+  set_vk_hash: ({new_vk_hash}: {new_vk_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_vk_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vk_hash: (options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a set_multi_vk_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the verification key hash of the N-seat circuit used to resolve
+   * rooms with more than two seats. Until it is set those rooms can only
+   * settle through `reveal`.
+   */
+  set_multi_vk_hash: ({new_vk_hash}: {new_vk_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_multi_vk_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_multi_vk_hash: (options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a set_fee_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sets the terms for rooms created from now on. Rooms already open keep
+   * the terms they were created with.
+   */
+  set_fee_terms: ({fee_bps, parity_bps}: {fee_bps: u32, parity_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_fee_terms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee_terms: (options?: MethodOptions) => Promise<AssembledTransaction<FeeTerms>>
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fees collected in `token` and not yet withdrawn.
+   */
+  get_treasury: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a withdraw_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  withdraw_fees: ({token, to, amount}: {token: string, to: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a allow_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allows new rooms to escrow `token`.
+   */
+  allow_token: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a disallow_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stops new rooms from using `token`. Open rooms, its jackpot and its
+   * treasury are unaffected.
+   */
+  disallow_token: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_allowed_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_allowed_tokens: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a migrate_nullifiers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Moves nullifiers written to instance storage by earlier versions into
+   * their own persistent entries. Instance storage cannot be enumerated,
+   * so the admin supplies the used nullifiers (e.g. from past `resolve`
+   * calls). Returns how many were moved; unknown ones are skipped.
+   */
+  migrate_nullifiers: ({nullifiers}: {nullifiers: Array<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a create_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Opens a room with `seats` places (`MIN_SEATS..=MAX_SEATS`) betting
+   * `bet_amount` of an allowlisted `token`; the creator takes the first
+   * seat. Private rooms (any `access` but `Public`) stay out of
+   * `list_open_rooms`. `rounds` above 1 makes the room a best-of-`rounds`
+   * match paid out when it ends.
    */
-  create_room: ({player, bet_amount}: {player: string, bet_amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
+  create_room: ({player, token, bet_amount, seats, access, rounds}: {player: string, token: string, bet_amount: i128, seats: u32, access: RoomAccess, rounds: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u64>>>
 
   /**
    * Construct and simulate a join_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Takes the next free seat. The session starts once every seat is taken.
+   * `invite_sig` is the invite key's signature of a `Key` room over
+   * `room_id ‖ player` (see `RoomAccess`) and is ignored otherwise. A
+   * wrong signature traps.
    */
-  join_room: ({room_id, player}: {room_id: u64, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  join_room: ({room_id, player, invite_sig}: {room_id: u64, player: string, invite_sig: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a commit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Seals the player's hand and guesses for the current round. `parity`
+   * is 0 (even) or 1 (odd); `exact_guess` is at most the highest sum the
+   * room's circuit accepts, `MAX_HAND * 2` for two seats and
+   * `MAX_HAND * MAX_SEATS` otherwise.
    */
   commit: ({room_id, player, commitment, parity, exact_guess}: {room_id: u64, player: string, commitment: Buffer, parity: u32, exact_guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a resolve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verifies the proof and settles the current round. The nullifier is
+   * not chosen by the caller: it is derived from the room id, session id,
+   * round and all commitments (see `get_nullifier`), so a proof is spent
+   * by exactly the round it was generated for.
+   */
+  resolve: ({room_id, proof, total_sum}: {room_id: u64, proof: Buffer, total_sum: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Non-ZK fallback: opens the caller's commitment on-chain by recomputing
+   * `poseidon2([hand, parity_guess, exact_sum_guess, salt])`. The room
+   * round settles once every player has revealed. The first reveal leaves
+   * the others at least `REVEAL_TIMEOUT_LEDGERS` to follow before anyone
+   * can claim a forfeit. `resolve` with a proof remains
+   * the default path and keeps hands private.
+   */
+  reveal: ({room_id, player, hand, salt}: {room_id: u64, player: string, hand: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The creator closes a lobby that has not filled up; everyone seated
+   * gets their bet back.
+   */
+  cancel_room: ({room_id}: {room_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claims the pot, less the room's fee, after the current deadline when
+   * others stalled: before everyone committed, the players who committed
+   * split it; afterwards, the players who revealed do, once the reveal
+   * window has closed. `player` must be one of them.
    */
-  resolve: ({room_id, proof, total_sum, nullifier}: {room_id: u64, proof: Buffer, total_sum: u32, nullifier: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  claim_timeout: ({room_id, player}: {room_id: u64, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a refund_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Returns every bet once the current deadline passed without a result
+   * and nobody can claim a forfeit: the lobby never filled, nobody
+   * committed, or everyone committed and no proof or reveal arrived.
+   * Anyone may call.
+   */
+  refund_room: ({room_id}: {room_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_room transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_room: ({room_id}: {room_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Room>>>
 
+  /**
+   * Construct and simulate a get_nullifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The nullifier `resolve` burns for the current round. Only defined once
+   * every player has committed.
+   */
+  get_nullifier: ({room_id}: {room_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
+  /**
+   * Construct and simulate a list_open_rooms transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Joinable rooms with an id above `cursor`, oldest first, at most
+   * `limit` (capped at `MAX_PAGE`). Expired lobbies are skipped;
+   * `token` and `bet_amount` narrow the results when given. A call reads
+   * at most `MAX_SCAN` rooms, so a page may come back short or empty
+   * before the index is exhausted: keep passing `next_cursor` until it
+   * is `None`.
+   */
+  list_open_rooms: ({cursor, limit, token, bet_amount}: {cursor: u64, limit: u32, token: Option<string>, bet_amount: Option<i128>}, options?: MethodOptions) => Promise<AssembledTransaction<RoomPage>>
+
+  /**
+   * Construct and simulate a list_rooms_by_player transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Unfinished rooms `player` took a seat in with an id above `cursor`,
+   * oldest first, at most `limit` (capped at `MAX_PAGE`). Rooms leave the
+   * list once settled, cancelled or refunded.
+   */
+  list_rooms_by_player: ({player, cursor, limit}: {player: string, cursor: u64, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<RoomEntry>>>
+
   /**
    * Construct and simulate a get_room_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -108,21 +378,49 @@ export interface Client {
 
   /**
    * Construct and simulate a get_jackpot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the accumulated global jackpot (i128 stroops).
    */
-  get_jackpot: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+  get_jackpot: ({token}: {token: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
 
   /**
    * Construct and simulate a get_room_pot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Returns the total pot locked in a room (bet x number of players).
    */
   get_room_pot: ({room_id}: {room_id: u64}, options?: MethodOptions) => Promise<AssembledTransaction<Result<i128>>>
 
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new admin address (admin only)
+   */
+  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address (admin only)
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (admin only)
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
         /** Constructor/Initialization Args for the contract's `__constructor` method */
-        {admin, game_hub, verifier, xlm_token}: {admin: string, game_hub: string, verifier: string, xlm_token: string},
+        {admin, game_hub, verifier, vk_hash, xlm_token}: {admin: string, game_hub: string, verifier: string, vk_hash: Buffer, xlm_token: string},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -134,34 +432,95 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({admin, game_hub, verifier, xlm_token}, options)
+    return ContractClient.deploy({admin, game_hub, verifier, vk_hash, xlm_token}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMUm9vbU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMSW52YWxpZFBoYXNlAAAAAwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAQAAAAAAAAADEludmFsaWRQcm9vZgAAAAUAAAAAAAAADU51bGxpZmllclVzZWQAAAAAAAAGAAAAAAAAAApJbnZhbGlkQmV0AAAAAAAHAAAAAAAAAA5YbG1Ub2tlbk5vdFNldAAAAAAACAAAAAAAAAAOVmVyaWZpZXJOb3RTZXQAAAAAAAkAAAAAAAAADUdhbWVIdWJOb3RTZXQAAAAAAAAKAAAAAAAAAAhTZWxmUGxheQAAAAs=",
-        "AAAAAgAAAAAAAAAAAAAAClJvb21TdGF0dXMAAAAAAAMAAAAAAAAAAAAAAAVMb2JieQAAAAAAAAAAAAAAAAAABkNvbW1pdAAAAAAAAAAAAAAAAAAHU2V0dGxlZAA=",
-        "AAAAAQAAAAAAAAAAAAAAC1BsYXllclN0YXRlAAAAAAUAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAAD2V4YWN0X3N1bV9ndWVzcwAAAAAEAAAAAAAAAA1oYXNfY29tbWl0dGVkAAAAAAAAAQAAAAAAAAAMcGFyaXR5X2d1ZXNzAAAABA==",
-        "AAAAAQAAAAAAAAAAAAAABFJvb20AAAAIAAAAAAAAAApiZXRfYW1vdW50AAAAAAALAAAAAAAAAAtoYXNfcGxheWVyMgAAAAABAAAAAAAAAAdwbGF5ZXIxAAAAB9AAAAALUGxheWVyU3RhdGUAAAAAAAAAAAdwbGF5ZXIyAAAAB9AAAAALUGxheWVyU3RhdGUAAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAKUm9vbVN0YXR1cwAAAAAAAAAAAAl0b3RhbF9zdW0AAAAAAAPoAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAQAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAh2ZXJpZmllcgAAABMAAAAAAAAACXhsbV90b2tlbgAAAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAALY3JlYXRlX3Jvb20AAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApiZXRfYW1vdW50AAAAAAALAAAAAQAAA+kAAAAGAAAAAw==",
-        "AAAAAAAAAAAAAAAJam9pbl9yb29tAAAAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
-        "AAAAAAAAAAAAAAAGY29tbWl0AAAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABnBhcml0eQAAAAAABAAAAAAAAAALZXhhY3RfZ3Vlc3MAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
-        "AAAAAAAAAAAAAAAHcmVzb2x2ZQAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAYAAAAAAAAABXByb29mAAAAAAAADgAAAAAAAAAJdG90YWxfc3VtAAAAAAAABAAAAAAAAAAJbnVsbGlmaWVyAAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGwAAAAAAAAAMUm9vbU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMSW52YWxpZFBoYXNlAAAAAwAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAAQAAAAAAAAADEludmFsaWRQcm9vZgAAAAUAAAAAAAAADU51bGxpZmllclVzZWQAAAAAAAAGAAAAAAAAAApJbnZhbGlkQmV0AAAAAAAHAAAAAAAAAA5YbG1Ub2tlbk5vdFNldAAAAAAACAAAAAAAAAAOVmVyaWZpZXJOb3RTZXQAAAAAAAkAAAAAAAAADUdhbWVIdWJOb3RTZXQAAAAAAAAKAAAAAAAAAAhTZWxmUGxheQAAAAsAAAAAAAAADFZrSGFzaE5vdFNldAAAAAwAAAAAAAAADUludmFsaWRSZXZlYWwAAAAAAAANAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAADgAAAAAAAAALSW52YWxpZEhhbmQAAAAADwAAAAAAAAALUm9vbUV4cGlyZWQAAAAAEAAAAAAAAAASRGVhZGxpbmVOb3RSZWFjaGVkAAAAAAARAAAAAAAAAA5Ob3RoaW5nVG9DbGFpbQAAAAAAEgAAAAAAAAAMSW52YWxpZFNlYXRzAAAAEwAAAAAAAAAPSW52YWxpZEZlZVRlcm1zAAAAABQAAAAAAAAAEEluc3VmZmljaWVudEZlZXMAAAAVAAAAAAAAAA9Ub2tlbk5vdEFsbG93ZWQAAAAAFgAAAAAAAAANSW52YWxpZEFjY2VzcwAAAAAAABcAAAAAAAAACk5vdEludml0ZWQAAAAAABgAAAAAAAAAEE1pc3NpbmdJbnZpdGVTaWcAAAAZAAAAAAAAAA1JbnZhbGlkUm91bmRzAAAAAAAAGgAAAAAAAAAMSW52YWxpZEd1ZXNzAAAAGw==",
+        "AAAAAgAAAAAAAAAAAAAAClJvb21TdGF0dXMAAAAAAAUAAAAAAAAAAAAAAAVMb2JieQAAAAAAAAAAAAAAAAAABkNvbW1pdAAAAAAAAAAAAAAAAAAHU2V0dGxlZAAAAAAAAAAAAAAAAAlDYW5jZWxsZWQAAAAAAAAAAAAAAAAAAAhSZWZ1bmRlZA==",
+        "AAAAAgAAAHxSZXN1bHQgb2YgYSB0d28tc2VhdCByb29tLiBgRHJhd2AgY292ZXJzIGEgc2hhcmVkIHByaXplIChib3RoIG9yIG5laXRoZXIKZ3Vlc3NpbmcgcmlnaHQpIGFuZCBhIHJlZnVuZDsgYFBlbmRpbmdgIHVudGlsIHRoZW4uAAAAAAAAAAdPdXRjb21lAAAAAAQAAAAAAAAAAAAAAAdQZW5kaW5nAAAAAAAAAAAAAAAABVAxV2luAAAAAAAAAAAAAAAAAAAFUDJXaW4AAAAAAAAAAAAAAAAAAAREcmF3",
+        "AAAAAgAAASxXaG8gbWF5IHRha2UgdGhlIGZyZWUgc2VhdHMgb2YgYSByb29tLiBgSW52aXRlZGAgbGlzdHMgdGhlIG9ubHkKYWRkcmVzc2VzIGFsbG93ZWQgdG8gam9pbiwgb25lIGRpc3RpbmN0IGFkZHJlc3MgcGVyIGZyZWUgc2VhdDsgYEtleWAgaG9sZHMgYW4gZWQyNTUxOSBwdWJsaWMga2V5LCBhbmQgYQpqb2luZXIgcGFzc2VzIGl0cyBzaWduYXR1cmUgb3ZlciBgcm9vbV9pZCDigJYgcGxheWVyYCB0byBgam9pbl9yb29tYDogdGhlCmlkIGFzIDggYmlnLWVuZGlhbiBieXRlcywgdGhlbiB0aGUgYWRkcmVzcyBhcyBgU2NWYWxgIFhEUi4AAAAAAAAAClJvb21BY2Nlc3MAAAAAAAMAAAAAAAAAAAAAAAZQdWJsaWMAAAAAAAEAAAAAAAAAB0ludml0ZWQAAAAAAQAAA+oAAAATAAAAAQAAAAAAAAADS2V5AAAAAAEAAAPuAAAAIA==",
+        "AAAAAQAAAAAAAAAAAAAAC1BsYXllclN0YXRlAAAAAAkAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAACmV4YWN0X2hpdHMAAAAAAAQAAAAAAAAAD2V4YWN0X3N1bV9ndWVzcwAAAAAEAAAAAAAAAARoYW5kAAAABAAAAAAAAAANaGFzX2NvbW1pdHRlZAAAAAAAAAEAAAAAAAAADGhhc19yZXZlYWxlZAAAAAEAAAAAAAAADHBhcml0eV9ndWVzcwAAAAQAAAAAAAAABXNjb3JlAAAAAAAABA==",
+        "AAAAAQAAAJdQYXlvdXQgdGVybXMsIGluIGJhc2lzIHBvaW50cy4gYGZlZV9icHNgIG9mIHRoZSBwb3QgZ29lcyB0byB0aGUgdHJlYXN1cnk7CmBwYXJpdHlfYnBzYCBvZiB0aGUgcmVzdCBmdW5kcyB0aGUgcGFyaXR5IHBvb2wgYW5kIHRoZSByZW1haW5kZXIgdGhlCmphY2twb3QuAAAAAAAAAAAIRmVlVGVybXMAAAACAAAAAAAAAAdmZWVfYnBzAAAAAAQAAAAAAAAACnBhcml0eV9icHMAAAAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAABFJvb20AAAAQAAAAAAAAAAZhY2Nlc3MAAAAAB9AAAAAKUm9vbUFjY2VzcwAAAAAAAAAAAApiZXRfYW1vdW50AAAAAAALAAAAyExlZGdlciBzZXF1ZW5jZSBlbmRpbmcgdGhlIGN1cnJlbnQgcGhhc2U6IGpvaW5pbmcgaW4gYExvYmJ5YCwKY29tbWl0dGluZyBpbiBgQ29tbWl0YCwgdGhlbiByZXNvbHZpbmcgb3IgcmV2ZWFsaW5nIG9uY2UgZXZlcnlvbmUgaGFzCmNvbW1pdHRlZCwgYXQgbGVhc3QgYFJFVkVBTF9USU1FT1VUX0xFREdFUlNgIGFmdGVyIHRoZSBmaXJzdCByZXZlYWwuAAAACGRlYWRsaW5lAAAABAAAAFJUZXJtcyBpbiBmb3JjZSB3aGVuIHRoZSByb29tIHdhcyBjcmVhdGVkOyBsYXRlciBjb25maWcgY2hhbmdlcyBkbwpub3QgYXBwbHkgdG8gaXQuAAAAAAAJZmVlX3Rlcm1zAAAAAAAH0AAAAAhGZWVUZXJtcwAAAFZTZXQgd2hlbiBhIHR3by1zZWF0IHJvb20gZmluaXNoZXM7IGxhcmdlciByb29tcyBzdGF5IGBQZW5kaW5nYCBhbmQKb25seSB1c2UgYHdpbm5lcnNgLgAAAAAAB291dGNvbWUAAAAH0AAAAAdPdXRjb21lAAAAAD1TZWF0ZWQgcGxheWVycyBpbiBqb2luIG9yZGVyOyB0aGUgZmlyc3Qgb25lIGNyZWF0ZWQgdGhlIHJvb20uAAAAAAAAB3BsYXllcnMAAAAD6gAAB9AAAAALUGxheWVyU3RhdGUAAAAAKEluZGV4IG9mIHRoZSByb3VuZCBiZWluZyBwbGF5ZWQsIGZyb20gMC4AAAAFcm91bmQAAAAAAAAEAAAALFRvdGFsIHN1bSBvZiBldmVyeSBmaW5pc2hlZCByb3VuZCwgaW4gb3JkZXIuAAAACnJvdW5kX3N1bXMAAAAAA+oAAAAEAAAALUJlc3Qtb2YtYHJvdW5kc2AgbWF0Y2g7IDEgZm9yIGEgc2luZ2xlIHRocm93LgAAAAAAAAZyb3VuZHMAAAAAAAQAAAAAAAAABXNlYXRzAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAClJvb21TdGF0dXMAAAAAAGBUb2tlbiBlc2Nyb3dlZCBieSB0aGlzIHJvb207IGJldHMsIHBheW91dHMsIGZlZXMgYW5kIHRoZSBqYWNrcG90IGl0CmZlZWRzIGFyZSBhbGwgaW4gdGhpcyBhc3NldC4AAAAFdG9rZW4AAAAAAAATAAAAI1RvdGFsIG9mIHRoZSBsYXRlc3QgZmluaXNoZWQgcm91bmQuAAAAAAl0b3RhbF9zdW0AAAAAAAPoAAAABAAAAEBUaGUgc29sZSB3aW5uZXI7IGBOb25lYCB3aGlsZSBvcGVuIGFuZCB3aGVuIHRoZSBwcml6ZSBpcyBzaGFyZWQuAAAABndpbm5lcgAAAAAD6AAAABMAAACNRXhhY3Qtc3VtIGhpdHRlcnMgaWYgYW55b25lIGhpdCwgb3RoZXJ3aXNlIGNvcnJlY3QgcGFyaXR5IGd1ZXNzZXJzOwppbiBhIG1hdGNoLCB0aGUgdG9wIHNjb3JlcnMuIE9uIGEgdGltZW91dCwgdGhlIHBsYXllcnMgd2hvIGRpZCBub3QKc3RhbGwuAAAAAAAAB3dpbm5lcnMAAAAD6gAAABM=",
+        "AAAAAQAAAEBBIHJvb20gdG9nZXRoZXIgd2l0aCBpdHMgaWQsIGFzIHJldHVybmVkIGJ5IHRoZSBsaXN0aW5nIHF1ZXJpZXMuAAAAAAAAAAlSb29tRW50cnkAAAAAAAACAAAAAAAAAARyb29tAAAH0AAAAARSb29tAAAAAAAAAAdyb29tX2lkAAAAAAY=",
+        "AAAAAQAAAHZBIHBhZ2Ugb2YgYGxpc3Rfb3Blbl9yb29tc2AuIGBuZXh0X2N1cnNvcmAgaXMgd2hlcmUgdGhlIG5leHQgY2FsbCBwaWNrcwp1cCwgYE5vbmVgIG9uY2UgdGhlIHdob2xlIGluZGV4IGhhcyBiZWVuIHJlYWQuAAAAAAAAAAAACFJvb21QYWdlAAAAAgAAAAAAAAALbmV4dF9jdXJzb3IAAAAD6AAAAAYAAAAAAAAABXJvb21zAAAAAAAD6gAAB9AAAAAJUm9vbUVudHJ5AAAA",
+        "AAAABQAAAAAAAAAAAAAAC1Jvb21DcmVhdGVkAAAAAAEAAAAMcm9vbV9jcmVhdGVkAAAABwAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAKYmV0X2Ftb3VudAAAAAAACwAAAAAAAAAAAAAABXNlYXRzAAAAAAAABAAAAAAAAAAAAAAABnJvdW5kcwAAAAAABAAAAAAAAAAAAAAAB3ByaXZhdGUAAAAAAQAAAAAAAAAC",
+        "AAAABQAAAC9gc2Vzc2lvbl9pZGAgaXMgMCB1bnRpbCB0aGUgbGFzdCBzZWF0IGlzIHRha2VuLgAAAAAAAAAAClJvb21Kb2luZWQAAAAAAAEAAAALcm9vbV9qb2luZWQAAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAAAAAAEc2VhdAAAAAQAAAAAAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAACUNvbW1pdHRlZAAAAAAAAAEAAAAJY29tbWl0dGVkAAAAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAAAAAAFcm91bmQAAAAAAAAEAAAAAAAAAAI=",
+        "AAAABQAAAFdUaGUgcG90IHdhcyBwYWlkIG91dC4gYHBheW91dHNgIGlzIHBlciBzZWF0OyBgdG90YWxfc3VtYCBpcyBgTm9uZWAgZm9yIGEKdGltZW91dCBjbGFpbS4AAAAAAAAAAAhSZXNvbHZlZAAAAAEAAAAIcmVzb2x2ZWQAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAYAAAABAAAAAAAAAAl0b3RhbF9zdW0AAAAAAAPoAAAABAAAAAAAAAAAAAAAB3BheW91dHMAAAAD6gAAAAsAAAAAAAAAAAAAAANmZWUAAAAACwAAAAAAAAAAAAAADWphY2twb3RfZGVsdGEAAAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAACkphY2twb3RXb24AAAAAAAEAAAALamFja3BvdF93b24AAAAABAAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAAHd2lubmVycwAAAAPqAAAAEwAAAAAAAAAC",
+        "AAAABQAAAD5FdmVyeSBiZXQgd2FzIHJldHVybmVkOyBgc3RhdHVzYCBpcyBgQ2FuY2VsbGVkYCBvciBgUmVmdW5kZWRgLgAAAAAAAAAAAA1Sb29tQ2FuY2VsbGVkAAAAAAAAAQAAAA5yb29tX2NhbmNlbGxlZAAAAAAAAgAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAClJvb21TdGF0dXMAAAAAAAAAAAAC",
+        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAUAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAh2ZXJpZmllcgAAABMAAAAAAAAAB3ZrX2hhc2gAAAAD7gAAACAAAAAAAAAACXhsbV90b2tlbgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAMc2V0X3ZlcmlmaWVyAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
+        "AAAAAAAAAERQb2ludHMgcmVzb2x1dGlvbiBhdCBhIGRpZmZlcmVudCBjaXJjdWl0IHJlZ2lzdGVyZWQgaW4gdGhlIHZlcmlmaWVyLgAAAAtzZXRfdmtfaGFzaAAAAAABAAAAAAAAAAtuZXdfdmtfaGFzaAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAAAAAAALZ2V0X3ZrX2hhc2gAAAAAAAAAAAEAAAPpAAAD7gAAACAAAAAD",
+        "AAAAAAAAAKJTZXRzIHRoZSB2ZXJpZmljYXRpb24ga2V5IGhhc2ggb2YgdGhlIE4tc2VhdCBjaXJjdWl0IHVzZWQgdG8gcmVzb2x2ZQpyb29tcyB3aXRoIG1vcmUgdGhhbiB0d28gc2VhdHMuIFVudGlsIGl0IGlzIHNldCB0aG9zZSByb29tcyBjYW4gb25seQpzZXR0bGUgdGhyb3VnaCBgcmV2ZWFsYC4AAAAAABFzZXRfbXVsdGlfdmtfaGFzaAAAAAAAAAEAAAAAAAAAC25ld192a19oYXNoAAAAA+4AAAAgAAAAAA==",
+        "AAAAAAAAAAAAAAARZ2V0X211bHRpX3ZrX2hhc2gAAAAAAAAAAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
+        "AAAAAAAAAGdTZXRzIHRoZSB0ZXJtcyBmb3Igcm9vbXMgY3JlYXRlZCBmcm9tIG5vdyBvbi4gUm9vbXMgYWxyZWFkeSBvcGVuIGtlZXAKdGhlIHRlcm1zIHRoZXkgd2VyZSBjcmVhdGVkIHdpdGguAAAAAA1zZXRfZmVlX3Rlcm1zAAAAAAAAAgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAApwYXJpdHlfYnBzAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAAAAAAANZ2V0X2ZlZV90ZXJtcwAAAAAAAAAAAAABAAAH0AAAAAhGZWVUZXJtcw==",
+        "AAAAAAAAADBGZWVzIGNvbGxlY3RlZCBpbiBgdG9rZW5gIGFuZCBub3QgeWV0IHdpdGhkcmF3bi4AAAAMZ2V0X3RyZWFzdXJ5AAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAANd2l0aGRyYXdfZmVlcwAAAAAAAAMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAACdG8AAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAACNBbGxvd3MgbmV3IHJvb21zIHRvIGVzY3JvdyBgdG9rZW5gLgAAAAALYWxsb3dfdG9rZW4AAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAFxTdG9wcyBuZXcgcm9vbXMgZnJvbSB1c2luZyBgdG9rZW5gLiBPcGVuIHJvb21zLCBpdHMgamFja3BvdCBhbmQgaXRzCnRyZWFzdXJ5IGFyZSB1bmFmZmVjdGVkLgAAAA5kaXNhbGxvd190b2tlbgAAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAASZ2V0X2FsbG93ZWRfdG9rZW5zAAAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAQ1Nb3ZlcyBudWxsaWZpZXJzIHdyaXR0ZW4gdG8gaW5zdGFuY2Ugc3RvcmFnZSBieSBlYXJsaWVyIHZlcnNpb25zIGludG8KdGhlaXIgb3duIHBlcnNpc3RlbnQgZW50cmllcy4gSW5zdGFuY2Ugc3RvcmFnZSBjYW5ub3QgYmUgZW51bWVyYXRlZCwKc28gdGhlIGFkbWluIHN1cHBsaWVzIHRoZSB1c2VkIG51bGxpZmllcnMgKGUuZy4gZnJvbSBwYXN0IGByZXNvbHZlYApjYWxscykuIFJldHVybnMgaG93IG1hbnkgd2VyZSBtb3ZlZDsgdW5rbm93biBvbmVzIGFyZSBza2lwcGVkLgAAAAAAABJtaWdyYXRlX251bGxpZmllcnMAAAAAAAEAAAAAAAAACm51bGxpZmllcnMAAAAAA+oAAAPuAAAAIAAAAAEAAAAE",
+        "AAAAAAAAASVPcGVucyBhIHJvb20gd2l0aCBgc2VhdHNgIHBsYWNlcyAoYE1JTl9TRUFUUy4uPU1BWF9TRUFUU2ApIGJldHRpbmcKYGJldF9hbW91bnRgIG9mIGFuIGFsbG93bGlzdGVkIGB0b2tlbmA7IHRoZSBjcmVhdG9yIHRha2VzIHRoZSBmaXJzdApzZWF0LiBQcml2YXRlIHJvb21zIChhbnkgYGFjY2Vzc2AgYnV0IGBQdWJsaWNgKSBzdGF5IG91dCBvZgpgbGlzdF9vcGVuX3Jvb21zYC4gYHJvdW5kc2AgYWJvdmUgMSBtYWtlcyB0aGUgcm9vbSBhIGJlc3Qtb2YtYHJvdW5kc2AKbWF0Y2ggcGFpZCBvdXQgd2hlbiBpdCBlbmRzLgAAAAAAAAtjcmVhdGVfcm9vbQAAAAAGAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAKYmV0X2Ftb3VudAAAAAAACwAAAAAAAAAFc2VhdHMAAAAAAAAEAAAAAAAAAAZhY2Nlc3MAAAAAB9AAAAAKUm9vbUFjY2VzcwAAAAAAAAAAAAZyb3VuZHMAAAAAAAQAAAABAAAD6QAAAAYAAAAD",
+        "AAAAAAAAAOFUYWtlcyB0aGUgbmV4dCBmcmVlIHNlYXQuIFRoZSBzZXNzaW9uIHN0YXJ0cyBvbmNlIGV2ZXJ5IHNlYXQgaXMgdGFrZW4uCmBpbnZpdGVfc2lnYCBpcyB0aGUgaW52aXRlIGtleSdzIHNpZ25hdHVyZSBvZiBhIGBLZXlgIHJvb20gb3Zlcgpgcm9vbV9pZCDigJYgcGxheWVyYCAoc2VlIGBSb29tQWNjZXNzYCkgYW5kIGlzIGlnbm9yZWQgb3RoZXJ3aXNlLiBBCndyb25nIHNpZ25hdHVyZSB0cmFwcy4AAAAAAAAJam9pbl9yb29tAAAAAAAAAwAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmludml0ZV9zaWcAAAAAA+gAAAPuAAAAQAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAONTZWFscyB0aGUgcGxheWVyJ3MgaGFuZCBhbmQgZ3Vlc3NlcyBmb3IgdGhlIGN1cnJlbnQgcm91bmQuIGBwYXJpdHlgCmlzIDAgKGV2ZW4pIG9yIDEgKG9kZCk7IGBleGFjdF9ndWVzc2AgaXMgYXQgbW9zdCB0aGUgaGlnaGVzdCBzdW0gdGhlCnJvb20ncyBjaXJjdWl0IGFjY2VwdHMsIGBNQVhfSEFORCAqIDJgIGZvciB0d28gc2VhdHMgYW5kCmBNQVhfSEFORCAqIE1BWF9TRUFUU2Agb3RoZXJ3aXNlLgAAAAAGY29tbWl0AAAAAAAFAAAAAAAAAAdyb29tX2lkAAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAABnBhcml0eQAAAAAABAAAAAAAAAALZXhhY3RfZ3Vlc3MAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAPhWZXJpZmllcyB0aGUgcHJvb2YgYW5kIHNldHRsZXMgdGhlIGN1cnJlbnQgcm91bmQuIFRoZSBudWxsaWZpZXIgaXMKbm90IGNob3NlbiBieSB0aGUgY2FsbGVyOiBpdCBpcyBkZXJpdmVkIGZyb20gdGhlIHJvb20gaWQsIHNlc3Npb24gaWQsCnJvdW5kIGFuZCBhbGwgY29tbWl0bWVudHMgKHNlZSBgZ2V0X251bGxpZmllcmApLCBzbyBhIHByb29mIGlzIHNwZW50CmJ5IGV4YWN0bHkgdGhlIHJvdW5kIGl0IHdhcyBnZW5lcmF0ZWQgZm9yLgAAAAdyZXNvbHZlAAAAAAMAAAAAAAAAB3Jvb21faWQAAAAABgAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAAAAAAl0b3RhbF9zdW0AAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAXJOb24tWksgZmFsbGJhY2s6IG9wZW5zIHRoZSBjYWxsZXIncyBjb21taXRtZW50IG9uLWNoYWluIGJ5IHJlY29tcHV0aW5nCmBwb3NlaWRvbjIoW2hhbmQsIHBhcml0eV9ndWVzcywgZXhhY3Rfc3VtX2d1ZXNzLCBzYWx0XSlgLiBUaGUgcm9vbQpyb3VuZCBzZXR0bGVzIG9uY2UgZXZlcnkgcGxheWVyIGhhcyByZXZlYWxlZC4gVGhlIGZpcnN0IHJldmVhbCBsZWF2ZXMKdGhlIG90aGVycyBhdCBsZWFzdCBgUkVWRUFMX1RJTUVPVVRfTEVER0VSU2AgdG8gZm9sbG93IGJlZm9yZSBhbnlvbmUKY2FuIGNsYWltIGEgZm9yZmVpdC4gYHJlc29sdmVgIHdpdGggYSBwcm9vZiByZW1haW5zCnRoZSBkZWZhdWx0IHBhdGggYW5kIGtlZXBzIGhhbmRzIHByaXZhdGUuAAAAAAAGcmV2ZWFsAAAAAAAEAAAAAAAAAAdyb29tX2lkAAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEaGFuZAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFdUaGUgY3JlYXRvciBjbG9zZXMgYSBsb2JieSB0aGF0IGhhcyBub3QgZmlsbGVkIHVwOyBldmVyeW9uZSBzZWF0ZWQKZ2V0cyB0aGVpciBiZXQgYmFjay4AAAAAC2NhbmNlbF9yb29tAAAAAAEAAAAAAAAAB3Jvb21faWQAAAAABgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAP1DbGFpbXMgdGhlIHBvdCwgbGVzcyB0aGUgcm9vbSdzIGZlZSwgYWZ0ZXIgdGhlIGN1cnJlbnQgZGVhZGxpbmUgd2hlbgpvdGhlcnMgc3RhbGxlZDogYmVmb3JlIGV2ZXJ5b25lIGNvbW1pdHRlZCwgdGhlIHBsYXllcnMgd2hvIGNvbW1pdHRlZApzcGxpdCBpdDsgYWZ0ZXJ3YXJkcywgdGhlIHBsYXllcnMgd2hvIHJldmVhbGVkIGRvLCBvbmNlIHRoZSByZXZlYWwKd2luZG93IGhhcyBjbG9zZWQuIGBwbGF5ZXJgIG11c3QgYmUgb25lIG9mIHRoZW0uAAAAAAAADWNsYWltX3RpbWVvdXQAAAAAAAACAAAAAAAAAAdyb29tX2lkAAAAAAYAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAANRSZXR1cm5zIGV2ZXJ5IGJldCBvbmNlIHRoZSBjdXJyZW50IGRlYWRsaW5lIHBhc3NlZCB3aXRob3V0IGEgcmVzdWx0CmFuZCBub2JvZHkgY2FuIGNsYWltIGEgZm9yZmVpdDogdGhlIGxvYmJ5IG5ldmVyIGZpbGxlZCwgbm9ib2R5CmNvbW1pdHRlZCwgb3IgZXZlcnlvbmUgY29tbWl0dGVkIGFuZCBubyBwcm9vZiBvciByZXZlYWwgYXJyaXZlZC4KQW55b25lIG1heSBjYWxsLgAAAAtyZWZ1bmRfcm9vbQAAAAABAAAAAAAAAAdyb29tX2lkAAAAAAYAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAAIZ2V0X3Jvb20AAAABAAAAAAAAAAdyb29tX2lkAAAAAAYAAAABAAAD6QAAB9AAAAAEUm9vbQAAAAM=",
+        "AAAAAAAAAGJUaGUgbnVsbGlmaWVyIGByZXNvbHZlYCBidXJucyBmb3IgdGhlIGN1cnJlbnQgcm91bmQuIE9ubHkgZGVmaW5lZCBvbmNlCmV2ZXJ5IHBsYXllciBoYXMgY29tbWl0dGVkLgAAAAAADWdldF9udWxsaWZpZXIAAAAAAAABAAAAAAAAAAdyb29tX2lkAAAAAAYAAAABAAAD6QAAA+4AAAAgAAAAAw==",
+        "AAAAAAAAAVBKb2luYWJsZSByb29tcyB3aXRoIGFuIGlkIGFib3ZlIGBjdXJzb3JgLCBvbGRlc3QgZmlyc3QsIGF0IG1vc3QKYGxpbWl0YCAoY2FwcGVkIGF0IGBNQVhfUEFHRWApLiBFeHBpcmVkIGxvYmJpZXMgYXJlIHNraXBwZWQ7CmB0b2tlbmAgYW5kIGBiZXRfYW1vdW50YCBuYXJyb3cgdGhlIHJlc3VsdHMgd2hlbiBnaXZlbi4gQSBjYWxsIHJlYWRzCmF0IG1vc3QgYE1BWF9TQ0FOYCByb29tcywgc28gYSBwYWdlIG1heSBjb21lIGJhY2sgc2hvcnQgb3IgZW1wdHkKYmVmb3JlIHRoZSBpbmRleCBpcyBleGhhdXN0ZWQ6IGtlZXAgcGFzc2luZyBgbmV4dF9jdXJzb3JgIHVudGlsIGl0CmlzIGBOb25lYC4AAAAPbGlzdF9vcGVuX3Jvb21zAAAAAAQAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAAAAAAV0b2tlbgAAAAAAA+gAAAATAAAAAAAAAApiZXRfYW1vdW50AAAAAAPoAAAACwAAAAEAAAfQAAAACFJvb21QYWdl",
+        "AAAAAAAAALNVbmZpbmlzaGVkIHJvb21zIGBwbGF5ZXJgIHRvb2sgYSBzZWF0IGluIHdpdGggYW4gaWQgYWJvdmUgYGN1cnNvcmAsCm9sZGVzdCBmaXJzdCwgYXQgbW9zdCBgbGltaXRgIChjYXBwZWQgYXQgYE1BWF9QQUdFYCkuIFJvb21zIGxlYXZlIHRoZQpsaXN0IG9uY2Ugc2V0dGxlZCwgY2FuY2VsbGVkIG9yIHJlZnVuZGVkLgAAAAAUbGlzdF9yb29tc19ieV9wbGF5ZXIAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmN1cnNvcgAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAACVJvb21FbnRyeQAAAA==",
         "AAAAAAAAAAAAAAAOZ2V0X3Jvb21fY291bnQAAAAAAAAAAAABAAAABg==",
-        "AAAAAAAAADZSZXR1cm5zIHRoZSBhY2N1bXVsYXRlZCBnbG9iYWwgamFja3BvdCAoaTEyOCBzdHJvb3BzKS4AAAAAAAtnZXRfamFja3BvdAAAAAAAAAAAAQAAAAs=",
-        "AAAAAAAAAEFSZXR1cm5zIHRoZSB0b3RhbCBwb3QgbG9ja2VkIGluIGEgcm9vbSAoYmV0IHggbnVtYmVyIG9mIHBsYXllcnMpLgAAAAAAAAxnZXRfcm9vbV9wb3QAAAABAAAAAAAAAAdyb29tX2lkAAAAAAYAAAABAAAD6QAAAAsAAAAD" ]),
+        "AAAAAAAAAAAAAAALZ2V0X2phY2twb3QAAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAMZ2V0X3Jvb21fcG90AAAAAQAAAAAAAAAHcm9vbV9pZAAAAAAGAAAAAQAAA+kAAAALAAAAAw==",
+        "AAAAAAAAAB1HZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAACRTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcyAoYWRtaW4gb25seSkAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAChHZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAB2dldF9odWIAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAC9TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzIChhZG1pbiBvbmx5KQAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAACpVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAoYWRtaW4gb25seSkAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    create_room: this.txFromJSON<Result<u64>>,
+    set_verifier: this.txFromJSON<null>,
+        set_vk_hash: this.txFromJSON<null>,
+        get_vk_hash: this.txFromJSON<Result<Buffer>>,
+        set_multi_vk_hash: this.txFromJSON<null>,
+        get_multi_vk_hash: this.txFromJSON<Result<Buffer>>,
+        set_fee_terms: this.txFromJSON<Result<void>>,
+        get_fee_terms: this.txFromJSON<FeeTerms>,
+        get_treasury: this.txFromJSON<i128>,
+        withdraw_fees: this.txFromJSON<Result<void>>,
+        allow_token: this.txFromJSON<null>,
+        disallow_token: this.txFromJSON<null>,
+        get_allowed_tokens: this.txFromJSON<Array<string>>,
+        migrate_nullifiers: this.txFromJSON<u32>,
+        create_room: this.txFromJSON<Result<u64>>,
         join_room: this.txFromJSON<Result<void>>,
         commit: this.txFromJSON<Result<void>>,
         resolve: this.txFromJSON<Result<void>>,
+        reveal: this.txFromJSON<Result<void>>,
+        cancel_room: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<void>>,
+        refund_room: this.txFromJSON<Result<void>>,
         get_room: this.txFromJSON<Result<Room>>,
+        get_nullifier: this.txFromJSON<Result<Buffer>>,
+        list_open_rooms: this.txFromJSON<RoomPage>,
+        list_rooms_by_player: this.txFromJSON<Array<RoomEntry>>,
         get_room_count: this.txFromJSON<u64>,
         get_jackpot: this.txFromJSON<i128>,
-        get_room_pot: this.txFromJSON<Result<i128>>
+        get_room_pot: this.txFromJSON<Result<i128>>,
+        get_admin: this.txFromJSON<string>,
+        set_admin: this.txFromJSON<null>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -111,7 +111,7 @@ export default function IntroScreen({ onEnter }: IntroScreenProps) {
           <div>› Bet XLM. Guess if the total is <span className="text-white">ODD or EVEN</span>.</div>
           <div>› Optionally guess the <span className="text-white">exact sum</span> for the jackpot.</div>
           <div>› <span className="text-white">ZK Proof</span> ensures nobody lies about their hand.</div>
-          <div>› 80% pot goes to parity winner. 20% feeds the jackpot.</div>
+          <div>› After a small house fee, most of the pot goes to the parity winner. The rest feeds the jackpot.</div>
           <div>› Jackpot pays out if someone nails the exact sum.</div>
         </div>
       </div>
@@ -3,21 +3,25 @@
  * 
  * This module generates actual zero-knowledge proofs using Noir.js
  * Production-ready implementation for ZK Porrinha game
+ *
+ * Proofs are UltraHonk with the keccak transcript, the scheme the on-chain
+ * verifier checks. Keep @aztec/bb.js and @noir-lang/noir_js on the versions
+ * pinned in circuits/zk-porrinha/dockerfile-vk (bb 0.87.0, nargo 1.0.0-beta.9).
  */
 
 import { Noir } from '@noir-lang/noir_js';
-import { BarretenbergBackend, type CompiledCircuit } from '@noir-lang/backend_barretenberg';
+import { UltraHonkBackend } from '@aztec/bb.js';
 import { Buffer } from "buffer";
 import { xdr } from "@stellar/stellar-sdk";
 
 // Cache for the Noir instance
 let noirInstance: Noir | null = null;
-let backendInstance: BarretenbergBackend | null = null;
+let backendInstance: UltraHonkBackend | null = null;
 
 /**
  * Initialize the Noir prover with the compiled circuit
  */
-async function initializeProver(): Promise<{ noir: Noir; backend: BarretenbergBackend }> {
+async function initializeProver(): Promise<{ noir: Noir; backend: UltraHonkBackend }> {
   if (noirInstance && backendInstance) {
     return { noir: noirInstance, backend: backendInstance };
   }
@@ -34,7 +38,7 @@ async function initializeProver(): Promise<{ noir: Noir; backend: BarretenbergBa
   console.log('[NoirProver] Circuit loaded successfully');
   
   // Initialize backend
-  backendInstance = new BarretenbergBackend(circuit);
+  backendInstance = new UltraHonkBackend(circuit.bytecode);
   console.log('[NoirProver] Backend initialized');
   
   // Initialize Noir
@@ -193,7 +197,7 @@ export async function generateRealProof(input: RealProverInput): Promise<RealPro
   console.log(`[NoirProver] Circuit execution complete in ${execElapsed}s`);
 
   const proofStart = performance.now();
-  const proof = await backend.generateProof(witness);
+  const proof = await backend.generateProof(witness, { keccak: true });
   const proofElapsed = ((performance.now() - proofStart) / 1000).toFixed(2);
   console.log(`[NoirProver] Proof generated in ${proofElapsed}s`);
 
@@ -229,7 +233,7 @@ export async function verifyProof(
     const isValid = await backend.verifyProof({
       proof,
       publicInputs,
-    });
+    }, { keccak: true });
     
     console.log('[NoirProver] Proof verification:', isValid ? '✅ VALID' : '❌ INVALID');
     return isValid;
@@ -1,5 +1,5 @@
 import { zkPorrinhaService } from '../zkPorrinhaService';
-import type { Client as ZkPorrinhaClient } from '../bindings';
+import type { Client as ZkPorrinhaClient, FeeTerms } from '../bindings';
 import { devWalletService } from '@/services/devWalletService';
 
 let _actionLock = false;
@@ -121,6 +121,8 @@ export function determineRoomPhase(room: any): 'lobby' | 'commit' | 'settled' {
     case 'Commit':
       return 'commit';
     case 'Settled':
+    case 'Cancelled':
+    case 'Refunded':
       return 'settled';
     default:
       return 'lobby';
@@ -131,6 +133,24 @@ export function formatXLM(stroops: bigint | number): string {
   return (Number(stroops) / 10000000).toFixed(2);
 }
 
+const BPS_DENOMINATOR = 10_000n;
+
+/**
+ * Splits a settled pot like the contract does: the house fee comes off the
+ * top, `parity_bps` of the rest is the parity prize and the remainder feeds
+ * the jackpot.
+ */
+export function splitPot(pot: bigint, terms: FeeTerms) {
+  const fee = (pot * BigInt(terms.fee_bps)) / BPS_DENOMINATOR;
+  const net = pot - fee;
+  const parityPrize = (net * BigInt(terms.parity_bps)) / BPS_DENOMINATOR;
+  return { fee, parityPrize, jackpotContribution: net - parityPrize };
+}
+
+export function formatBps(bps: number): string {
+  return `${bps / 100}%`;
+}
+
 export function startNewGameState() {
   return {
     currentRoomId: null as bigint | null,
@@ -1,17 +1,38 @@
 import { Client as VerifierClient, networks } from './noir_verifier_bindings/src';
+import { Client as ZkPorrinhaClient } from './bindings';
+import { config } from '@/config';
 import { rpc } from '@stellar/stellar-sdk';
 
 const RPC_URL = 'https://soroban-testnet.stellar.org';
 const VERIFIER_CONTRACT_ID = 'CDH3DFQDGSTX4CCG7L7J4NGRCLMNZGOK5VLIBSYAWBLO2ODNANOZLRC2';
 
+let vkHashRequest: Promise<Buffer> | null = null;
+
 /**
- * SHA-256 da VK do circuito zk-porrinha v2 (circuits/zk-porrinha/vk.bin, 1779 bytes).
+ * SHA-256 da VK com que o contrato zk-porrinha liquida salas de 2 lugares,
+ * lida do seu `get_vk_hash` (buscada uma vez e reaproveitada).
  * Qualquer outra VK — isto é, qualquer outro circuito — será rejeitada pelo contrato.
  */
-export const VK_HASH = Buffer.from(
-  'ac147cd32a5681d6d29487088e5250b616e9ec4f42ea095eb07a68b89640a6b5',
-  'hex'
-);
+export function getVkHash(): Promise<Buffer> {
+  if (!vkHashRequest) {
+    vkHashRequest = fetchVkHash().catch((error) => {
+      vkHashRequest = null;
+      throw error;
+    });
+  }
+  return vkHashRequest;
+}
+
+async function fetchVkHash(): Promise<Buffer> {
+  const client = new ZkPorrinhaClient({
+    contractId: config.ZK_PORRINHA_CONTRACT_ID,
+    networkPassphrase: networks.testnet.networkPassphrase,
+    rpcUrl: RPC_URL,
+  });
+  const tx = await client.get_vk_hash();
+  const res = await tx.simulate();
+  return res.result.unwrap();
+}
 
 /**
  * Initialize the Noir verifier client
@@ -43,6 +64,7 @@ export async function verifyProofOnChain(
 ): Promise<boolean> {
   try {
     const client = getVerifierClient(publicKey);
+    const vkHash = await getVkHash();
     
     const proofBuffer = Buffer.from(proof);
     const publicInputsBuffers = publicInputs.map(input => Buffer.from(input));
@@ -50,14 +72,13 @@ export async function verifyProofOnChain(
     console.log('🔍 Verifying proof on-chain...');
     console.log('Proof size:', proof.length, 'bytes');
     console.log('Public inputs:', publicInputsBuffers.length);
-    console.log('VK hash:', VK_HASH.toString('hex'));
     
     // Camada 1: VK hash vincula a prova a este circuito específico
     // Camada 2: BN254 pairing check criptográfico real
     const tx = await client.verify({
       proof: proofBuffer,
       public_inputs: publicInputsBuffers,
-      vk_hash: VK_HASH,
+      vk_hash: vkHash,
     });
     
     const result = await tx.simulate();
@@ -132,8 +153,8 @@ export async function getCircuitInfo(
 
 /**
  * Alias para verifyProofOnChain com VK hash explícito.
- * Mantido por retrocompatibilidade — usa sempre o VK_HASH do circuito atual.
- * @deprecated Use verifyProofOnChain que já inclui o VK_HASH automaticamente.
+ * Mantido por retrocompatibilidade — usa sempre o VK hash configurado no contrato.
+ * @deprecated Use verifyProofOnChain que já busca o VK hash automaticamente.
  */
 export async function verifyProofWithCircuit(
   proof: Uint8Array,
@@ -141,7 +162,7 @@ export async function verifyProofWithCircuit(
   _circuitHash: Uint8Array,
   publicKey: string
 ): Promise<boolean> {
-  // Ignora o circuitHash passado e usa o VK_HASH embarcado no verifier.ts.
+  // Ignora o circuitHash passado e usa o VK hash lido do contrato zk-porrinha.
   // O contrato v5 valida internamente via SHA-256 da VK.
   return verifyProofOnChain(proof, publicInputs, publicKey);
 }
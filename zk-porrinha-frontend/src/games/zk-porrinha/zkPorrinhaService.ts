@@ -10,6 +10,9 @@ import { localGameState, type LocalHand } from './localGameState';
 
 type ClientOptions = contract.ClientOptions;
 
+// Rooms created from this client are always 2-seat, public, single-round XLM games.
+const XLM_TOKEN_ADDRESS = 'CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC';
+
 function extractTxHash(sent: any): string {
   return (
     sent?.sendTransactionResponse?.hash ||
@@ -100,8 +103,7 @@ export class ZkPorrinhaService {
       try {
         const room = await this.getRoom(roomId);
         if (!room) return null;
-        const players = room.has_player2 ? 2n : 1n;
-        return BigInt(room.bet_amount) * players;
+        return BigInt(room.bet_amount) * BigInt(room.players.length);
       } catch {
         console.error('[ZkPorrinhaService] getRoomPot failed', e);
         return null;
@@ -110,11 +112,11 @@ export class ZkPorrinhaService {
   }
 
   /**
-   * Returns the global accumulated jackpot from the contract (i128 stroops).
+   * Returns the accumulated XLM jackpot from the contract (i128 stroops).
    */
   async getJackpot(): Promise<bigint | null> {
     try {
-      const tx = await this.baseClient.get_jackpot();
+      const tx = await this.baseClient.get_jackpot({ token: XLM_TOKEN_ADDRESS });
       const res = await tx.simulate();
       return res.result as unknown as bigint;
     } catch (e) {
@@ -151,7 +153,11 @@ export class ZkPorrinhaService {
       const client = this.createSigningClient(playerAddress, signer);
       const tx = await client.create_room({
         player: playerAddress,
+        token: XLM_TOKEN_ADDRESS,
         bet_amount: betAmount,
+        seats: 2,
+        access: { tag: "Public", values: undefined },
+        rounds: 1,
       });
       const { sent, txHash } = await signAndSendTx(tx);
       let roomId: bigint;
@@ -183,6 +189,7 @@ export class ZkPorrinhaService {
       const tx = await client.join_room({
         room_id: roomId,
         player: playerAddress,
+        invite_sig: undefined,
       });
       const { txHash } = await signAndSendTx(tx);
       return { success: true, txHash, betAmount: room.bet_amount };
@@ -259,11 +266,16 @@ export class ZkPorrinhaService {
 
       const { computePoseidon2Commitment } = await import('./poseidonNoir');
 
+      if (room.seats !== 2 || room.players.length !== 2) {
+        throw new Error('Proof resolution is only supported for 2-seat rooms');
+      }
+      const [player1, player2] = room.players;
+
       // Read parity/exact guesses from the on-chain room state
-      const parity1 = Number(room.player1.parity_guess);
-      const exact1 = Number(room.player1.exact_sum_guess);
-      const parity2 = Number(room.player2.parity_guess);
-      const exact2 = Number(room.player2.exact_sum_guess);
+      const parity1 = Number(player1.parity_guess);
+      const exact1 = Number(player1.exact_sum_guess);
+      const parity2 = Number(player2.parity_guess);
+      const exact2 = Number(player2.exact_sum_guess);
 
       // Recompute commitments using the full 4-argument Poseidon2(hand, parity, exact, salt)
       const h1Buf = await computePoseidon2Commitment(mySecret.hand, parity1, exact1, mySecret.saltHex);
@@ -298,15 +310,6 @@ export class ZkPorrinhaService {
       const { proofBytes, totalSum } = await generateRealProof(proverInput);
       console.log('✅ Proof generated successfully');
 
-      const nullifierData = Buffer.concat([
-        Buffer.from(roomId.toString()),
-        h1Buf,
-        h2Buf,
-      ]);
-      
-      const hashBuffer = await crypto.subtle.digest('SHA-256', nullifierData);
-      const nullifier = Buffer.from(hashBuffer);
-
       let verificationResult = false;
       if (verifyOnChain) {
         console.log('🔍 Verifying proof on-chain...');
@@ -330,7 +333,6 @@ export class ZkPorrinhaService {
         room_id: roomId, 
         proof: Buffer.from(proofBytes), 
         total_sum: totalSum,
-        nullifier: nullifier,
       });
       const { txHash } = await signAndSendTx(tx);
       
@@ -360,13 +362,13 @@ export class ZkPorrinhaService {
         return { autoResolved: false, error: 'Room not found' };
       }
 
-      if (!room.player1.has_committed || !room.player2.has_committed) {
+      if (room.players.length < room.seats || !room.players.every((p) => p.has_committed)) {
         console.log('⏳ Waiting for both players to commit...');
         return { autoResolved: false };
       }
 
-      const player1Addr = room.player1.address;
-      const player2Addr = room.player2.address;
+      const player1Addr = room.players[0].address;
+      const player2Addr = room.players[1].address;
       
       const state = localGameState.canAutoResolve(roomId, player1Addr, player2Addr);
       
@@ -393,22 +395,22 @@ export class ZkPorrinhaService {
         
         if (resolvedRoom && resolvedRoom.winner) {
           const winnerAddr = resolvedRoom.winner;
-          const isPlayer1Winner = winnerAddr === resolvedRoom.player1.address;
+          const isPlayer1Winner = winnerAddr === resolvedRoom.players[0].address;
           const winnerName = isPlayer1Winner ? 'Player 1' : 'Player 2';
           const winnerShort = winnerAddr.substring(0, 8) + '...';
           
           console.log('🏆 WINNER:', winnerName, winnerShort);
           console.log('🎲 Total sum revealed:', resolvedRoom.total_sum || 'unknown');
           console.log('💰 Bet amount:', resolvedRoom.bet_amount, 'stroops per player');
-          console.log('💵 Total pot:', (BigInt(resolvedRoom.bet_amount) * 2n).toString(), 'stroops');
+          console.log('💵 Total pot:', (BigInt(resolvedRoom.bet_amount) * BigInt(resolvedRoom.players.length)).toString(), 'stroops');
           
           console.log('📊 Player 1 guesses:', {
-            parity: resolvedRoom.player1.parity_guess === 0 ? 'Even' : 'Odd',
-            exact: resolvedRoom.player1.exact_sum_guess,
+            parity: resolvedRoom.players[0].parity_guess === 0 ? 'Even' : 'Odd',
+            exact: resolvedRoom.players[0].exact_sum_guess,
           });
           console.log('📊 Player 2 guesses:', {
-            parity: resolvedRoom.player2.parity_guess === 0 ? 'Even' : 'Odd',
-            exact: resolvedRoom.player2.exact_sum_guess,
+            parity: resolvedRoom.players[1].parity_guess === 0 ? 'Even' : 'Odd',
+            exact: resolvedRoom.players[1].exact_sum_guess,
           });
         }
         
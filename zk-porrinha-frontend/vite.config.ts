@@ -17,7 +17,7 @@ export default defineConfig({
     dedupe: ['@stellar/stellar-sdk']
   },
   optimizeDeps: {
-    exclude: ['@noir-lang/noir_js', '@noir-lang/backend_barretenberg'],
+    exclude: ['@noir-lang/noir_js', '@aztec/bb.js'],
     esbuildOptions: {
       define: {
         global: 'globalThis'